@@ -0,0 +1,231 @@
+use graphics::types::Color;
+use std::{convert::TryFrom, fs, io, path::Path};
+
+use crate::text::Style;
+
+/// How many columns ANSI art is drawn across before it wraps, as on an 80 column DOS screen.
+pub const ANSI_WIDTH: usize = 80;
+
+/// The 16 colors of the VGA text mode palette, in the order ANSI color codes number them: the 8 normal colors, then their bright versions.
+pub const VGA_COLORS: [Color; 16] = [
+    [0.0,   0.0,   0.0,   1.0],
+    [0.667, 0.0,   0.0,   1.0],
+    [0.0,   0.667, 0.0,   1.0],
+    [0.667, 0.333, 0.0,   1.0],
+    [0.0,   0.0,   0.667, 1.0],
+    [0.667, 0.0,   0.667, 1.0],
+    [0.0,   0.667, 0.667, 1.0],
+    [0.667, 0.667, 0.667, 1.0],
+    [0.333, 0.333, 0.333, 1.0],
+    [1.0,   0.333, 0.333, 1.0],
+    [0.333, 1.0,   0.333, 1.0],
+    [1.0,   1.0,   0.333, 1.0],
+    [0.333, 0.333, 1.0,   1.0],
+    [1.0,   0.333, 1.0,   1.0],
+    [0.333, 1.0,   1.0,   1.0],
+    [1.0,   1.0,   1.0,   1.0],
+];
+
+// The symbols code page 437 draws for the control codes 0 to 31.
+const CP437_LOW: &str = " ☺☻♥♦♣♠•◘○◙♂♀♪♫☼►◄↕‼¶§▬↨↑↓→←∟↔▲▼";
+
+// The characters code page 437 puts at 127 to 255.
+const CP437_HIGH: &str = "⌂ÇüéâäàåçêëèïîìÄÅÉæÆôöòûùÿÖÜ¢£¥₧ƒáíóúñÑªº¿⌐¬½¼¡«»░▒▓│┤╡╢╖╕╣║╗╝╜╛┐└┴┬├─┼╞╟╚╔╩╦╠═╬╧╨╤╥╙╘╒╓╫╪┘┌█▄▌▐▀αßΓπΣσµτΦΘΩδ∞φε∩≡±≥≤⌠⌡÷≈°∙·√ⁿ²■\u{A0}";
+
+/// Returns the Unicode character for a byte in code page 437, the character set of DOS and BBS art.
+/// Bytes below 32 are the symbols DOS drew for them, like smileys and card suits, rather than control codes.
+/// ```
+/// # use simpleterm_profile::ansi::cp437_char;
+/// assert_eq!(cp437_char(b'A'), 'A');
+/// assert_eq!(cp437_char(0xB1), '▒');
+/// assert_eq!(cp437_char(0xC9), '╔');
+/// assert_eq!(cp437_char(0x03), '♥');
+/// ```
+pub fn cp437_char(byte: u8) -> char {
+    match byte {
+        0..=31 => CP437_LOW.chars().nth(byte as usize).unwrap_or(' '),
+        32..=126 => byte as char,
+        _ => CP437_HIGH.chars().nth(byte as usize - 127).unwrap_or(' '),
+    }
+}
+
+/// Converts bytes in code page 437 to a string, keeping line breaks as line breaks.
+/// ```
+/// # use simpleterm_profile::ansi::decode_cp437;
+/// assert_eq!(decode_cp437(&[0xDA, 0xC4, 0xBF, b'\n', 0xC0, 0xC4, 0xD9]), "┌─┐\n└─┘");
+/// ```
+pub fn decode_cp437(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| match byte {
+        b'\n' | b'\r' => *byte as char,
+        _ => cp437_char(*byte),
+    }).collect()
+}
+
+/// Reinterprets text whose characters each stand for a byte of code page 437, as happens when DOS art is read or pasted as Latin-1.
+/// Characters past 255 can't have come from a single byte, so they're kept as they are, along with line breaks.
+/// ```
+/// # use simpleterm_profile::ansi::from_cp437_text;
+/// assert_eq!(from_cp437_text("\u{C9}\u{CD}\u{BB}\n\u{C8}\u{CD}\u{BC}"), "╔═╗\n╚═╝");
+/// assert_eq!(from_cp437_text("plain ascii"), "plain ascii");
+/// assert_eq!(from_cp437_text("\u{03}╔"), "♥╔");
+/// ```
+pub fn from_cp437_text(text: &str) -> String {
+    text.chars().map(|character| match u8::try_from(u32::from(character)) {
+        Ok(b'\n') | Ok(b'\r') | Err(_) => character,
+        Ok(byte) => cp437_char(byte),
+    }).collect()
+}
+
+/// Colored art loaded from a classic ANSI art file, ready to draw with
+/// [display_ansi](../terminal/struct.Terminal.html#method.display_ansi).
+/// Each character has its own style. The terminal can't draw a background color behind a character,
+/// so blank cells with a colored background become solid blocks of that color, and other characters keep only their own color.
+/// ```
+/// # use simpleterm_profile::ansi::*;
+/// let art: AnsiArt = AnsiArt::parse(b"\x1b[1;31mHi\x1b[0m\r\n\x1b[44m \x1b[0m!");
+/// assert_eq!(art.lines, vec![String::from("Hi"), String::from("█!")]);
+/// assert_eq!(art.styles[0][0].color, Some(VGA_COLORS[9]));
+/// assert_eq!(art.styles[1][0].color, Some(VGA_COLORS[4]));
+/// assert_eq!(art.styles[1][1].color, Some(VGA_COLORS[7]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnsiArt {
+    /// The lines of the art, with trailing blank cells removed.
+    pub lines: Vec<String>,
+    /// A style for each character of each line.
+    pub styles: Vec<Vec<Style>>,
+}
+
+// The colors and attributes set by the most recent color escape codes.
+#[derive(Clone, Copy, Debug)]
+struct Attributes {
+    fg: usize,
+    bg: usize,
+    bold: bool,
+    blink: bool,
+    inverse: bool,
+}
+
+impl Default for Attributes {
+    fn default() -> Attributes {
+        Attributes { fg: 7, bg: 0, bold: false, blink: false, inverse: false }
+    }
+}
+
+impl Attributes {
+    // Applies the parameters of a "select graphic rendition" escape code.
+    fn apply(&mut self, params: &[Option<usize>]) {
+        if params.is_empty() { *self = Attributes::default(); }
+        for param in params.iter().map(|param| param.unwrap_or(0)) {
+            match param {
+                0 => *self = Attributes::default(),
+                1 => self.bold = true,
+                5 => self.blink = true,
+                7 => self.inverse = true,
+                22 => self.bold = false,
+                25 => self.blink = false,
+                27 => self.inverse = false,
+                30..=37 => self.fg = param - 30,
+                39 => self.fg = 7,
+                40..=47 => self.bg = param - 40,
+                49 => self.bg = 0,
+                _ => {},
+            }
+        }
+    }
+
+    // Returns the character to draw and its style, turning blank cells with a background into blocks of that color.
+    fn cell(&self, character: char) -> (char, Style) {
+        let (fg, bg): (usize, usize) = if self.inverse { (self.bg, self.fg) } else { (self.fg, self.bg) };
+        let fg: usize = if self.bold { fg | 8 } else { fg };
+
+        if (character == ' ' || character == '\u{A0}') && bg != 0 {
+            ('█', Style { color: Some(VGA_COLORS[bg]), ..Style::default() })
+        } else {
+            (character, Style { color: Some(VGA_COLORS[fg]), blink: self.blink, ..Style::default() })
+        }
+    }
+}
+
+impl AnsiArt {
+    /// Reads ANSI art from the bytes of a .ans file: code page 437 characters mixed with escape codes for color and cursor movement.
+    /// The art wraps at [ANSI_WIDTH](constant.ANSI_WIDTH.html) columns, and anything after the end of file marker, like a SAUCE record, is ignored.
+    pub fn parse(bytes: &[u8]) -> AnsiArt {
+        let mut cells: Vec<Vec<Option<(char, Style)>>> = Vec::new();
+        let mut attributes: Attributes = Attributes::default();
+        let (mut row, mut col): (usize, usize) = (0, 0);
+        let mut saved: (usize, usize) = (0, 0);
+
+        let mut i: usize = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                0x1A => break,
+                b'\r' => col = 0,
+                b'\n' => {
+                    row += 1;
+                    col = 0;
+                },
+                b'\t' => col = (col / 8 + 1) * 8,
+                0x1B if bytes.get(i + 1) == Some(&b'[') => {
+                    let start: usize = i + 2;
+                    let end: usize = match bytes[start..].iter().position(|byte| (0x40..=0x7E).contains(byte)) {
+                        Some(offset) => start + offset,
+                        None => break,
+                    };
+                    let params: Vec<Option<usize>> = String::from_utf8_lossy(&bytes[start..end])
+                        .split(';')
+                        .map(|param| param.trim_start_matches('?').parse().ok())
+                        .collect();
+                    let count: usize = params.first().copied().flatten().unwrap_or(1).max(1);
+
+                    match bytes[end] {
+                        b'm' => attributes.apply(if end == start { &[] } else { &params }),
+                        b'A' => row = row.saturating_sub(count),
+                        b'B' => row += count,
+                        b'C' => col = (col + count).min(ANSI_WIDTH - 1),
+                        b'D' => col = col.saturating_sub(count),
+                        b'H' | b'f' => {
+                            row = params.first().copied().flatten().unwrap_or(1).saturating_sub(1);
+                            col = params.get(1).copied().flatten().unwrap_or(1).saturating_sub(1);
+                        },
+                        b'J' if params.first() == Some(&Some(2)) => {
+                            cells.clear();
+                            row = 0;
+                            col = 0;
+                        },
+                        b'K' => if let Some(line) = cells.get_mut(row) { line.truncate(col); },
+                        b's' => saved = (row, col),
+                        b'u' => (row, col) = saved,
+                        _ => {},
+                    }
+                    i = end;
+                },
+                byte => {
+                    if col >= ANSI_WIDTH {
+                        row += 1;
+                        col = 0;
+                    }
+                    if cells.len() <= row { cells.resize(row + 1, Vec::new()); }
+                    if cells[row].len() <= col { cells[row].resize(col + 1, None); }
+                    cells[row][col] = Some(attributes.cell(cp437_char(byte)));
+                    col += 1;
+                },
+            }
+            i += 1;
+        }
+
+        let mut art: AnsiArt = AnsiArt::default();
+        for line in cells {
+            let filled: Vec<(char, Style)> = line.into_iter().map(|cell| cell.unwrap_or((' ', Style::default()))).collect();
+            let length: usize = filled.iter().rposition(|(character, _)| *character != ' ').map_or(0, |last| last + 1);
+            art.lines.push(filled[..length].iter().map(|(character, _)| *character).collect());
+            art.styles.push(filled[..length].iter().map(|(_, style)| *style).collect());
+        }
+        art
+    }
+
+    /// Loads ANSI art from a .ans file.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<AnsiArt> {
+        Ok(AnsiArt::parse(&fs::read(path)?))
+    }
+}
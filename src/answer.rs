@@ -0,0 +1,48 @@
+/// How a question asked with [ask](../terminal/struct.Terminal.html#method.ask) ended.
+/// ```
+/// # use simpleterm_profile::answer::Answer;
+/// let answer: Answer = Answer::Text(String::from("Ada"));
+/// assert!(answer.is_text());
+/// assert_eq!(answer.text(), Some(String::from("Ada")));
+/// assert_eq!(Answer::Cancelled.text(), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// The user typed this answer.
+    Text(String),
+    /// The user pressed Back (Escape by default) instead of answering. The window stays open.
+    Cancelled,
+    /// Nobody answered before the terminal's answer timeout ran out.
+    TimedOut,
+    /// The window was closed, or had already been closed, so there's nobody left to answer.
+    /// Pressing Quit (Escape by default) closes the window when the question can't be cancelled.
+    WindowClosed,
+}
+
+impl Answer {
+    /// Returns true if the user typed an answer.
+    pub fn is_text(&self) -> bool {
+        matches!(self, Answer::Text(_))
+    }
+
+    /// Returns the typed answer, or None if the question ended any other way.
+    pub fn text(self) -> Option<String> {
+        match self {
+            Answer::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns the typed answer as a string slice, or None if the question ended any other way.
+    /// ```
+    /// # use simpleterm_profile::answer::Answer;
+    /// assert_eq!(Answer::Text(String::from("yes")).as_deref(), Some("yes"));
+    /// assert_eq!(Answer::TimedOut.as_deref(), None);
+    /// ```
+    pub fn as_deref(&self) -> Option<&str> {
+        match self {
+            Answer::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
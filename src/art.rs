@@ -1,3 +1,8 @@
+use graphics::types::Color;
+use std::{collections::BTreeMap, fs, io, path::Path, time::Duration};
+
+use crate::text::{display_width, Style};
+
 pub const DANCE_1: &str = "<('-'<) ";
 pub const DANCE_2: &str = "<('-')>";
 pub const DANCE_3: &str = " (>'-')>";
@@ -32,3 +37,716 @@ pub const DANCES: [&str; 16] = [
     DANCE_12,
     DANCE_11,
 ];
+
+// Borders
+
+pub const BORDER_SINGLE: &str = r"+------------------+
+|                  |
+|                  |
+|                  |
++------------------+";
+
+pub const BORDER_DOUBLE: &str = r"#==================#
+||                ||
+||                ||
+||                ||
+#==================#";
+
+pub const BORDER_STARS: &str = r"*  *  *  *  *  *  *
+*                 *
+*                 *
+*                 *
+*  *  *  *  *  *  *";
+
+// Animals
+
+pub const CAT: &str = r" /\_/\
+( o.o )
+ > ^ <";
+
+pub const DOG: &str = r"  / \__
+ (    @\___
+ /         O
+/   (_____/
+/_____/   U";
+
+pub const FISH: &str = r"><(((('>";
+
+pub const BUNNY: &str = r"(\_/)
+(='.'=)
+(')_(')";
+
+pub const OWL: &str = r#" ,_,
+(O,O)
+(   )
+-"-"-"#;
+
+// Logos
+
+pub const LOGO_SIMPLETERM: &str = r" ___ ___ __  __ ___ _    ___ _____ ___ ___ __  __
+/ __|_ _|  \/  | _ \ |  | __|_   _| __| _ \  \/  |
+\__ \| || |\/| |  _/ |__| _|  | | | _||   / |\/| |
+|___/___|_|  |_|_| |____|___| |_| |___|_|_\_|  |_|";
+
+pub const LOGO_PROMPT: &str = r" _
+\ \
+ > >
+/_/ ___";
+
+// Dice faces
+
+pub const DIE_1: &str = "+-------+\n|       |\n|   o   |\n|       |\n+-------+";
+pub const DIE_2: &str = "+-------+\n| o     |\n|       |\n|     o |\n+-------+";
+pub const DIE_3: &str = "+-------+\n| o     |\n|   o   |\n|     o |\n+-------+";
+pub const DIE_4: &str = "+-------+\n| o   o |\n|       |\n| o   o |\n+-------+";
+pub const DIE_5: &str = "+-------+\n| o   o |\n|   o   |\n| o   o |\n+-------+";
+pub const DIE_6: &str = "+-------+\n| o   o |\n| o   o |\n| o   o |\n+-------+";
+
+// Card suits
+
+pub const SPADE: &str = r"   .
+  / \
+ (   )
+  `|`";
+
+pub const HEART: &str = r" _  _
+( \/ )
+ \  /
+  \/";
+
+pub const DIAMOND: &str = r"  /\
+ /  \
+ \  /
+  \/";
+
+pub const CLUB: &str = r"  _
+ ( )
+(_ _)
+  |";
+
+// Weather icons
+
+pub const SUN: &str = r"   \   /
+    .-.
+ - (   ) -
+    `-'
+   /   \";
+
+pub const CLOUD: &str = r"   .--.
+.-(    ).
+(___.__)__)";
+
+pub const RAIN: &str = r"   .--.
+.-(    ).
+(___.__)__)
+ ' ' ' '
+' ' ' '";
+
+pub const SNOW: &str = r"   .--.
+.-(    ).
+(___.__)__)
+ *  *  *
+*  *  *";
+
+pub const STORM: &str = r"   .--.
+.-(    ).
+(___.__)__)
+  /_  /_
+   /   /";
+
+/// Every piece of art in the gallery, looked up by "category/name" with `get`.
+pub const GALLERY: [(&str, &str); 37] = [
+    ("dance/1", DANCE_1),
+    ("dance/2", DANCE_2),
+    ("dance/3", DANCE_3),
+    ("dance/4", DANCE_4),
+    ("dance/5", DANCE_5),
+    ("dance/6", DANCE_6),
+    ("dance/7", DANCE_7),
+    ("dance/8", DANCE_8),
+    ("dance/9", DANCE_9),
+    ("dance/10", DANCE_10),
+    ("dance/11", DANCE_11),
+    ("dance/12", DANCE_12),
+    ("borders/single", BORDER_SINGLE),
+    ("borders/double", BORDER_DOUBLE),
+    ("borders/stars", BORDER_STARS),
+    ("animals/cat", CAT),
+    ("animals/dog", DOG),
+    ("animals/fish", FISH),
+    ("animals/bunny", BUNNY),
+    ("animals/owl", OWL),
+    ("logos/simpleterm", LOGO_SIMPLETERM),
+    ("logos/prompt", LOGO_PROMPT),
+    ("dice/1", DIE_1),
+    ("dice/2", DIE_2),
+    ("dice/3", DIE_3),
+    ("dice/4", DIE_4),
+    ("dice/5", DIE_5),
+    ("dice/6", DIE_6),
+    ("suits/spade", SPADE),
+    ("suits/heart", HEART),
+    ("suits/diamond", DIAMOND),
+    ("suits/club", CLUB),
+    ("weather/sun", SUN),
+    ("weather/cloud", CLOUD),
+    ("weather/rain", RAIN),
+    ("weather/snow", SNOW),
+    ("weather/storm", STORM),
+];
+
+/// Looks up a piece of art in the gallery by its "category/name" path.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(get("weather/rain"), Some(RAIN));
+/// assert_eq!(get("weather/hail"), None);
+/// ```
+pub fn get(path: &str) -> Option<&'static str> {
+    GALLERY.iter().find(|(name, _)| *name == path).map(|(_, art)| *art)
+}
+
+/// Lists the paths of every piece of art in the given category.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(list("suits"), vec!["suits/spade", "suits/heart", "suits/diamond", "suits/club"]);
+/// ```
+pub fn list(category: &str) -> Vec<&'static str> {
+    GALLERY.iter()
+        .map(|(path, _)| *path)
+        .filter(|path| path.split_once('/').is_some_and(|(cat, _)| cat == category))
+        .collect()
+}
+
+/// Reads a piece of ascii art from a text file, so large art doesn't have to be embedded in the source code.
+/// A byte order mark, Windows line endings, and trailing newlines are removed, so the art matches one written as a constant.
+pub fn load_art(path: &Path) -> io::Result<String> {
+    let text: String = fs::read_to_string(path)?;
+    Ok(text.trim_start_matches('\u{feff}').replace("\r\n", "\n").trim_end_matches('\n').to_string())
+}
+
+/// Art loaded from a folder of text files and looked up by name, like the built-in [GALLERY](constant.GALLERY.html).
+/// Each .txt file is named by its path inside the folder without the extension, so resources/art/animals/cat.txt is "animals/cat".
+/// Names the library doesn't have fall back to the gallery.
+/// ```
+/// # use std::fs;
+/// # use simpleterm_profile::art::*;
+/// let folder = std::env::temp_dir().join("simpleterm_art_library");
+/// fs::create_dir_all(folder.join("ships")).unwrap();
+/// fs::write(folder.join("ships/boat.txt"), " __/\\__\r\n \\____/\r\n").unwrap();
+/// let library: ArtLibrary = ArtLibrary::open(&folder).unwrap();
+/// assert_eq!(library.names(), vec!["ships/boat"]);
+/// assert_eq!(library.get("ships/boat"), Some(" __/\\__\n \\____/"));
+/// assert_eq!(library.get("animals/cat"), Some(CAT));
+/// # fs::remove_dir_all(folder).unwrap();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArtLibrary {
+    pieces: BTreeMap<String, String>,
+}
+
+impl ArtLibrary {
+    /// Loads every .txt file in the given folder and the folders inside it.
+    pub fn open<P: AsRef<Path>>(folder: P) -> io::Result<ArtLibrary> {
+        let mut library: ArtLibrary = ArtLibrary::default();
+        library.load_folder(folder.as_ref(), "")?;
+        Ok(library)
+    }
+
+    // Adds the art in a folder, naming each piece with the given prefix followed by its path inside the folder.
+    fn load_folder(&mut self, folder: &Path, prefix: &str) -> io::Result<()> {
+        for entry in fs::read_dir(folder)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                let name: String = path.file_name().map_or(String::default(), |name| name.to_string_lossy().into_owned());
+                self.load_folder(&path, &format!("{}{}/", prefix, name))?;
+            } else if path.extension().is_some_and(|extension| extension == "txt") {
+                let name: String = path.file_stem().map_or(String::default(), |name| name.to_string_lossy().into_owned());
+                self.pieces.insert(format!("{}{}", prefix, name), load_art(&path)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a piece of art under the given name, replacing any piece that already had it.
+    pub fn insert(&mut self, name: &str, art: &str) {
+        self.pieces.insert(String::from(name), String::from(art));
+    }
+
+    /// Looks up a piece of art by name, falling back to the built-in gallery.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.pieces.get(name).map(String::as_str).or_else(|| get(name))
+    }
+
+    /// Lists the names of every piece of art loaded into the library, in alphabetical order.
+    pub fn names(&self) -> Vec<&str> {
+        self.pieces.keys().map(String::as_str).collect()
+    }
+}
+
+/// The four suits of a deck of playing cards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Suit {
+    Spades,
+    Hearts,
+    Diamonds,
+    Clubs,
+}
+
+impl Suit {
+    /// Returns the art for this suit.
+    pub fn art(&self) -> &'static str {
+        match self {
+            Suit::Spades => SPADE,
+            Suit::Hearts => HEART,
+            Suit::Diamonds => DIAMOND,
+            Suit::Clubs => CLUB,
+        }
+    }
+}
+
+/// Returns the face of a die showing n. Faces 1 through 6 use pips, anything else shows the number.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(die_face(3), DIE_3);
+/// assert_eq!(die_face(12), "+-------+\n|       |\n|  12   |\n|       |\n+-------+");
+/// ```
+pub fn die_face(n: u32) -> String {
+    match n {
+        1 => String::from(DIE_1),
+        2 => String::from(DIE_2),
+        3 => String::from(DIE_3),
+        4 => String::from(DIE_4),
+        5 => String::from(DIE_5),
+        6 => String::from(DIE_6),
+        _ => format!("+-------+\n|       |\n|{:^7}|\n|       |\n+-------+", n),
+    }
+}
+
+/// Returns a playing card of the given rank and suit, where rank 1 is an ace and 11 through 13 are the face cards.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// let card: String = playing_card(12, Suit::Diamonds);
+/// assert!(card.starts_with(".---------.\n|Q        |"));
+/// assert!(card.ends_with("|        Q|\n'---------'"));
+/// ```
+pub fn playing_card(rank: u8, suit: Suit) -> String {
+    let rank: String = match rank {
+        1 => String::from("A"),
+        11 => String::from("J"),
+        12 => String::from("Q"),
+        13 => String::from("K"),
+        n => n.to_string(),
+    };
+
+    let mut lines: Vec<String> = vec![String::from(".---------."), format!("|{:<9}|", rank)];
+    for line in suit.art().lines() {
+        lines.push(format!("|  {:<7}|", line));
+    }
+    lines.push(format!("|{:>9}|", rank));
+    lines.push(String::from("'---------'"));
+
+    lines.join("\n")
+}
+
+/// Places pieces of art next to each other with the given number of spaces between them, lining up their top rows.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(beside(&["ab\nc", "d"], 1), "ab d\nc   ");
+/// ```
+pub fn beside(pieces: &[&str], gap: usize) -> String {
+    let height: usize = pieces.iter().map(|piece| dimensions(piece).1).max().unwrap_or(0);
+    let mut rows: Vec<String> = vec![String::new(); height];
+
+    for (i, piece) in pieces.iter().enumerate() {
+        let padded: String = pad_to(piece, dimensions(piece).0, height, Align::Start);
+        for (row, line) in rows.iter_mut().zip(padded.lines()) {
+            if i > 0 { row.push_str(&" ".repeat(gap)); }
+            row.push_str(line);
+        }
+    }
+
+    rows.join("\n")
+}
+
+/// Where art sits inside the space it's padded to. Start is the top left, and End is the bottom right.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+}
+
+/// Measures art as (columns, rows), where columns is the display width of its widest line.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(dimensions("ab\nc"), (2, 2));
+/// assert_eq!(dimensions("日本\n-"), (4, 2));
+/// assert_eq!(dimensions(""), (0, 0));
+/// ```
+pub fn dimensions(art: &str) -> (usize, usize) {
+    (art.lines().map(display_width).max().unwrap_or(0), art.lines().count())
+}
+
+/// Pads art with spaces so it's exactly cols columns wide and rows rows tall, placing it according to align.
+/// The art moves as a block, so its lines keep their positions relative to each other.
+/// Art that's already bigger than that in either direction is left as is in that direction.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(pad_to("ab\nc", 4, 3, Align::Start), "ab  \nc   \n    ");
+/// assert_eq!(pad_to("ab\nc", 4, 4, Align::Center), "    \n ab \n c  \n    ");
+/// assert_eq!(pad_to("ab\nc", 3, 2, Align::End), " ab\n c ");
+/// ```
+pub fn pad_to(art: &str, cols: usize, rows: usize, align: Align) -> String {
+    let (width, height): (usize, usize) = dimensions(art);
+    let lead = |extra: usize| match align {
+        Align::Start => 0,
+        Align::Center => extra / 2,
+        Align::End => extra,
+    };
+    let top: usize = lead(rows.saturating_sub(height));
+    let left: usize = lead(cols.saturating_sub(width));
+
+    let blank: String = " ".repeat(cols);
+    let mut lines: Vec<String> = vec![blank.clone(); top];
+    for line in art.lines() {
+        let right: usize = cols.saturating_sub(left + display_width(line));
+        lines.push(format!("{}{}{}", " ".repeat(left), line, " ".repeat(right)));
+    }
+    lines.resize(lines.len().max(rows), blank);
+
+    lines.join("\n")
+}
+
+/// Encodes data as a QR code drawn with half-block characters, two modules tall per line so it stays compact enough for the window.
+/// The light modules and quiet zone are the drawn characters, so it scans correctly as light text on a dark background.
+/// Returns None if the data is too long to encode.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// let code: String = qr_code("https://example.com").unwrap();
+/// assert_eq!(code.lines().count(), 15);
+/// ```
+#[cfg(feature="qr")]
+pub fn qr_code(data: &str) -> Option<String> {
+    const QUIET_ZONE: usize = 2;
+
+    let code: qrcode::QrCode = qrcode::QrCode::new(data).ok()?;
+    let width: usize = code.width();
+    let size: usize = width + QUIET_ZONE * 2;
+    let modules: Vec<qrcode::Color> = code.to_colors();
+    let is_light = |x: usize, y: usize| -> bool {
+        if x < QUIET_ZONE || y < QUIET_ZONE || x >= width + QUIET_ZONE || y >= width + QUIET_ZONE { return true; }
+        modules[(y - QUIET_ZONE) * width + (x - QUIET_ZONE)] == qrcode::Color::Light
+    };
+
+    let mut lines: Vec<String> = Vec::new();
+    for y in (0..size).step_by(2) {
+        let line: String = (0..size).map(|x| {
+            match (is_light(x, y), y + 1 < size && is_light(x, y + 1)) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => ' ',
+            }
+        }).collect();
+        lines.push(line);
+    }
+
+    Some(lines.join("\n"))
+}
+
+const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Draws values as a single line of block characters, scaled between the smallest and largest value.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(sparkline(&[0.0, 1.0, 2.0, 7.0]), "▁▂▃█");
+/// assert_eq!(sparkline(&[5.0, 5.0]), "▅▅");
+/// ```
+pub fn sparkline(values: &[f64]) -> String {
+    let min: f64 = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max: f64 = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let top: f64 = (SPARK_CHARS.len() - 1) as f64;
+
+    values.iter().map(|value| {
+        let level: f64 = if max > min { (value - min) / (max - min) * top } else { top / 2.0 };
+        SPARK_CHARS[level.round() as usize]
+    }).collect()
+}
+
+/// Draws a horizontal bar chart with one labeled bar per value. The largest value's bar is width characters long,
+/// and negative values get no bar.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(bar_chart(&["cats", "dogs"], &[2.0, 4.0], 4), "cats ██ 2\ndogs ████ 4");
+/// ```
+pub fn bar_chart(labels: &[&str], values: &[f64], width: usize) -> String {
+    let label_width: usize = labels.iter().map(|label| label.chars().count()).max().unwrap_or(0);
+    let max: f64 = values.iter().cloned().fold(0.0, f64::max);
+
+    labels.iter().zip(values).map(|(label, value)| {
+        let length: usize = if max > 0.0 { (value.max(0.0) / max * width as f64).round() as usize } else { 0 };
+        format!("{:<label_width$} {} {}", label, "█".repeat(length), value, label_width = label_width)
+    }).collect::<Vec<String>>().join("\n")
+}
+
+const BIG_DIGITS: [[&str; 3]; 10] = [
+    [" _ ", "| |", "|_|"],
+    ["   ", "  |", "  |"],
+    [" _ ", " _|", "|_ "],
+    [" _ ", " _|", " _|"],
+    ["   ", "|_|", "  |"],
+    [" _ ", "|_ ", " _|"],
+    [" _ ", "|_ ", "|_|"],
+    [" _ ", "  |", "  |"],
+    [" _ ", "|_|", "|_|"],
+    [" _ ", "|_|", " _|"],
+];
+const BIG_COLON: [&str; 3] = [" ", "o", "o"];
+const BIG_SPACE: [&str; 3] = ["   ", "   ", "   "];
+
+/// Renders digits and colons as large seven-segment style art, three lines tall. Any other character becomes a blank space.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(big_digits("1:05"), "       _   _ \n  | o | | |_ \n  | o |_|  _|");
+/// ```
+pub fn big_digits(text: &str) -> String {
+    let glyphs: Vec<[&str; 3]> = text.chars().map(|c| match c {
+        ':' => BIG_COLON,
+        _ => c.to_digit(10).map_or(BIG_SPACE, |digit| BIG_DIGITS[digit as usize]),
+    }).collect();
+
+    (0..3).map(|row| {
+        glyphs.iter().map(|glyph| glyph[row]).collect::<Vec<&str>>().join(" ")
+    }).collect::<Vec<String>>().join("\n")
+}
+
+/// A character ramp of shading blocks, from darkest to lightest.
+pub const SHADE_RAMP: &str = " .:░▒▓█";
+
+/// A character ramp of plain ascii, from darkest to lightest.
+pub const ASCII_RAMP: &str = " .:-=+*#%@";
+
+const BAYER_4X4: [[f32; 4]; 4] = [
+    [ 0.0,  8.0,  2.0, 10.0],
+    [12.0,  4.0, 14.0,  6.0],
+    [ 3.0, 11.0,  1.0,  9.0],
+    [15.0,  7.0, 13.0,  5.0],
+];
+
+/// How brightness between two ramp characters is approximated when converting grayscale to art.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dither {
+    /// Each pixel becomes the nearest ramp character.
+    None,
+    /// A 4x4 Bayer threshold pattern, which gives a regular crosshatched texture.
+    Ordered,
+    /// Floyd-Steinberg error diffusion, which gives the smoothest gradients for photos.
+    FloydSteinberg,
+}
+
+/// Converts grayscale pixels, given row by row with 0.0 as black and 1.0 as white, into art using the given character ramp
+/// from darkest to lightest.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// let gradient: [f32; 4] = [0.0, 0.33, 0.67, 1.0];
+/// assert_eq!(from_luminance(&gradient, 2, " .:#", Dither::None), " .\n:#");
+/// assert_eq!(from_luminance(&[0.5; 4], 4, " #", Dither::Ordered), " # #");
+/// ```
+pub fn from_luminance(pixels: &[f32], width: usize, ramp: &str, dither: Dither) -> String {
+    let ramp: Vec<char> = ramp.chars().collect();
+    if width == 0 || ramp.is_empty() { return String::new(); }
+    let top: f32 = (ramp.len() - 1) as f32;
+
+    let mut values: Vec<f32> = pixels.iter().map(|pixel| pixel.clamp(0.0, 1.0)).collect();
+    let height: usize = values.len().div_ceil(width);
+    let mut lines: Vec<String> = Vec::new();
+
+    for y in 0..height {
+        let mut line: String = String::new();
+        for x in 0..width {
+            let i: usize = y * width + x;
+            if i >= values.len() { break; }
+
+            let value: f32 = match dither {
+                Dither::Ordered => values[i] + (BAYER_4X4[y % 4][x % 4] + 0.5) / 16.0 / top - 0.5 / top,
+                _ => values[i],
+            };
+            let level: usize = (value * top).round().clamp(0.0, top) as usize;
+
+            if dither == Dither::FloydSteinberg {
+                let error: f32 = values[i] - level as f32 / top;
+                let mut spread = |dx: isize, dy: usize, weight: f32| {
+                    let nx: isize = x as isize + dx;
+                    let ni: usize = (y + dy) * width + nx as usize;
+                    if nx >= 0 && (nx as usize) < width && ni < values.len() { values[ni] += error * weight; }
+                };
+                spread(1, 0, 7.0 / 16.0);
+                spread(-1, 1, 3.0 / 16.0);
+                spread(0, 1, 5.0 / 16.0);
+                spread(1, 1, 1.0 / 16.0);
+            }
+
+            line.push(ramp[level]);
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+/// Converts an image file, like a PNG or JPEG, into art the given number of characters wide, using a character ramp from darkest to lightest
+/// like [ASCII_RAMP](constant.ASCII_RAMP.html) or [SHADE_RAMP](constant.SHADE_RAMP.html). Rows are squashed to make up for characters being
+/// taller than they're wide, images narrower than the width keep one character per pixel, and transparent pixels count as black.
+#[cfg(feature = "sprites")]
+pub fn from_image<P: AsRef<Path>>(path: P, width: usize, charset: &str) -> image::ImageResult<String> {
+    let image = image::open(path)?;
+    let size: (usize, usize) = (image.width() as usize, image.height() as usize);
+    let sheet: crate::sprites::SpriteSheet = crate::sprites::SpriteSheet::from_image(&image, size);
+    Ok(sheet.cell_art(0, width, charset, Dither::FloydSteinberg).unwrap_or_default())
+}
+
+/// Formats bytes as a classic hex dump, sixteen bytes per line, with the offset, the bytes in hex, and the printable ascii.
+/// ```
+/// # use simpleterm_profile::art::*;
+/// assert_eq!(hex_dump(b"Hello, world!\n"), "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a        |Hello, world!.|");
+/// ```
+pub fn hex_dump(bytes: &[u8]) -> String {
+    bytes.chunks(16).enumerate().map(|(row, chunk)| {
+        let mut hex: String = String::new();
+        for i in 0..16 {
+            if i == 8 { hex.push(' '); }
+            match chunk.get(i) {
+                Some(byte) => hex.push_str(&format!("{:02x} ", byte)),
+                None => hex.push_str("   "),
+            }
+        }
+
+        let ascii: String = chunk.iter().map(|byte| if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' }).collect();
+        format!("{:08x}  {} |{}|", row * 16, hex, ascii)
+    }).collect::<Vec<String>>().join("\n")
+}
+
+/// A sequence of art frames shown one after another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Animation {
+    /// The art for each frame, in order.
+    pub frames: Vec<String>,
+    /// How long each frame stays on screen.
+    pub frame_time: Duration,
+}
+
+impl Animation {
+    /// Creates an animation from the given art frames, showing each one for frame_time.
+    pub fn new(frames: &[&str], frame_time: Duration) -> Animation {
+        Animation {
+            frames: frames.iter().map(|frame| String::from(*frame)).collect(),
+            frame_time,
+        }
+    }
+
+    /// Returns the frame that should be on screen after the given amount of time has passed, looping forever.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::art::*;
+    /// let dance: Animation = Animation::new(&[DANCE_1, DANCE_2, DANCE_3], Duration::from_millis(100));
+    /// assert_eq!(dance.frame_at(Duration::from_millis(50)), DANCE_1);
+    /// assert_eq!(dance.frame_at(Duration::from_millis(250)), DANCE_3);
+    /// assert_eq!(dance.frame_at(Duration::from_millis(350)), DANCE_1);
+    /// ```
+    pub fn frame_at(&self, elapsed: Duration) -> &str {
+        if self.frames.is_empty() { return ""; }
+
+        let frame_millis: u128 = self.frame_time.as_millis().max(1);
+        let index: usize = ((elapsed.as_millis() / frame_millis) % self.frames.len() as u128) as usize;
+        &self.frames[index]
+    }
+}
+
+// One piece of an ArtScene.
+#[derive(Clone, Debug, PartialEq)]
+struct ScenePiece {
+    name: String,
+    animation: Animation,
+    offset: (usize, usize),
+    color: Option<Color>,
+}
+
+/// A little tableau built from several pieces of art, each placed at a (column, row) offset and drawn in its own color.
+/// Pieces are named so they can be moved or removed later, and can be animations, so a whole scene animates together.
+/// Pieces added later are drawn over earlier ones, and spaces in a piece are see-through.
+/// ```
+/// # use std::time::Duration;
+/// # use simpleterm_profile::{art::*, text::*};
+/// let mut scene: ArtScene = ArtScene::new()
+///     .piece("ground", "======", (0, 2), Some(EMERALD))
+///     .piece("cat", "/\\\n..", (1, 1), None);
+/// let (lines, styles) = scene.render(Duration::ZERO);
+/// assert_eq!(lines, vec!["      ", " /\\   ", "=..==="]);
+/// assert_eq!(styles[2][0].color, Some(EMERALD));
+/// assert_eq!(styles[2][1].color, None);
+///
+/// scene.move_piece("cat", (3, 0));
+/// assert_eq!(scene.render(Duration::ZERO).0, vec!["   /\\ ", "   .. ", "======"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ArtScene {
+    pieces: Vec<ScenePiece>,
+}
+
+impl ArtScene {
+    /// Creates an empty scene.
+    pub fn new() -> ArtScene {
+        ArtScene::default()
+    }
+
+    /// Adds a still piece of art at the given (column, row) offset, in the given color or the terminal's foreground color if None.
+    pub fn piece(self, name: &str, art: &str, offset: (usize, usize), color: Option<Color>) -> ArtScene {
+        self.animated_piece(name, Animation::new(&[art], Duration::from_secs(1)), offset, color)
+    }
+
+    /// Adds an animated piece of art at the given (column, row) offset, in the given color or the terminal's foreground color if None.
+    pub fn animated_piece(mut self, name: &str, animation: Animation, offset: (usize, usize), color: Option<Color>) -> ArtScene {
+        self.pieces.push(ScenePiece { name: String::from(name), animation, offset, color });
+        self
+    }
+
+    /// Moves the named piece to a new offset. Returns false if there's no piece with that name.
+    pub fn move_piece(&mut self, name: &str, offset: (usize, usize)) -> bool {
+        match self.pieces.iter_mut().find(|piece| piece.name == name) {
+            Some(piece) => {
+                piece.offset = offset;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Removes the named piece from the scene. Returns false if there's no piece with that name.
+    pub fn remove_piece(&mut self, name: &str) -> bool {
+        let count: usize = self.pieces.len();
+        self.pieces.retain(|piece| piece.name != name);
+        self.pieces.len() != count
+    }
+
+    /// Draws every piece as it looks after the given amount of time, returning the lines of the scene and a style for each character.
+    pub fn render(&self, elapsed: Duration) -> (Vec<String>, Vec<Vec<Style>>) {
+        let frames: Vec<(&ScenePiece, &str)> = self.pieces.iter().map(|piece| (piece, piece.animation.frame_at(elapsed))).collect();
+        let width: usize = frames.iter().map(|(piece, frame)| piece.offset.0 + frame.lines().map(|line| line.chars().count()).max().unwrap_or(0)).max().unwrap_or(0);
+        let height: usize = frames.iter().map(|(piece, frame)| piece.offset.1 + frame.lines().count()).max().unwrap_or(0);
+
+        let mut cells: Vec<Vec<char>> = vec![vec![' '; width]; height];
+        let mut styles: Vec<Vec<Style>> = vec![vec![Style::default(); width]; height];
+        for (piece, frame) in frames {
+            for (row, line) in frame.lines().enumerate() {
+                for (col, c) in line.chars().enumerate() {
+                    if c == ' ' { continue; }
+                    let (x, y): (usize, usize) = (piece.offset.0 + col, piece.offset.1 + row);
+                    cells[y][x] = c;
+                    styles[y][x] = Style { color: piece.color, ..Style::default() };
+                }
+            }
+        }
+
+        (cells.into_iter().map(|row| row.into_iter().collect()).collect(), styles)
+    }
+}
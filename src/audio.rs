@@ -0,0 +1,73 @@
+use rodio::{buffer::SamplesBuffer, Decoder, OutputStream, OutputStreamHandle, Source};
+use std::{error::Error, f32::consts::TAU, fs::File, io::BufReader, path::Path, sync::Arc, time::Duration};
+
+const SAMPLE_RATE: u32 = 44100;
+const CLICK_PITCH: f32 = 1800.0;
+const CLICK_TIME: Duration = Duration::from_millis(12);
+const CHIME_PITCH: f32 = 880.0;
+const CHIME_TIME: Duration = Duration::from_millis(180);
+
+/// A short sound held in memory so it can be played again and again without reading the file each time.
+#[derive(Clone, Debug)]
+pub struct Sound {
+    channels: u16,
+    sample_rate: u32,
+    samples: Arc<[f32]>,
+}
+
+impl Sound {
+    /// Loads a sound from a WAV or Ogg Vorbis file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Sound, Box<dyn Error>> {
+        let decoder = Decoder::new(BufReader::new(File::open(path)?))?;
+        let channels: u16 = decoder.channels();
+        let sample_rate: u32 = decoder.sample_rate();
+        Ok(Sound { channels, sample_rate, samples: decoder.convert_samples::<f32>().collect() })
+    }
+
+    /// Makes a beep of the given pitch in hertz, fading out over the given length so it doesn't pop at the end.
+    pub fn beep(pitch: f32, length: Duration) -> Sound {
+        let count: usize = (length.as_secs_f32() * SAMPLE_RATE as f32) as usize;
+        let samples: Arc<[f32]> = (0..count).map(|i| {
+            let fade: f32 = 1.0 - i as f32 / count as f32;
+            (i as f32 / SAMPLE_RATE as f32 * pitch * TAU).sin() * fade * 0.5
+        }).collect();
+        Sound { channels: 1, sample_rate: SAMPLE_RATE, samples }
+    }
+
+    /// Returns how long the sound lasts.
+    pub fn length(&self) -> Duration {
+        Duration::from_secs_f64(self.samples.len() as f64 / self.channels.max(1) as f64 / self.sample_rate.max(1) as f64)
+    }
+}
+
+/// The sounds a terminal plays while typing: a click for each character and a chime when a message finishes,
+/// turned on with [enable_sounds](../terminal/struct.Terminal.html#method.enable_sounds).
+pub struct TypeSounds {
+    /// Played for each character typed, other than spaces.
+    pub click: Sound,
+    /// Played when a message told with [tell](../terminal/struct.Terminal.html#method.tell) finishes typing.
+    pub chime: Sound,
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+}
+
+impl TypeSounds {
+    /// Opens the default audio output with a beep for the click and a lower, longer beep for the chime.
+    pub fn new() -> Result<TypeSounds, Box<dyn Error>> {
+        let (stream, handle) = OutputStream::try_default()?;
+        Ok(TypeSounds {
+            click: Sound::beep(CLICK_PITCH, CLICK_TIME),
+            chime: Sound::beep(CHIME_PITCH, CHIME_TIME),
+            _stream: stream,
+            handle,
+        })
+    }
+
+    /// Plays the given sound at the given volume, from 0.0 for silent to 1.0 for full volume, without waiting for it to finish.
+    pub fn play(&self, sound: &Sound, volume: f32) {
+        if volume > 0.0 {
+            let source = SamplesBuffer::new(sound.channels, sound.sample_rate, sound.samples.to_vec()).amplify(volume.min(1.0));
+            let _ = self.handle.play_raw(source);
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use graphics::types::{Color, FontSize};
+use piston::{CloseArgs, Event, Input, RenderEvent, Size};
+use std::{any::Any, error::Error, time::Duration};
+
+use crate::{answer::Answer, draw::{Anchor, BorderAnimation, Split}, keyboard::VirtualKeyboard, terminal::Terminal, text::*, widgets::Corner};
+
+/// The script-level operations every terminal backend supports.
+/// 
+/// Scripts and effects written against this trait can run on any backend. The windowed [Terminal](../terminal/struct.Terminal.html)
+/// is the default backend, and it can draw on any [Screen](trait.Screen.html).
+/// 
+/// ```no_run
+/// # use simpleterm_profile::{backend::Backend, text::*};
+/// # use simpleterm_profile::terminal::Terminal;
+/// fn greet<B: Backend>(term: &mut B) {
+///     let name: String = term.ask("What is your name?").text().unwrap_or_default();
+///     term.tell(&format!("Hello, {}!", name));
+/// }
+/// 
+/// let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+/// greet(&mut term);
+/// ```
+pub trait Backend {
+    /// Returns false once the backend has been closed and the script should end.
+    fn is_active(&self) -> bool;
+
+    /// Types out the given message, then waits for the user to type something and returns how the question ended.
+    /// If the backend is closed before input can be returned, returns Answer::WindowClosed.
+    fn ask(&mut self, message: &str) -> Answer;
+
+    /// Displays an ascii art string for the given amount of time.
+    fn display_art(&mut self, art: &str, time: Duration);
+
+    /// Types out the given message, then waits for the given amount of time to continue.
+    fn show(&mut self, message: &str, time: Duration);
+
+    /// Types out the given message, then waits for the user to continue.
+    fn tell(&mut self, message: &str);
+
+    /// Changes the background and foreground colors used by the next text command.
+    fn set_colors(&mut self, bgc: Color, fgc: Color);
+}
+
+impl Backend for Terminal {
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn ask(&mut self, message: &str) -> Answer {
+        Terminal::ask(self, message)
+    }
+
+    fn display_art(&mut self, art: &str, time: Duration) {
+        Terminal::display_art(self, art, time)
+    }
+
+    fn show(&mut self, message: &str, time: Duration) {
+        Terminal::show(self, message, time)
+    }
+
+    fn tell(&mut self, message: &str) {
+        Terminal::tell(self, message)
+    }
+
+    fn set_colors(&mut self, bgc: Color, fgc: Color) {
+        Terminal::set_colors(self, bgc, fgc)
+    }
+}
+
+/// The window a [Terminal](../terminal/struct.Terminal.html) draws its frames on and reads its events from.
+/// 
+/// The terminal works out everything a frame shows as a [Scene](struct.Scene.html) and leaves drawing it to the screen,
+/// so other screens can be swapped in with [with_screen](../terminal/struct.Terminal.html#method.with_screen).
+/// Events are the input types of piston's pistoncore-input crate, which has no window or graphics code of its own,
+/// so a screen only has to translate its own events into them and doesn't need piston_window at all.
+/// Screens that draw with piston's graphics can draw a scene with [draw_scene](../draw/fn.draw_scene.html).
+/// 
+/// The screens that ship with the crate are [PistonScreen](../window/struct.PistonScreen.html), the default,
+/// [SoftwareScreen](../software/struct.SoftwareScreen.html), a plain winit window drawn with the CPU for machines without OpenGL,
+/// TtyScreen in the tty module, which draws inside a real terminal with crossterm, CanvasScreen in the web module for the browser,
+/// and [Headless](struct.Headless.html) for tests. No wgpu or SDL2 screen ships with the crate; those would have to be written by the script.
+/// 
+/// Window controls that a screen can't support, like choosing a monitor, do nothing by default.
+pub trait Screen {
+    /// Returns the next event, waiting until there is one, or None once the window has closed.
+    fn next_event(&mut self) -> Option<Event>;
+
+    /// Returns the next event if one is ready, or None straight away if there isn't one yet, for the terminal's non-blocking mode.
+    /// Once the window has closed this returns a close event. By default it waits for the next event,
+    /// so screens that can check for events without waiting should override it.
+    fn poll_event(&mut self) -> Option<Event> {
+        Some(self.next_event().unwrap_or(Event::Input(Input::Close(CloseArgs), None)))
+    }
+
+    /// Draws the scene if the event is a render event, and returns true if a frame was drawn.
+    fn draw(&mut self, event: &Event, scene: &Scene) -> bool;
+
+    /// Returns the size of the window, in points.
+    fn size(&self) -> Size;
+
+    /// Resizes the window to the given size, in points.
+    fn set_size(&mut self, size: Size);
+
+    /// Closes the window, so the terminal gets a close event and no more after it.
+    fn close(&mut self);
+
+    /// Closes the window and opens a new one the given size, in points.
+    fn reopen(&mut self, size: Size);
+
+    /// Loads the given font file from the resources folder so text can be drawn in it, or returns an error if it can't be loaded.
+    fn load_font(&mut self, font: &str) -> Result<(), Box<dyn Error>>;
+
+    /// Returns how wide the given text is when drawn in the given font at the given size, or None if it can't be measured.
+    fn text_width(&mut self, font: &str, size: FontSize, text: &str) -> Option<f64>;
+
+    /// Returns the (x, y) screen position of the window's top left corner, if it has one.
+    fn position(&self) -> Option<(i32, i32)> {
+        None
+    }
+
+    /// Moves the window so its top left corner is at the given (x, y) screen position.
+    fn set_position(&mut self, _x: i32, _y: i32) {}
+
+    /// Returns the number of monitors the window can be shown on.
+    fn monitor_count(&self) -> usize {
+        0
+    }
+
+    /// Returns the (x, y, width, height) of the monitor with the given index, or the window's current monitor if given None.
+    fn monitor_bounds(&self, _index: Option<usize>) -> Option<(f64, f64, f64, f64)> {
+        None
+    }
+
+    /// Makes the window fullscreen on the monitor with the given index, or returns it to a normal window if given None.
+    fn set_fullscreen(&mut self, _monitor: Option<usize>) {}
+
+    /// Keeps the window above all other windows if given true, or lets it be covered normally if given false.
+    fn set_always_on_top(&mut self, _always_on_top: bool) {}
+
+    /// Minimizes the window to the taskbar if given true, or restores it if given false.
+    fn set_minimized(&mut self, _minimized: bool) {}
+
+    /// Asks the window manager to draw the user's attention to the window, such as by flashing its taskbar entry.
+    fn request_attention(&mut self) {}
+
+    /// Returns the screen as Any, so code that knows what kind of screen it has can reach it, or None if the screen doesn't allow that.
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        None
+    }
+}
+
+/// Everything drawn in one frame of a terminal, worked out by [render_frame](../terminal/struct.Terminal.html#method.render_frame)
+/// for a [Screen](trait.Screen.html) to draw. Positions are in points, laid out for a window the given size.
+#[derive(Clone, Copy, Debug)]
+pub struct Scene<'a> {
+    /// The size the terminal is laid out at, which is never smaller than [MIN_WINDOW_SIZE](../constant.MIN_WINDOW_SIZE.html).
+    pub size: Size,
+    /// The background color.
+    pub bg: Color,
+    /// The foreground color, used for the border, input line, art, and panels.
+    pub fg: Color,
+    /// The color messages and credits are drawn in.
+    pub message_color: Color,
+    /// The font file everything but the on-screen keyboard is drawn in.
+    pub font: &'a str,
+    /// The monospace font file the on-screen keyboard is drawn in.
+    pub keyboard_font: &'a str,
+    /// The font size of messages, credits, and the input line.
+    pub font_size: FontSize,
+    /// How far apart lines of messages and credits are drawn, as a multiple of the font size.
+    pub spacing: f64,
+    /// What's drawn inside the terminal box.
+    pub content: SceneContent<'a>,
+    /// Whether blinking text is visible this frame.
+    pub blink_on: bool,
+    /// How far the terminal has faded to the background color, from 0.0 to 1.0.
+    pub fade: f32,
+    /// How the border around the terminal text is animated.
+    pub border: BorderAnimation,
+    /// How long the terminal has been open, for animations.
+    pub clock: Duration,
+    /// Whether scanlines are drawn.
+    pub scanlines: bool,
+    /// Whether the border is lit up, like a signal lamp sending Morse code.
+    pub signal: bool,
+    /// Whether the screen is flashing for a bell.
+    pub bell: bool,
+    /// The chat bubbles drawn, and whether each has a box drawn around it, if the chat conversation is shown.
+    pub chat: Option<(&'a [Bubble], bool)>,
+    /// The color of the "> " marker at the start of the input line, if it's drawn.
+    pub input_marker: Option<Color>,
+    /// The text drawn on the input line, if any.
+    pub input: Option<&'a str>,
+    /// A completion drawn dimmed on the input line right after the first text, if any.
+    pub ghost: Option<(&'a str, &'a str)>,
+    /// The on-screen keyboard drawn above the input line, if any.
+    pub keyboard: Option<&'a VirtualKeyboard>,
+    /// Labels pinned to anchors on the terminal.
+    pub pins: &'a [(Anchor, String)],
+    /// The (label, value) readings shown in the widget panel, which isn't drawn if there are none.
+    pub widgets: &'a [(String, String)],
+    /// Which corner the widget panel is drawn in.
+    pub widget_corner: Corner,
+    /// The label and fraction from 0.0 to 1.0 of the progress bar, if one is shown.
+    pub progress: Option<(&'a str, f64)>,
+    /// The caption of the spinner, if one is shown.
+    pub spinner: Option<&'a str>,
+    /// The (key, description) pairs shown in the help box, if it's open.
+    pub help: Option<&'a [(String, String)]>,
+    /// How the frame is split in two, and how far from 0.0 to 1.0 the halves have slid off the window, for a split transition.
+    pub split: Option<(Split, f64)>,
+}
+
+/// What's drawn inside the terminal box in a [Scene](struct.Scene.html).
+#[derive(Clone, Copy, Debug)]
+pub enum SceneContent<'a> {
+    /// Message lines with a style for each character, drawn from the top of the terminal moved down by offset, like when scrolling.
+    /// If there's a gutter, its line numbers start from first_line.
+    Message {
+        /// The lines of the message.
+        lines: &'a [String],
+        /// The style of each character, by line.
+        styles: &'a [Vec<Style>],
+        /// How far below the top of the terminal the message is drawn.
+        offset: f64,
+        /// The gutter of line numbers and the number of the first line, if there is one.
+        gutter: Option<(Gutter, usize)>,
+    },
+    /// Lines of art with a style for each character, centered on the terminal in the given font size.
+    Art {
+        /// The lines of art.
+        lines: &'a [String],
+        /// The style of each character, by line.
+        styles: &'a [Vec<Style>],
+        /// The font size the art is drawn in.
+        font_size: FontSize,
+    },
+    /// Lines centered across the terminal, with the first line's baseline the given height from the top of the window, like rolling credits.
+    Credits {
+        /// The lines of the credits.
+        lines: &'a [String],
+        /// How far below the top of the window the first line's baseline is.
+        top: f64,
+    },
+}
+
+/// A screen with no window, for running a terminal where nothing needs to be seen, like in tests.
+/// It has no events of its own, so the terminal needs an [input source](../terminal/struct.Terminal.html#method.set_input_source)
+/// to drive it. Nothing is drawn, and text is measured as if every column were half the font size wide.
+/// 
+/// ```
+/// # use std::time::Duration;
+/// # use piston_window::Key;
+/// # use simpleterm_profile::{backend::Headless, input::ScriptedInput, text::*};
+/// # use simpleterm_profile::terminal::Terminal;
+/// let mut term: Terminal = Terminal::with_screen(Box::new(Headless::new((800, 600))), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+/// term.type_time = Duration::ZERO;
+/// term.set_input_source(ScriptedInput::new().frames(2).text("Ada").press(Key::Return));
+/// assert_eq!(term.ask("What is your name?").text(), Some(String::from("Ada")));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Headless {
+    size: Size,
+}
+
+impl Headless {
+    /// Creates a screen laid out like a window of the given (width, height).
+    pub fn new(size: (u32, u32)) -> Headless {
+        Headless { size: size.into() }
+    }
+}
+
+impl Screen for Headless {
+    fn next_event(&mut self) -> Option<Event> {
+        None
+    }
+
+    fn draw(&mut self, event: &Event, _scene: &Scene) -> bool {
+        event.render_args().is_some()
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn close(&mut self) {}
+
+    fn reopen(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn load_font(&mut self, _font: &str) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    fn text_width(&mut self, _font: &str, size: FontSize, text: &str) -> Option<f64> {
+        Some(display_width(text) as f64 * size as f64 / 2.0)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+/// Opens a [Terminal](../terminal/struct.Terminal.html) with the given title, colors, and font info, the same way as
+/// [new](../terminal/struct.Terminal.html#method.new): in a window, drawn with the CPU when there's no usable OpenGL driver,
+/// or inside the real terminal the program was started from when the tty feature is enabled. Panics if neither can be opened.
+/// 
+/// ```no_run
+/// # use simpleterm_profile::{backend, text::*};
+/// let mut term = backend::open("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+/// term.tell("This works with or without a window!");
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn open(title: &str, size: (u32, u32), bg: Color, fg: Color, font: &str, font_size: u32) -> Box<dyn Backend> {
+    match Terminal::try_new(title, size, bg, fg, font, font_size) {
+        Ok(term) => Box::new(term),
+        Err(e) => panic!("Couldn't create a terminal: {}", e),
+    }
+}
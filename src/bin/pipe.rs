@@ -0,0 +1,7 @@
+use simpleterm_profile::{text::*, terminal::Terminal};
+
+fn main() {
+    // Stream whatever is piped in onto the terminal, e.g. "ls -la | cargo run --bin pipe".
+    let mut term: Terminal = Terminal::new("simpleterm pipe", (800, 600), DARK_GREY, EMERALD, "LeagueSpartan-Regular.ttf", 24);
+    term.pipe_stdin();
+}
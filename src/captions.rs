@@ -0,0 +1,107 @@
+use std::{fs, io, path::Path, time::Duration};
+
+/// A caption shown from its start time until its end time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cue {
+    /// When the caption appears, from the start of the track.
+    pub start: Duration,
+    /// When the caption disappears, from the start of the track.
+    pub end: Duration,
+    /// The text of the caption, which may span several lines.
+    pub text: String,
+}
+
+/// A track of timed captions, like the subtitles for a video, played with
+/// [play_captions](../terminal/struct.Terminal.html#method.play_captions).
+/// ```
+/// # use std::time::Duration;
+/// # use simpleterm_profile::captions::*;
+/// let track: CaptionTrack = CaptionTrack::parse("1\n00:00:01,000 --> 00:00:03,500\nHello there.\n\n2\n00:00:04,000 --> 00:00:06,000\nGeneral <i>Kenobi!</i>\n");
+/// assert_eq!(track.cues.len(), 2);
+/// assert_eq!(track.cues[1].text, "General Kenobi!");
+/// assert_eq!(track.cue_at(Duration::from_secs(2)), Some(0));
+/// assert_eq!(track.cue_at(Duration::from_millis(3750)), None);
+/// assert_eq!(track.end(), Duration::from_secs(6));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CaptionTrack {
+    /// The captions in the order they appear.
+    pub cues: Vec<Cue>,
+}
+
+impl CaptionTrack {
+    /// Reads captions from the text of an SRT or WebVTT file. Cue numbers, the WEBVTT header, notes, cue settings,
+    /// and formatting tags like &lt;i&gt; are left out, and blocks without a readable timing line are skipped.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::captions::*;
+    /// let track: CaptionTrack = CaptionTrack::parse("WEBVTT\n\nNOTE made by hand\n\n00:01.500 --> 00:02.000 align:start\nFirst line\nSecond line\n");
+    /// assert_eq!(track.cues, vec![Cue { start: Duration::from_millis(1500), end: Duration::from_secs(2), text: String::from("First line\nSecond line") }]);
+    /// ```
+    pub fn parse(text: &str) -> CaptionTrack {
+        let text: String = text.replace("\r\n", "\n");
+        let mut cues: Vec<Cue> = Vec::new();
+        for block in text.split("\n\n") {
+            let lines: Vec<&str> = block.lines().collect();
+            let timing: Option<usize> = lines.iter().position(|line| line.contains("-->"));
+            if let Some(i) = timing {
+                let mut times = lines[i].split("-->").map(|time| time.split_whitespace().next().and_then(parse_timestamp));
+                if let (Some(Some(start)), Some(Some(end))) = (times.next(), times.next()) {
+                    let text: String = lines[i + 1..].iter().map(|line| strip_tags(line)).collect::<Vec<String>>().join("\n");
+                    cues.push(Cue { start, end, text });
+                }
+            }
+        }
+        cues.sort_by_key(|cue| cue.start);
+        CaptionTrack { cues }
+    }
+
+    /// Loads captions from an SRT or WebVTT file.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<CaptionTrack> {
+        Ok(CaptionTrack::parse(&fs::read_to_string(path)?))
+    }
+
+    /// Returns the index of the cue showing at the given time, or None if no cue is showing.
+    pub fn cue_at(&self, time: Duration) -> Option<usize> {
+        self.cues.iter().rposition(|cue| cue.start <= time && time < cue.end)
+    }
+
+    /// Returns the index of the first cue that starts after the given time, if any.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::captions::*;
+    /// let track: CaptionTrack = CaptionTrack::parse("00:01.000 --> 00:02.000\nOne\n\n00:05.000 --> 00:06.000\nTwo\n");
+    /// assert_eq!(track.next_cue(Duration::from_millis(1500)), Some(1));
+    /// assert_eq!(track.next_cue(Duration::from_secs(5)), None);
+    /// ```
+    pub fn next_cue(&self, time: Duration) -> Option<usize> {
+        self.cues.iter().position(|cue| cue.start > time)
+    }
+
+    /// Returns when the last cue disappears.
+    pub fn end(&self) -> Duration {
+        self.cues.iter().map(|cue| cue.end).max().unwrap_or_default()
+    }
+}
+
+// Reads a timestamp like 01:02:03,456 or 02:03.456 as a duration.
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let (clock, millis): (&str, &str) = timestamp.rsplit_once(['.', ',']).unwrap_or((timestamp, "0"));
+    let seconds: u64 = clock.split(':').try_fold(0, |total: u64, part| Some(total * 60 + part.parse::<u64>().ok()?))?;
+    Some(Duration::from_secs(seconds) + Duration::from_millis(millis.parse().ok()?))
+}
+
+// Removes formatting tags like <i> and </b> from a line of caption text.
+fn strip_tags(line: &str) -> String {
+    let mut stripped: String = String::new();
+    let mut in_tag: bool = false;
+    for c in line.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {},
+        }
+    }
+    stripped
+}
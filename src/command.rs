@@ -0,0 +1,32 @@
+use graphics::types::Color;
+use std::time::Duration;
+
+/// Something for the terminal to do in its non-blocking mode, queued with
+/// [enqueue](../terminal/struct.Terminal.html#method.enqueue) and carried out a frame at a time by
+/// [update](../terminal/struct.Terminal.html#method.update). Each does the same thing as the blocking method of the same name.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    /// Types out the message, then waits for the user to continue.
+    Tell(String),
+    /// Types out the message, then waits for the given amount of time.
+    Show(String, Duration),
+    /// Types out the message, then waits for the user to type an answer, which update returns.
+    Ask(String),
+    /// Displays ascii art for the given amount of time.
+    DisplayArt(String, Duration),
+    /// Changes the background and foreground colors used by the commands after it.
+    SetColors(Color, Color),
+}
+
+impl Command {
+    /// Returns true if the command types out a message before waiting.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::command::Command;
+    /// assert!(Command::Tell(String::from("Hello!")).types());
+    /// assert!(!Command::DisplayArt(String::from("<3"), Duration::from_secs(1)).types());
+    /// ```
+    pub fn types(&self) -> bool {
+        matches!(self, Command::Tell(_) | Command::Show(_, _) | Command::Ask(_))
+    }
+}
@@ -1,31 +1,313 @@
-use piston_window::{*, types::{Color, FontSize}};
-use crate::{text::*, TEXT_OFFSET};
+#[cfg(not(target_arch = "wasm32"))]
+use gfx::{format::{DepthStencil, Srgba8}, handle::{DepthStencilView, RenderTargetView}, traits::FactoryExt, Factory};
+#[cfg(not(target_arch = "wasm32"))]
+use gfx_device_gl::{Device, Resources};
+use graphics::{*, character::CharacterCache, types::{Color, FontSize}};
+#[cfg(not(target_arch = "wasm32"))]
+use piston_window::{G2d, G2dTexture, GfxFactory, PistonWindow, Texture};
+use piston::Size;
+use std::{f32::consts::TAU, fmt::Debug, ops::Range, sync::atomic::{AtomicUsize, Ordering}, time::Duration};
+use texture::{CreateTexture, Filter, Format, TextureSettings};
+use crate::{backend::{Scene, SceneContent}, keyboard::VirtualKeyboard, text::*, widgets::Corner, MIN_WINDOW_SIZE, TEXT_OFFSET};
+
+const WIDGET_FONT_SIZE: FontSize = 14;
+const BORDER_CYCLE_TIME: f32 = 4.0;
+const BORDER_DASH: f64 = 20.0;
+const BORDER_MARCH_SPEED: f64 = 40.0;
+const SIGNAL_WIDTH: f64 = 10.0;
+const PROGRESS_STRIPE: f64 = 8.0;
+const PROGRESS_STRIPE_SPEED: f64 = 24.0;
+const PIN_MARGIN: f64 = 20.0;
+const PANEL_PADDING: f64 = 6.0;
+const SCANLINE_SPACING: usize = 3;
+// Scanline textures have this many rows per pixel of window height, so the half-pixel lines stay sharp.
+const SCANLINE_ROWS_PER_PIXEL: usize = 2;
+// Where the copies behind text with a backdrop are drawn, relative to the text.
+const SHADOW_OFFSETS: [(f64, f64); 1] = [(2.0, 2.0)];
+const OUTLINE_OFFSETS: [(f64, f64); 8] = [(-1.0, -1.0), (0.0, -1.0), (1.0, -1.0), (-1.0, 0.0), (1.0, 0.0), (-1.0, 1.0), (0.0, 1.0), (1.0, 1.0)];
+
+// How many characters of text have been drawn since the count was last taken.
+static GLYPHS_DRAWN: AtomicUsize = AtomicUsize::new(0);
+
+/// A glyph cache text can be drawn from with the graphics backend G, like piston_window's `Glyphs` with `G2d`.
+/// Every draw function takes its glyphs this way, so any [Screen](../backend/trait.Screen.html) can draw with them.
+pub trait FontCache<G: Graphics>: CharacterCache<Texture = G::Texture, Error: Debug> {}
+
+impl<G: Graphics, C: CharacterCache<Texture = G::Texture, Error: Debug>> FontCache<G> for C {}
+
+/// How the box around the terminal text moves over time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BorderAnimation {
+    /// The border stays a solid foreground color.
+    None,
+    /// The border's hue cycles around the color wheel.
+    ColorCycle,
+    /// The border is a dashed line that marches around the terminal.
+    MarchingDashes,
+    /// The border slowly brightens and dims.
+    Pulse,
+}
+
+/// Where a message sits vertically on the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Placement {
+    /// The message starts at the top of the terminal, as usual.
+    Top,
+    /// The message is centered between the top and bottom of the window, like a title card.
+    Center,
+    /// The message ends just above the input line.
+    Bottom,
+}
+
+/// Returns how far below its usual place a message with the given number of lines and line spacing is drawn for the given placement.
+/// Messages too tall to move are left at the top.
+/// ```
+/// # use simpleterm_profile::{draw::*, text::DEFAULT_LINE_SPACING};
+/// assert_eq!(placement_offset((800, 600).into(), 3, 32, DEFAULT_LINE_SPACING, Placement::Top), 0.0);
+/// assert_eq!(placement_offset((800, 600).into(), 1, 32, DEFAULT_LINE_SPACING, Placement::Center), 262.0);
+/// assert_eq!(placement_offset((800, 600).into(), 1, 32, DEFAULT_LINE_SPACING, Placement::Bottom), 472.0);
+/// assert_eq!(placement_offset((800, 600).into(), 40, 32, DEFAULT_LINE_SPACING, Placement::Bottom), 0.0);
+/// ```
+pub fn placement_offset(win_size: Size, lines: usize, font_size: FontSize, spacing: f64, placement: Placement) -> f64 {
+    let size: f64 = font_size as f64;
+    let between: f64 = lines.saturating_sub(1) as f64 * line_height(font_size, spacing);
+    let first_line: f64 = match placement {
+        Placement::Top => TEXT_OFFSET.1,
+        Placement::Center => (win_size.height - between - size * 0.75) / 2.0 + size * 0.75,
+        Placement::Bottom => (win_size.height - TEXT_OFFSET.1) + 20.0 - size * 1.5 - between,
+    };
+    (first_line - TEXT_OFFSET.1).max(0.0)
+}
+
+/// Returns where the first line of credits with the given number of lines is drawn, as the height of its baseline from the top of the window,
+/// once they've scrolled up the given distance. The credits start just below the bottom of the window and stop once the last line is
+/// centered, like a final title card.
+/// ```
+/// # use simpleterm_profile::{draw::*, text::{line_height, DEFAULT_LINE_SPACING}};
+/// assert_eq!(credits_offset((800, 600).into(), 3, 32, DEFAULT_LINE_SPACING, 0.0), 632.0);
+/// assert_eq!(credits_offset((800, 600).into(), 3, 32, DEFAULT_LINE_SPACING, 100.0), 532.0);
+/// assert_eq!(credits_offset((800, 600).into(), 3, 32, 1.5, 5000.0), credits_offset((800, 600).into(), 1, 32, 1.5, 5000.0) - line_height(32, 1.5) * 2.0);
+/// ```
+pub fn credits_offset(win_size: Size, lines: usize, font_size: FontSize, spacing: f64, traveled: f64) -> f64 {
+    let last_centered: f64 = TEXT_OFFSET.1 + placement_offset(win_size, 1, font_size, spacing, Placement::Center);
+    let end: f64 = last_centered - lines.saturating_sub(1) as f64 * line_height(font_size, spacing);
+    (win_size.height + font_size as f64 - traveled).max(end)
+}
+
+/// Where a pinned label is drawn on the terminal, set with [pin](../terminal/struct.Terminal.html#method.pin).
+/// A corner can be given wherever an anchor is expected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Anchor {
+    /// Tucked into a corner of the terminal, inside the border.
+    Corner(Corner),
+    /// Starting at an (x, y) point in the window, measured to the label's baseline.
+    Point(f64, f64),
+    /// Starting at the given column and row of the message text, counting from 0.
+    Cell(usize, usize),
+}
+
+impl From<Corner> for Anchor {
+    fn from(corner: Corner) -> Anchor {
+        Anchor::Corner(corner)
+    }
+}
+
+/// Returns the (x, y) point a pinned label of the given width is drawn at for an anchor, with the label's baseline at y.
+/// Pinned labels are drawn a little smaller than the given message font size, like the input line.
+/// ```
+/// # use simpleterm_profile::{draw::*, text::{line_height, DEFAULT_LINE_SPACING}, widgets::Corner};
+/// assert_eq!(anchor_position((800, 600).into(), Corner::TopRight.into(), 100.0, (32, DEFAULT_LINE_SPACING)), (680.0, 46.0));
+/// assert_eq!(anchor_position((800, 600).into(), Anchor::Point(5.0, 6.0), 100.0, (32, DEFAULT_LINE_SPACING)), (5.0, 6.0));
+/// assert_eq!(anchor_position((800, 600).into(), Anchor::Cell(0, 2), 100.0, (32, 1.5)), (25.0, 50.0 + 2.0 * line_height(32, 1.5)));
+/// ```
+pub fn anchor_position(win_size: Size, anchor: Anchor, width: f64, (font_size, spacing): (FontSize, f64)) -> (f64, f64) {
+    let size: f64 = pin_font_size(font_size) as f64;
+    match anchor {
+        Anchor::Corner(Corner::TopLeft) => (PIN_MARGIN, PIN_MARGIN + size),
+        Anchor::Corner(Corner::TopRight) => (win_size.width - width - PIN_MARGIN, PIN_MARGIN + size),
+        Anchor::Corner(Corner::BottomLeft) => (PIN_MARGIN, win_size.height - PIN_MARGIN),
+        Anchor::Corner(Corner::BottomRight) => (win_size.width - width - PIN_MARGIN, win_size.height - PIN_MARGIN),
+        Anchor::Point(x, y) => (x, y),
+        Anchor::Cell(column, row) => (TEXT_OFFSET.0 + column as f64 * font_size as f64 / 2.15, TEXT_OFFSET.1 + row as f64 * line_height(font_size, spacing)),
+    }
+}
+
+/// Draws pinned labels at their anchors in the foreground color, a little smaller than the given message font size.
+pub fn draw_pins<G: Graphics>(win_size: Size, pins: &[(Anchor, String)], glyphs: &mut impl FontCache<G>, (font_size, spacing): (FontSize, f64), colors: TextColors, context: Context, graphics: &mut G) {
+    let size: FontSize = pin_font_size(font_size);
+    for (anchor, label) in pins.iter() {
+        let width: f64 = glyphs.width(size, label).unwrap_or(0.0);
+        let (x, y): (f64, f64) = anchor_position(win_size, *anchor, width, (font_size, spacing));
+        count_glyphs(label);
+        text::Text::new_color(colors.fg, size).draw(
+            label,
+            glyphs,
+            &context.draw_state,
+            context.transform.trans(x, y),
+            graphics,
+        ).unwrap();
+    }
+}
+
+// The font size pinned labels are drawn at for the given message font size.
+fn pin_font_size(font_size: FontSize) -> FontSize {
+    font_size.saturating_sub(6).max(1)
+}
+
+/// The scanline patterns drawn behind and over the terminal text, pre-rendered to textures so each pass is a single quad.
+/// They're rendered for one window size and pair of colors, and need to be rendered again when either changes.
+pub struct Scanlines<T> {
+    key: (u32, Color, Color),
+    background: T,
+    foreground: T,
+}
+
+impl<T: ImageSize> Scanlines<T> {
+    /// Renders the scanline textures for the given window size and colors with the given texture factory,
+    /// or returns None if the textures can't be created.
+    pub fn new<F>(texture_context: &mut F, win_size: Size, bgc: Color, fgc: Color) -> Option<Scanlines<T>>
+    where
+        T: CreateTexture<F>,
+    {
+        let height: usize = (win_size.height - 30.0).max(1.0) as usize;
+        Some(Scanlines {
+            key: (win_size.height as u32, bgc, fgc),
+            background: scanline_texture(texture_context, height, background_line_color(bgc, fgc))?,
+            foreground: scanline_texture(texture_context, height, foreground_line_color(bgc))?,
+        })
+    }
+
+    /// Returns true if these scanlines were rendered for the given window size and colors.
+    pub fn matches(&self, win_size: Size, bgc: Color, fgc: Color) -> bool {
+        self.key == (win_size.height as u32, bgc, fgc)
+    }
+}
+
+/// Which way the terminal splits apart in a split transition, shown with [split_away](../terminal/struct.Terminal.html#method.split_away).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Split {
+    /// The terminal splits along a line across the middle, and the top half slides up as the bottom half slides down.
+    Horizontal,
+    /// The terminal splits along a line down the middle, and the left half slides left as the right half slides right.
+    Vertical,
+}
+
+/// A texture a whole frame can be rendered to, so it can then be drawn in pieces, like the halves of a split transition.
+/// It's created for one window size, and needs to be created again when that changes.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct SceneTarget {
+    key: [u32; 2],
+    texture: G2dTexture,
+    color: RenderTargetView<Resources, Srgba8>,
+    stencil: DepthStencilView<Resources, DepthStencil>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SceneTarget {
+    /// Creates a target the given size in pixels, or returns None if it can't be created.
+    pub fn new(factory: &mut GfxFactory, draw_size: [u32; 2]) -> Option<SceneTarget> {
+        let (width, height): (u16, u16) = (draw_size[0].clamp(1, u16::MAX as u32) as u16, draw_size[1].clamp(1, u16::MAX as u32) as u16);
+        let (surface, view, color) = factory.create_render_target::<Srgba8>(width, height).ok()?;
+        let stencil = factory.create_depth_stencil_view_only::<DepthStencil>(width, height).ok()?;
+        let sampler = factory.create_sampler_linear();
+        Some(SceneTarget { key: draw_size, texture: Texture { surface, sampler, view }, color, stencil })
+    }
+
+    /// Returns true if this target was created for the given size in pixels.
+    pub fn matches(&self, draw_size: [u32; 2]) -> bool {
+        self.key == draw_size
+    }
+
+    /// Renders a frame to this target instead of the window, calling draw to draw it the same way as
+    /// [draw_2d](https://docs.rs/piston_window/0.109.0/piston_window/struct.PistonWindow.html#method.draw_2d).
+    pub fn render<F>(&self, window: &mut PistonWindow, viewport: Viewport, draw: F)
+    where
+        F: FnOnce(Context, &mut G2d, &mut Device),
+    {
+        let PistonWindow { g2d, encoder, device, .. } = window;
+        g2d.draw(encoder, &self.color, &self.stencil, viewport, |c, g| draw(c, g, device));
+    }
+
+    /// Returns the texture frames are rendered to, which is stored bottom row first.
+    pub fn texture(&self) -> &G2dTexture {
+        &self.texture
+    }
+}
+
+/// Returns the part of a window of the given size each half of a split frame is cut from, and where it's drawn,
+/// once the halves have slid the given fraction of the way off the window.
+/// ```
+/// # use simpleterm_profile::draw::*;
+/// let [(top, top_at), (bottom, bottom_at)] = split_halves((800, 600).into(), Split::Horizontal, 0.5);
+/// assert_eq!((top, top_at), ([0.0, 0.0, 800.0, 300.0], [0.0, -150.0, 800.0, 300.0]));
+/// assert_eq!((bottom, bottom_at), ([0.0, 300.0, 800.0, 300.0], [0.0, 450.0, 800.0, 300.0]));
+///
+/// let [(_, left_at), (_, right_at)] = split_halves((800, 600).into(), Split::Vertical, 1.0);
+/// assert_eq!((left_at[0], right_at[0]), (-400.0, 800.0));
+/// ```
+pub fn split_halves(win_size: Size, split: Split, progress: f64) -> [([f64; 4], [f64; 4]); 2] {
+    let progress: f64 = if progress.is_nan() { 0.0 } else { progress.clamp(0.0, 1.0) };
+    let (width, height): (f64, f64) = (win_size.width, win_size.height);
+    match split {
+        Split::Horizontal => {
+            let (half, offset): (f64, f64) = (height / 2.0, height / 2.0 * progress);
+            [([0.0, 0.0, width, half], [0.0, -offset, width, half]), ([0.0, half, width, half], [0.0, half + offset, width, half])]
+        },
+        Split::Vertical => {
+            let (half, offset): (f64, f64) = (width / 2.0, width / 2.0 * progress);
+            [([0.0, 0.0, half, height], [-offset, 0.0, half, height]), ([half, 0.0, half, height], [half + offset, 0.0, half, height])]
+        },
+    }
+}
+
+/// Draws a whole rendered frame split in two, with the halves slid the given fraction of the way off the window.
+/// If flipped is true, the frame is stored bottom row first, the way render targets are.
+pub fn draw_split<G: Graphics>(win_size: Size, (frame, flipped): (&G::Texture, bool), split: Split, progress: f64, context: Context, graphics: &mut G) {
+    let (width, height): (u32, u32) = frame.get_size();
+    let scale: [f64; 2] = [width as f64 / win_size.width.max(1.0), height as f64 / win_size.height.max(1.0)];
+    for (from, to) in split_halves(win_size, split, progress) {
+        if flipped {
+            // Each half is cut from the flipped frame and drawn flipped back.
+            let source: [f64; 4] = [from[0] * scale[0], (win_size.height - from[1] - from[3]) * scale[1], from[2] * scale[0], from[3] * scale[1]];
+            Image::new().src_rect(source).rect([0.0, 0.0, to[2], to[3]])
+                .draw(frame, &context.draw_state, context.transform.trans(to[0], to[1] + to[3]).flip_v(), graphics);
+        } else {
+            let source: [f64; 4] = [from[0] * scale[0], from[1] * scale[1], from[2] * scale[0], from[3] * scale[1]];
+            Image::new().src_rect(source).rect(to).draw(frame, &context.draw_state, context.transform, graphics);
+        }
+    }
+}
+
+/// Returns the size to lay the terminal out at for a window of the given size, which is never smaller than
+/// [MIN_WINDOW_SIZE](../constant.MIN_WINDOW_SIZE.html), so tiny or minimized windows crop the terminal instead of tangling its layout.
+/// ```
+/// # use piston_window::Size;
+/// # use simpleterm_profile::draw::layout_size;
+/// assert_eq!(layout_size(Size { width: 0.0, height: 0.0 }), Size { width: 200.0, height: 120.0 });
+/// assert_eq!(layout_size(Size { width: 420.0, height: 100.0 }), Size { width: 420.0, height: 120.0 });
+/// ```
+pub fn layout_size(win_size: Size) -> Size {
+    Size { width: win_size.width.max(MIN_WINDOW_SIZE.0), height: win_size.height.max(MIN_WINDOW_SIZE.1) }
+}
 
 /// Displays a box around the text of the terminal, using the terminal's current colors and size.
-/// Also draws scanlines on the terminal background.
-pub fn draw_background(win_size: Size, bgc: Color, fgc: Color, lines: bool, context: Context, graphics: &mut G2d) {
+/// Also draws scanlines on the terminal background, if given any.
+pub fn draw_background<G: Graphics>(win_size: Size, bgc: Color, fgc: Color, lines: Option<&Scanlines<G::Texture>>, context: Context, graphics: &mut G) {
     rectangle(fgc, [10.0, 10.0, win_size.width - 20.0, win_size.height - 20.0], context.transform, graphics);
     rectangle(bgc, [15.0, 15.0, win_size.width - 30.0, win_size.height - 30.0], context.transform, graphics);
 
-    if lines {
-        let line_color: Color = if fgc.brighter_than(bgc) {
-            [bgc[0] - 0.2, bgc[1] - 0.2, bgc[2] - 0.2, 0.5]
-        } else {
-            [bgc[0] + 0.15, bgc[1] + 0.15, bgc[2] + 0.15, 0.4]
-        };
-        
-        for i in 0..((win_size.height - 30.0) as i32 / 3) {
-            rectangle(line_color, [15.0, (i * 3) as f64 + 15.0, win_size.width - 30.0, 0.5], context.transform, graphics);
-        }
+    if let Some(lines) = lines {
+        draw_scanlines(win_size, &lines.background, context, graphics);
     }
 }
 
 /// Draws art centered on the terminal. If the art is bigger than the terminal can display, you'll only see the center portion of it.
-pub fn draw_art(win_size: Size, art: &[String], glyphs: &mut Glyphs, font_size: FontSize, fgc: Color, context: Context, graphics: &mut G2d) {
+pub fn draw_art<G: Graphics>(win_size: Size, art: &[String], glyphs: &mut impl FontCache<G>, font_size: FontSize, fgc: Color, context: Context, graphics: &mut G) {
     let (x, y): (f64, f64) = place_art(win_size, art, font_size);
 
     let mut y_offset: f64 = 0.0;
     for line in art.iter() {
+        count_glyphs(line);
         text::Text::new_color(fgc, font_size).draw(
             line,
             glyphs,
@@ -34,34 +316,200 @@ pub fn draw_art(win_size: Size, art: &[String], glyphs: &mut Glyphs, font_size:
             graphics,
         ).unwrap();
 
-        y_offset += (font_size as f64) * 0.8;
+        y_offset += line_height(font_size, DEFAULT_LINE_SPACING);
     }
 }
 
 /// Draws text starting at the top of the terminal, using the terminal's current foreground color, font, and font size.
-pub fn draw_message(message: &[String], glyphs: &mut Glyphs, font_size: FontSize, fgc: Color, context: Context, graphics: &mut G2d)  {
+/// Each character is drawn with its matching style from styles, and characters without a style use the foreground color.
+/// Inverse characters are drawn in the background color on a block of their color, and blinking characters are skipped unless colors.blink_on is true.
+pub fn draw_message<G: Graphics>(message: &[String], styles: &[Vec<Style>], glyphs: &mut impl FontCache<G>, (font_size, spacing): (FontSize, f64), colors: TextColors, context: Context, graphics: &mut G)  {
     let x = TEXT_OFFSET.0;
     let y = TEXT_OFFSET.1;
 
     let mut y_offset: f64 = 0.0;
-    for line in message.iter() {
-        text::Text::new_color(fgc, font_size).draw(
-            line,
+    for (i, line) in message.iter().enumerate() {
+        let line_styles: &[Style] = styles.get(i).map(|s| &s[..]).unwrap_or(&[]);
+
+        let mut x_offset: f64 = 0.0;
+        for (run, style) in style_runs(line, line_styles) {
+            let run_width: f64 = glyphs.width(font_size, &run).unwrap_or(0.0);
+            let mut color: Color = style.color.unwrap_or(colors.fg);
+
+            if style.inverse {
+                let size: f64 = font_size as f64;
+                rectangle(color, [x + x_offset, y + y_offset - size * 0.75, run_width, size * 0.9], context.transform, graphics);
+                color = colors.bg;
+            }
+
+            if !style.blink || colors.blink_on {
+                let offsets: &[(f64, f64)] = match style.backdrop {
+                    Backdrop::None => &[],
+                    Backdrop::Shadow => &SHADOW_OFFSETS,
+                    Backdrop::Outline => &OUTLINE_OFFSETS,
+                };
+                for (dx, dy) in offsets {
+                    count_glyphs(&run);
+                    text::Text::new_color(backdrop_color(color), font_size).draw(
+                        &run,
+                        glyphs,
+                        &context.draw_state,
+                        context.transform.trans(x + x_offset + dx, y + y_offset + dy),
+                        graphics,
+                    ).unwrap();
+                }
+
+                count_glyphs(&run);
+                text::Text::new_color(color, font_size).draw(
+                    &run,
+                    glyphs,
+                    &context.draw_state,
+                    context.transform.trans(x + x_offset, y + y_offset),
+                    graphics,
+                ).unwrap();
+            }
+
+            x_offset += run_width;
+        }
+
+        y_offset += line_height(font_size, spacing);
+    }
+}
+
+/// Draws each line centered across the terminal, with the first line's baseline the given height from the top of the window.
+/// Lines outside the window are skipped.
+pub fn draw_credits<G: Graphics>(win_size: Size, (lines, top): (&[String], f64), glyphs: &mut impl FontCache<G>, (font_size, spacing): (FontSize, f64), colors: TextColors, context: Context, graphics: &mut G) {
+    let height: f64 = line_height(font_size, spacing);
+    for (i, line) in lines.iter().enumerate() {
+        let y: f64 = top + i as f64 * height;
+        if y < 0.0 || y > win_size.height + font_size as f64 { continue; }
+
+        let x: f64 = (win_size.width - glyphs.width(font_size, line).unwrap_or(0.0)) / 2.0;
+        draw_message(std::slice::from_ref(line), &[], glyphs, (font_size, spacing), colors, context.trans(x - TEXT_OFFSET.0, y - TEXT_OFFSET.1), graphics);
+    }
+}
+
+/// Draws a gutter of right-aligned line numbers beside the message, numbering the lines in the given range.
+/// Returns how far to the right the message should be drawn to make room for it.
+pub fn draw_gutter<G: Graphics>(gutter: Gutter, lines: Range<usize>, glyphs: &mut impl FontCache<G>, (font_size, spacing): (FontSize, f64), context: Context, graphics: &mut G) -> f64 {
+    let width: usize = gutter.width.max(lines.end.saturating_sub(1).to_string().len());
+    let x = TEXT_OFFSET.0;
+    let y = TEXT_OFFSET.1;
+
+    let mut y_offset: f64 = 0.0;
+    for number in lines {
+        let label: String = format!("{:>width$}", number, width = width);
+        count_glyphs(&label);
+        text::Text::new_color(gutter.color, font_size).draw(
+            &label,
             glyphs,
             &context.draw_state,
             context.transform.trans(x, y + y_offset),
             graphics,
         ).unwrap();
 
-        y_offset += (font_size as f64) * 0.8;
+        y_offset += line_height(font_size, spacing);
+    }
+
+    glyphs.width(font_size, &"0".repeat(width + 1)).unwrap_or(0.0)
+}
+
+/// Draws a chat conversation from the bottom of the terminal up, with the newest bubble just above the input line.
+/// Bubbles from [Side::Them](../text/enum.Side.html) are aligned left and bubbles from Side::Me are aligned right.
+/// Older bubbles that don't fit are not drawn. If boxed is true, a border is drawn around each bubble.
+pub fn draw_chat<G: Graphics>(win_size: Size, bubbles: &[Bubble], boxed: bool, glyphs: &mut impl FontCache<G>, (font_size, spacing): (FontSize, f64), context: Context, graphics: &mut G) {
+    let line_height: f64 = line_height(font_size, spacing);
+    let padding: f64 = 6.0;
+
+    let mut bottom: f64 = win_size.height - TEXT_OFFSET.1 - 10.0;
+    for bubble in bubbles.iter().rev() {
+        let height: f64 = (bubble.lines.len() as f64) * line_height + padding * 2.0;
+        let top: f64 = bottom - height;
+        if top < TEXT_OFFSET.1 - line_height { break; }
+
+        let width: f64 = bubble.lines.iter()
+            .map(|line| glyphs.width(font_size, line).unwrap_or(0.0))
+            .fold(0.0, f64::max);
+        let x: f64 = match bubble.side {
+            Side::Them => TEXT_OFFSET.0,
+            Side::Me => win_size.width - TEXT_OFFSET.0 - width,
+        };
+
+        if boxed {
+            Rectangle::new_border(bubble.color, 1.0).draw(
+                [x - padding, top, width + padding * 2.0, height],
+                &context.draw_state,
+                context.transform,
+                graphics,
+            );
+        }
+
+        let mut y_offset: f64 = top + padding + line_height;
+        for line in bubble.lines.iter() {
+            count_glyphs(line);
+            text::Text::new_color(bubble.color, font_size).draw(
+                line,
+                glyphs,
+                &context.draw_state,
+                context.transform.trans(x, y_offset),
+                graphics,
+            ).unwrap();
+
+            y_offset += line_height;
+        }
+
+        bottom = top - padding * 2.0;
+    }
+}
+
+/// Draws a box in the center of the terminal listing the given (key, description) pairs in two columns.
+pub fn draw_help<G: Graphics>(win_size: Size, entries: &[(String, String)], glyphs: &mut impl FontCache<G>, font_size: FontSize, colors: TextColors, context: Context, graphics: &mut G) {
+    let size: FontSize = (font_size * 2 / 3).max(8);
+    let line_height: f64 = (size as f64) * 1.2;
+    let padding: f64 = 12.0;
+
+    let key_width: f64 = entries.iter().map(|(key, _)| glyphs.width(size, key).unwrap_or(0.0)).fold(0.0, f64::max);
+    let desc_width: f64 = entries.iter().map(|(_, desc)| glyphs.width(size, desc).unwrap_or(0.0)).fold(0.0, f64::max);
+    let width: f64 = key_width + desc_width + padding * 3.0;
+    let height: f64 = (entries.len() as f64) * line_height + padding * 2.0;
+
+    let x: f64 = (win_size.width - width) / 2.0;
+    let y: f64 = (win_size.height - height) / 2.0;
+    rectangle(colors.fg, [x - 2.0, y - 2.0, width + 4.0, height + 4.0], context.transform, graphics);
+    rectangle(colors.bg, [x, y, width, height], context.transform, graphics);
+
+    let mut y_offset: f64 = y + padding + (size as f64);
+    for (key, desc) in entries.iter() {
+        count_glyphs(key);
+        text::Text::new_color(colors.fg, size).draw(
+            key,
+            glyphs,
+            &context.draw_state,
+            context.transform.trans(x + padding, y_offset),
+            graphics,
+        ).unwrap();
+
+        count_glyphs(desc);
+
+        text::Text::new_color(colors.fg, size).draw(
+            desc,
+            glyphs,
+            &context.draw_state,
+            context.transform.trans(x + key_width + padding * 2.0, y_offset),
+            graphics,
+        ).unwrap();
+
+        y_offset += line_height;
     }
 }
 
 /// Displays a marker before the input string at the bottom fo the terminal, using the terminal's current foreground color, font, and font size.
-pub fn draw_input_marker(win_size: Size, glyphs: &mut Glyphs, font_size: FontSize, fgc: Color, context: Context, graphics: &mut G2d) {
+pub fn draw_input_marker<G: Graphics>(win_size: Size, glyphs: &mut impl FontCache<G>, font_size: FontSize, fgc: Color, context: Context, graphics: &mut G) {
     let x = TEXT_OFFSET.0;
     let y = (win_size.height - TEXT_OFFSET.1) + 20.0;
 
+    count_glyphs("> ");
+
     text::Text::new_color(fgc, font_size - 6).draw(
         "> ",
         glyphs,
@@ -72,10 +520,12 @@ pub fn draw_input_marker(win_size: Size, glyphs: &mut Glyphs, font_size: FontSiz
 }
 
 /// Displays the current input string at the bottom of the terminal, using the terminal's current foreground color, font, and font size.
-pub fn draw_input(win_size: Size, message: &str, glyphs: &mut Glyphs, font_size: FontSize, fgc: Color, context: Context, graphics: &mut G2d)  {
+pub fn draw_input<G: Graphics>(win_size: Size, message: &str, glyphs: &mut impl FontCache<G>, font_size: FontSize, fgc: Color, context: Context, graphics: &mut G)  {
     let x = TEXT_OFFSET.0 + 20.0;
     let y = (win_size.height - TEXT_OFFSET.1) + 20.0;
 
+    count_glyphs(message);
+
     text::Text::new_color(fgc, font_size - 6).draw(
         message,
         glyphs,
@@ -85,14 +535,68 @@ pub fn draw_input(win_size: Size, message: &str, glyphs: &mut Glyphs, font_size:
     ).unwrap();
 }
 
-/// Displays scanlines over the terminal text and a border around the terminal box, using the terminal's current size and background color.
-pub fn draw_foreground(win_size: Size, bgc: Color, lines: bool, context: Context, graphics: &mut G2d) {
-    if lines {
-        let line_color: Color = [bgc[0], bgc[1], bgc[2], 0.4];
-        
-        for i in 0..((win_size.height - 30.0) as i32 / 3) {
-            rectangle(line_color, [15.0, (i * 3) as f64 + 15.0, win_size.width - 30.0, 0.5], context.transform, graphics);
-        }
+/// Displays a suggested completion dimmed on the input line, right after the given text already drawn there.
+pub fn draw_ghost<G: Graphics>(win_size: Size, (after, ghost): (&str, &str), glyphs: &mut impl FontCache<G>, font_size: FontSize, fgc: Color, context: Context, graphics: &mut G) {
+    let size: FontSize = font_size.saturating_sub(6);
+    let x = TEXT_OFFSET.0 + 20.0 + glyphs.width(size, after).unwrap_or(0.0);
+    let y = (win_size.height - TEXT_OFFSET.1) + 20.0;
+
+    count_glyphs(ghost);
+
+    text::Text::new_color([fgc[0], fgc[1], fgc[2], fgc[3] * 0.4], size).draw(
+        ghost,
+        glyphs,
+        &context.draw_state,
+        context.transform.trans(x, y),
+        graphics,
+    ).unwrap();
+}
+
+/// Returns how wide each character of the on-screen keyboard is drawn, given the monospace font it's drawn in.
+pub fn keyboard_column_width<C: CharacterCache>(glyphs: &mut C, font_size: FontSize) -> f64 {
+    glyphs.width(font_size, "0").unwrap_or(font_size as f64 * 0.6)
+}
+
+/// Displays the on-screen keyboard centered just above the input line, in the given monospace font.
+pub fn draw_keyboard<G: Graphics>(win_size: Size, keyboard: &VirtualKeyboard, glyphs: &mut impl FontCache<G>, font_size: FontSize, colors: TextColors, context: Context, graphics: &mut G) {
+    let (lines, styles) = keyboard.lines();
+    let columns: usize = lines.iter().map(|line| display_width(line)).max().unwrap_or(0);
+    let column_width: f64 = keyboard_column_width(glyphs, font_size);
+    let (x, y): (f64, f64) = keyboard_origin(win_size, (columns, lines.len()), column_width, font_size);
+
+    draw_message(&lines, &styles, glyphs, (font_size, DEFAULT_LINE_SPACING), colors, context.trans(x - TEXT_OFFSET.0, y - TEXT_OFFSET.1), graphics);
+}
+
+/// Returns the character (column, row) of an on-screen keyboard of the given size in characters under the given point, if it's over the keyboard.
+/// ```
+/// # use simpleterm_profile::draw::keyboard_cell;
+/// assert_eq!(keyboard_cell((800, 600).into(), (30, 5), 20.0, 32, [100.0, 100.0]), None);
+/// assert_eq!(keyboard_cell((800, 600).into(), (30, 5), 20.0, 32, [105.0, 420.0]), Some((0, 0)));
+/// assert_eq!(keyboard_cell((800, 600).into(), (30, 5), 20.0, 32, [690.0, 510.0]), Some((29, 4)));
+/// ```
+pub fn keyboard_cell(win_size: Size, (columns, rows): (usize, usize), column_width: f64, font_size: FontSize, point: [f64; 2]) -> Option<(usize, usize)> {
+    let (x, y): (f64, f64) = keyboard_origin(win_size, (columns, rows), column_width, font_size);
+    let top: f64 = y - font_size as f64 * 0.75;
+    if point[0] < x || point[1] < top { return None; }
+
+    let column: usize = ((point[0] - x) / column_width) as usize;
+    let row: usize = ((point[1] - top) / line_height(font_size, DEFAULT_LINE_SPACING)) as usize;
+    if column < columns && row < rows { Some((column, row)) } else { None }
+}
+
+// Returns the left edge and the first line's baseline of an on-screen keyboard of the given size in characters.
+fn keyboard_origin(win_size: Size, (columns, rows): (usize, usize), column_width: f64, font_size: FontSize) -> (f64, f64) {
+    let line_height: f64 = line_height(font_size, DEFAULT_LINE_SPACING);
+    let input_line: f64 = (win_size.height - TEXT_OFFSET.1) + 20.0;
+    let last_line: f64 = input_line - font_size as f64 * 1.5;
+
+    ((win_size.width - columns as f64 * column_width) / 2.0, last_line - rows.saturating_sub(1) as f64 * line_height)
+}
+
+/// Displays scanlines over the terminal text, if given any, and a border around the terminal box, using the terminal's current size and background color.
+pub fn draw_foreground<G: Graphics>(win_size: Size, bgc: Color, lines: Option<&Scanlines<G::Texture>>, context: Context, graphics: &mut G) {
+    if let Some(lines) = lines {
+        draw_scanlines(win_size, &lines.foreground, context, graphics);
     }
 
     rectangle(bgc, [0.0, 0.0, win_size.width, 10.0], context.transform, graphics);
@@ -100,3 +604,281 @@ pub fn draw_foreground(win_size: Size, bgc: Color, lines: bool, context: Context
     rectangle(bgc, [win_size.width - 10.0, 0.0, 10.0, win_size.height], context.transform, graphics);
     rectangle(bgc, [0.0, win_size.height - 10.0, win_size.width, 10.0], context.transform, graphics);
 }
+
+/// Redraws the box around the terminal text according to the given animation, where clock is how long the terminal has been running.
+/// Call this after `draw_background`, which draws the still border.
+pub fn draw_border<G: Graphics>(win_size: Size, animation: BorderAnimation, clock: Duration, colors: TextColors, context: Context, graphics: &mut G) {
+    let outer: [f64; 4] = [10.0, 10.0, win_size.width - 20.0, win_size.height - 20.0];
+    let width: f64 = 5.0;
+    let seconds: f32 = clock.as_secs_f32();
+
+    match animation {
+        BorderAnimation::None => {},
+        BorderAnimation::ColorCycle => {
+            let color: Color = rotate_hue(colors.fg, seconds / BORDER_CYCLE_TIME * TAU);
+            Rectangle::new_border(color, width / 2.0).draw(inset(outer, width / 2.0), &context.draw_state, context.transform, graphics);
+        },
+        BorderAnimation::MarchingDashes => {
+            Rectangle::new_border(colors.bg, width / 2.0).draw(inset(outer, width / 2.0), &context.draw_state, context.transform, graphics);
+
+            let perimeter: f64 = 2.0 * (outer[2] + outer[3]);
+            let mut distance: f64 = (clock.as_secs_f64() * BORDER_MARCH_SPEED) % (BORDER_DASH * 2.0) - BORDER_DASH * 2.0;
+            while distance < perimeter {
+                let start: f64 = distance.max(0.0);
+                let end: f64 = (distance + BORDER_DASH).min(perimeter);
+                if end > start { draw_dash(start, end, outer, width, colors.fg, context, graphics); }
+                distance += BORDER_DASH * 2.0;
+            }
+        },
+        BorderAnimation::Pulse => {
+            let strength: f32 = ((seconds / BORDER_CYCLE_TIME * TAU).sin() + 1.0) / 2.0;
+            let glow: Color = [colors.fg[0], colors.fg[1], colors.fg[2], colors.fg[3] * strength];
+            Rectangle::new_border(glow, width / 2.0).draw(inset(outer, -width / 2.0), &context.draw_state, context.transform, graphics);
+        },
+    }
+}
+
+/// Lights up the box around the terminal text in the given color, like a signal lamp, for sending Morse code.
+pub fn draw_signal<G: Graphics>(win_size: Size, color: Color, context: Context, graphics: &mut G) {
+    let outer: [f64; 4] = [10.0, 10.0, win_size.width - 20.0, win_size.height - 20.0];
+    Rectangle::new_border(color, SIGNAL_WIDTH / 2.0).draw(inset(outer, SIGNAL_WIDTH / 2.0), &context.draw_state, context.transform, graphics);
+}
+
+/// Draws a labeled progress bar in a small panel along the bottom of the terminal, just above the input line, filled to the given fraction.
+/// The filled part is striped, and the stripes march along as clock, how long the terminal has been running, goes by.
+pub fn draw_progress<G: Graphics>(win_size: Size, (label, fraction): (&str, f64), clock: Duration, glyphs: &mut impl FontCache<G>, colors: TextColors, context: Context, graphics: &mut G) {
+    let size: FontSize = WIDGET_FONT_SIZE;
+    let padding: f64 = PANEL_PADDING;
+    let [x, y, width, height] = draw_panel(win_size, colors, context, graphics);
+
+    let fraction: f64 = if fraction.is_nan() { 0.0 } else { fraction.clamp(0.0, 1.0) };
+    let caption: String = format!("{} {:>3}%", label, (fraction * 100.0).round());
+    count_glyphs(&caption);
+    text::Text::new_color(colors.fg, size).draw(
+        &caption,
+        glyphs,
+        &context.draw_state,
+        context.transform.trans(x + padding, y + padding + (size as f64)),
+        graphics,
+    ).unwrap();
+
+    let bar_x: f64 = x + padding * 2.0 + glyphs.width(size, &caption).unwrap_or(0.0);
+    let bar: [f64; 4] = [bar_x, y + padding, (x + width - padding - bar_x).max(0.0), height - padding * 2.0];
+    Rectangle::new_border(colors.fg, 0.5).draw(bar, &context.draw_state, context.transform, graphics);
+
+    let filled_end: f64 = bar[0] + bar[2] * fraction;
+    rectangle(colors.fg, [bar[0], bar[1], filled_end - bar[0], bar[3]], context.transform, graphics);
+
+    let stripe_color: Color = [colors.bg[0], colors.bg[1], colors.bg[2], 0.3];
+    let mut stripe: f64 = bar[0] - PROGRESS_STRIPE * 2.0 + (clock.as_secs_f64() * PROGRESS_STRIPE_SPEED) % (PROGRESS_STRIPE * 2.0);
+    while stripe < filled_end {
+        let (start, end): (f64, f64) = (stripe.max(bar[0]), (stripe + PROGRESS_STRIPE).min(filled_end));
+        if end > start { rectangle(stripe_color, [start, bar[1], end - start, bar[3]], context.transform, graphics); }
+        stripe += PROGRESS_STRIPE * 2.0;
+    }
+}
+
+/// Draws a panel just above the input line with the given caption and a spinner, for work of unknown length.
+pub fn draw_spinner<G: Graphics>(win_size: Size, caption: &str, glyphs: &mut impl FontCache<G>, colors: TextColors, context: Context, graphics: &mut G) {
+    let [x, y, _, _] = draw_panel(win_size, colors, context, graphics);
+    count_glyphs(caption);
+    text::Text::new_color(colors.fg, WIDGET_FONT_SIZE).draw(
+        caption,
+        glyphs,
+        &context.draw_state,
+        context.transform.trans(x + PANEL_PADDING, y + PANEL_PADDING + (WIDGET_FONT_SIZE as f64)),
+        graphics,
+    ).unwrap();
+}
+
+// Draws the outlined box that progress bars and spinners sit in, returning where its inside is.
+fn draw_panel<G: Graphics>(win_size: Size, colors: TextColors, context: Context, graphics: &mut G) -> [f64; 4] {
+    let height: f64 = (WIDGET_FONT_SIZE as f64) * 1.2 + PANEL_PADDING * 2.0;
+    let (x, y): (f64, f64) = (TEXT_OFFSET.0, win_size.height - TEXT_OFFSET.1 - 15.0 - height);
+    let width: f64 = win_size.width - TEXT_OFFSET.0 * 2.0;
+    rectangle(colors.fg, [x - 1.0, y - 1.0, width + 2.0, height + 2.0], context.transform, graphics);
+    rectangle(colors.bg, [x, y, width, height], context.transform, graphics);
+    [x, y, width, height]
+}
+
+// Draws the part of a rectangle's outline between two distances around its perimeter, going clockwise from the top left.
+fn draw_dash<G: Graphics>(start: f64, end: f64, rect: [f64; 4], width: f64, color: Color, context: Context, graphics: &mut G) {
+    let [x, y, w, h] = rect;
+    let sides: [f64; 4] = [w, h, w, h];
+
+    let mut side_start: f64 = 0.0;
+    for (side, length) in sides.iter().enumerate() {
+        let from: f64 = start.max(side_start) - side_start;
+        let to: f64 = end.min(side_start + length) - side_start;
+        if to > from {
+            let dash: [f64; 4] = match side {
+                0 => [x + from, y, to - from, width],
+                1 => [x + w - width, y + from, width, to - from],
+                2 => [x + w - to, y + h - width, to - from, width],
+                _ => [x, y + h - to, width, to - from],
+            };
+            rectangle(color, dash, context.transform, graphics);
+        }
+        side_start += length;
+    }
+}
+
+// Stretches a scanline texture over the terminal box.
+fn draw_scanlines<G: Graphics>(win_size: Size, texture: &G::Texture, context: Context, graphics: &mut G) {
+    Image::new().rect([15.0, 15.0, win_size.width - 30.0, win_size.height - 30.0]).draw(texture, &context.draw_state, context.transform, graphics);
+}
+
+// Renders a one pixel wide column of scanlines in the given color, the given number of pixels tall.
+fn scanline_texture<F, T: CreateTexture<F>>(texture_context: &mut F, height: usize, color: Color) -> Option<T> {
+    let rows: usize = height * SCANLINE_ROWS_PER_PIXEL;
+    let pixel: [u8; 4] = [0, 1, 2, 3].map(|i| (color[i].clamp(0.0, 1.0) * 255.0) as u8);
+
+    let mut memory: Vec<u8> = vec![0; rows * 4];
+    for row in (0..rows).step_by(SCANLINE_SPACING * SCANLINE_ROWS_PER_PIXEL) {
+        memory[row * 4..row * 4 + 4].copy_from_slice(&pixel);
+    }
+
+    let settings: TextureSettings = TextureSettings::new().filter(Filter::Nearest);
+    T::create(texture_context, Format::Rgba8, &memory, [1, rows as u32], &settings).ok()
+}
+
+// The color of the scanlines behind the text, darker than the background on dark themes and lighter on light ones.
+fn background_line_color(bgc: Color, fgc: Color) -> Color {
+    if fgc.brighter_than(bgc) {
+        [bgc[0] - 0.2, bgc[1] - 0.2, bgc[2] - 0.2, 0.5]
+    } else {
+        [bgc[0] + 0.15, bgc[1] + 0.15, bgc[2] + 0.15, 0.4]
+    }
+}
+
+// The color of the copies drawn behind text with a backdrop, a much darker shade of the text's color.
+fn backdrop_color(color: Color) -> Color {
+    [color[0] * 0.2, color[1] * 0.2, color[2] * 0.2, color[3]]
+}
+
+// The color of the scanlines over the text.
+fn foreground_line_color(bgc: Color) -> Color {
+    [bgc[0], bgc[1], bgc[2], 0.4]
+}
+
+// Shrinks a rectangle by the given amount on every side, or grows it if the amount is negative.
+fn inset(rect: [f64; 4], amount: f64) -> [f64; 4] {
+    [rect[0] + amount, rect[1] + amount, rect[2] - amount * 2.0, rect[3] - amount * 2.0]
+}
+
+// Rotates a color's hue by the given angle in radians, keeping its brightness.
+fn rotate_hue(color: Color, angle: f32) -> Color {
+    let (sin, cos) = angle.sin_cos();
+    let third: f32 = 1.0 / 3.0;
+    let root: f32 = third.sqrt();
+    let a: f32 = cos + (1.0 - cos) * third;
+    let b: f32 = third * (1.0 - cos) - root * sin;
+    let c: f32 = third * (1.0 - cos) + root * sin;
+    let [r, g, bl, alpha] = color;
+
+    [
+        (r * a + g * b + bl * c).clamp(0.0, 1.0),
+        (r * c + g * a + bl * b).clamp(0.0, 1.0),
+        (r * b + g * c + bl * a).clamp(0.0, 1.0),
+        alpha,
+    ]
+}
+
+/// Draws a small panel of widget labels and values in the given corner of the terminal, at a fixed small font size.
+pub fn draw_widgets<G: Graphics>(win_size: Size, readings: &[(String, String)], corner: Corner, glyphs: &mut impl FontCache<G>, colors: TextColors, context: Context, graphics: &mut G) {
+    if readings.is_empty() { return; }
+
+    let size: FontSize = WIDGET_FONT_SIZE;
+    let line_height: f64 = (size as f64) * 1.2;
+    let padding: f64 = 6.0;
+
+    let lines: Vec<String> = readings.iter().map(|(label, value)| format!("{} {}", label, value)).collect();
+    let width: f64 = lines.iter().map(|line| glyphs.width(size, line).unwrap_or(0.0)).fold(0.0, f64::max) + padding * 2.0;
+    let height: f64 = (lines.len() as f64) * line_height + padding * 2.0;
+
+    let margin: f64 = 20.0;
+    let x: f64 = match corner {
+        Corner::TopLeft | Corner::BottomLeft => margin,
+        Corner::TopRight | Corner::BottomRight => win_size.width - width - margin,
+    };
+    let y: f64 = match corner {
+        Corner::TopLeft | Corner::TopRight => margin,
+        Corner::BottomLeft | Corner::BottomRight => win_size.height - height - margin,
+    };
+    rectangle(colors.fg, [x - 1.0, y - 1.0, width + 2.0, height + 2.0], context.transform, graphics);
+    rectangle(colors.bg, [x, y, width, height], context.transform, graphics);
+
+    let mut y_offset: f64 = y + padding + (size as f64);
+    for line in lines.iter() {
+        count_glyphs(line);
+        text::Text::new_color(colors.fg, size).draw(
+            line,
+            glyphs,
+            &context.draw_state,
+            context.transform.trans(x + padding, y_offset),
+            graphics,
+        ).unwrap();
+
+        y_offset += line_height;
+    }
+}
+
+/// Draws a whole frame of the given scene, the way every [Screen](../backend/trait.Screen.html) that draws with piston's graphics does.
+/// The on-screen keyboard is drawn with keyboard_glyphs, and is skipped if they aren't given.
+pub fn draw_scene<G: Graphics, C: FontCache<G>>(scene: &Scene, glyphs: &mut C, keyboard_glyphs: Option<&mut C>, scanlines: Option<&Scanlines<G::Texture>>, c: Context, g: &mut G) {
+    let win_size: Size = scene.size;
+    let (bgc, fgc): (Color, Color) = (scene.bg, scene.fg);
+    let (font_size, spacing): (FontSize, f64) = (scene.font_size, scene.spacing);
+    let colors: TextColors = TextColors { bg: bgc, fg: fgc, blink_on: true };
+    let message_colors: TextColors = TextColors { bg: bgc, fg: scene.message_color, blink_on: scene.blink_on };
+
+    clear(bgc, g);
+
+    draw_background(win_size, bgc, fgc, scanlines, c, g);
+    draw_border(win_size, scene.border, scene.clock, colors, c, g);
+    if scene.signal { draw_signal(win_size, fgc, c, g); }
+    match scene.content {
+        SceneContent::Credits { lines, top } => draw_credits(win_size, (lines, top), glyphs, (font_size, spacing), message_colors, c, g),
+        SceneContent::Art { lines, styles, font_size: art_font_size } => {
+            let (x, y): (f64, f64) = place_art(win_size, lines, art_font_size);
+            draw_message(lines, styles, glyphs, (art_font_size, DEFAULT_LINE_SPACING), TextColors { blink_on: scene.blink_on, ..colors }, c.trans(x - TEXT_OFFSET.0, y - TEXT_OFFSET.1), g);
+        },
+        SceneContent::Message { lines, styles, offset, gutter } => {
+            let scrolled: Context = c.trans(0.0, offset);
+            let message_x: f64 = gutter.map_or(0.0, |(gutter, first_line)| draw_gutter(gutter, first_line..first_line + lines.len(), glyphs, (font_size, spacing), scrolled, g));
+            draw_message(lines, styles, glyphs, (font_size, spacing), message_colors, scrolled.trans(message_x, 0.0), g);
+        },
+    }
+    if let Some((bubbles, boxed)) = scene.chat { draw_chat(win_size, bubbles, boxed, glyphs, (font_size, spacing), c, g); }
+    if let Some(color) = scene.input_marker { draw_input_marker(win_size, glyphs, font_size, color, c, g); }
+    if let Some(input) = scene.input { draw_input(win_size, input, glyphs, font_size, fgc, c, g); }
+    if let Some(ghost) = scene.ghost { draw_ghost(win_size, ghost, glyphs, font_size, fgc, c, g); }
+    if let (Some(keyboard), Some(keyboard_glyphs)) = (scene.keyboard, keyboard_glyphs) {
+        draw_keyboard(win_size, keyboard, keyboard_glyphs, font_size, colors, c, g);
+    }
+    draw_pins(win_size, scene.pins, glyphs, (font_size, spacing), colors, c, g);
+    draw_widgets(win_size, scene.widgets, scene.widget_corner, glyphs, colors, c, g);
+    if let Some(progress) = scene.progress { draw_progress(win_size, progress, scene.clock, glyphs, colors, c, g); }
+    if let Some(caption) = scene.spinner { draw_spinner(win_size, caption, glyphs, colors, c, g); }
+    if let Some(help) = scene.help { draw_help(win_size, help, glyphs, font_size, TextColors { blink_on: scene.blink_on, ..colors }, c, g); }
+    if scene.bell { rectangle([fgc[0], fgc[1], fgc[2], 0.25], [0.0, 0.0, win_size.width, win_size.height], c.transform, g); }
+    draw_foreground(win_size, bgc, scanlines, c, g);
+    if scene.fade > 0.0 { rectangle([bgc[0], bgc[1], bgc[2], scene.fade], [0.0, 0.0, win_size.width, win_size.height], c.transform, g); }
+}
+
+/// Draws a scene split in two for a split transition, from a frame of it already rendered to a texture.
+pub fn draw_split_scene<G: Graphics>(scene: &Scene, frame: (&G::Texture, bool), (split, progress): (Split, f64), scanlines: Option<&Scanlines<G::Texture>>, c: Context, g: &mut G) {
+    clear(scene.bg, g);
+    draw_background(scene.size, scene.bg, scene.fg, scanlines, c, g);
+    draw_split(c.get_view_size().into(), frame, split, progress, c, g);
+}
+
+/// Returns how many characters of text the draw functions have drawn since this was last called, and resets the count.
+pub fn take_glyph_count() -> usize {
+    GLYPHS_DRAWN.swap(0, Ordering::Relaxed)
+}
+
+// Adds the characters of a piece of text about to be drawn to the glyph count.
+fn count_glyphs(text: &str) {
+    GLYPHS_DRAWN.fetch_add(text.chars().filter(|c| !c.is_whitespace()).count(), Ordering::Relaxed);
+}
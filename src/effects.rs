@@ -0,0 +1,145 @@
+use graphics::types::Color;
+use std::time::Duration;
+
+use crate::{backend::Backend, text::CRIMSON};
+#[cfg(feature = "audio")]
+use crate::audio::{Sound, TypeSounds};
+
+/// The modem commands and responses typed out before the handshake, along with how long each one stays on screen.
+const DIAL_STAGES: [(&str, u64); 6] = [
+    ("ATZ", 400),
+    ("OK", 300),
+    ("ATDT 555-0142", 900),
+    ("DIALING...", 1200),
+    ("RINGING...", 1500),
+    ("CARRIER DETECTED", 600),
+];
+
+/// The characters used to visualize handshake noise, from quiet to loud.
+const NOISE_CHARS: [char; 8] = [' ', '.', '-', '~', '=', '^', '*', '#'];
+
+/// How many frames of handshake noise to show, and how long each frame lasts.
+const HANDSHAKE_FRAMES: u64 = 24;
+const HANDSHAKE_TIME: Duration = Duration::from_millis(90);
+
+/// How long to pause between lines of a dramatic reveal by default.
+const REVEAL_PAUSE: Duration = Duration::from_millis(500);
+
+/// Plays a canned dial-up connection sequence: AT commands, a burst of handshake noise, and a CONNECT banner.
+/// 
+/// ```no_run
+/// # use simpleterm_profile::{effects, text::*};
+/// # use simpleterm_profile::terminal::Terminal;
+/// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+/// effects::dialup(&mut term);
+/// term.tell("Welcome to the BBS!");
+/// ```
+pub fn dialup<B: Backend>(term: &mut B) {
+    dial(term, || {});
+}
+
+/// Plays the dial-up sequence like [dialup](fn.dialup.html), starting the given sound through the given output when the handshake begins,
+/// at the given volume from 0.0 for silent to 1.0 for full volume.
+/// 
+/// ```no_run
+/// # use simpleterm_profile::{audio::{Sound, TypeSounds}, effects, text::*};
+/// # use simpleterm_profile::terminal::Terminal;
+/// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+/// let sounds: TypeSounds = TypeSounds::new().unwrap();
+/// let handshake: Sound = Sound::open("handshake.wav").unwrap();
+/// effects::dialup_with_sound(&mut term, &sounds, &handshake, 0.5);
+/// ```
+#[cfg(feature = "audio")]
+pub fn dialup_with_sound<B: Backend>(term: &mut B, sounds: &TypeSounds, handshake: &Sound, volume: f32) {
+    dial(term, || sounds.play(handshake, volume));
+}
+
+// Plays the dial-up sequence, calling handshake just before the handshake noise starts.
+fn dial<B: Backend, F: FnOnce()>(term: &mut B, handshake: F) {
+    for (message, millis) in DIAL_STAGES.iter() {
+        term.show(message, Duration::from_millis(*millis));
+    }
+
+    if term.is_active() { handshake(); }
+    for frame in 0..HANDSHAKE_FRAMES {
+        term.display_art(&handshake_noise(frame, 32, 5), HANDSHAKE_TIME);
+    }
+
+    term.show("CONNECT 56000", Duration::from_secs(2));
+}
+
+/// Builds a single frame of handshake noise. The noise gets louder as the handshake progresses, then settles down.
+/// ```
+/// # use simpleterm_profile::effects::handshake_noise;
+/// let noise: String = handshake_noise(3, 16, 2);
+/// assert_eq!(noise.lines().count(), 2);
+/// assert!(noise.lines().all(|line| line.chars().count() == 16));
+/// assert_eq!(noise, handshake_noise(3, 16, 2));
+/// ```
+pub fn handshake_noise(frame: u64, width: usize, height: usize) -> String {
+    let middle: u64 = HANDSHAKE_FRAMES / 2;
+    let distance: u64 = frame.abs_diff(middle);
+    let loudness: u64 = (NOISE_CHARS.len() as u64).saturating_sub(distance / 2).max(2);
+    let mut seed: u64 = frame.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+
+    let mut lines: Vec<String> = Vec::new();
+    for _ in 0..height {
+        let mut line: String = String::new();
+        for _ in 0..width {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+
+            let level: usize = (seed % loudness) as usize;
+            line.push(NOISE_CHARS[level]);
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+/// How a dramatic reveal, shown with [reveal](../terminal/struct.Terminal.html#method.reveal), builds from line to line.
+/// Each line types slower than the one before it by the slowdown, or faster if it's less than 1,
+/// and its color moves from the foreground color toward the accent until the last line is fully in the accent.
+/// ```
+/// # use std::time::Duration;
+/// # use simpleterm_profile::{effects::Reveal, text::*};
+/// let reveal: Reveal = Reveal { accent: [1.0, 1.0, 1.0, 1.0], slowdown: 2.0, ..Reveal::default() };
+/// let type_time: Duration = Duration::from_millis(10);
+/// assert_eq!(reveal.line(0, 3, type_time, [0.0, 0.0, 0.0, 1.0]), (type_time, [0.0, 0.0, 0.0, 1.0]));
+/// assert_eq!(reveal.line(1, 3, type_time, [0.0, 0.0, 0.0, 1.0]), (Duration::from_millis(20), [0.5, 0.5, 0.5, 1.0]));
+/// assert_eq!(reveal.line(2, 3, type_time, [0.0, 0.0, 0.0, 1.0]), (Duration::from_millis(40), [1.0, 1.0, 1.0, 1.0]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reveal {
+    /// The color the last line is typed in.
+    pub accent: Color,
+    /// How many times longer each line takes to type each character than the line before it.
+    pub slowdown: f64,
+    /// How long to wait after each line before typing the next.
+    pub pause: Duration,
+}
+
+impl Default for Reveal {
+    /// Slows down by half again each line, ending in crimson.
+    fn default() -> Reveal {
+        Reveal { accent: CRIMSON, slowdown: 1.5, pause: REVEAL_PAUSE }
+    }
+}
+
+impl Reveal {
+    /// Returns how long each character takes to type, and what color it's typed in, for the line at the given index
+    /// out of the given number of lines, starting from the given typing delay and foreground color.
+    pub fn line(&self, index: usize, count: usize, type_time: Duration, fg: Color) -> (Duration, Color) {
+        let slowdown: f64 = if self.slowdown.is_finite() && self.slowdown > 0.0 { self.slowdown } else { 1.0 };
+        let time: Duration = Duration::try_from_secs_f64(type_time.as_secs_f64() * slowdown.powi(index as i32)).unwrap_or(Duration::MAX);
+
+        let blend: f32 = if count > 1 { (index as f32 / (count - 1) as f32).min(1.0) } else { 1.0 };
+        let mut color: Color = fg;
+        for (channel, target) in color.iter_mut().zip(self.accent.iter()) {
+            *channel += (target - *channel) * blend;
+        }
+        (time, color)
+    }
+}
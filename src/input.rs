@@ -0,0 +1,95 @@
+use piston::*;
+use std::collections::VecDeque;
+
+/// Somewhere the terminal can get its events from in place of the window, set with
+/// [set_input_source](../terminal/struct.Terminal.html#method.set_input_source).
+/// Every wait loop in the terminal reads through it, so a test or fuzzer can drive the whole terminal with any events it likes.
+/// Any function returning events one at a time works as a source.
+pub trait InputSource {
+    /// Returns the next event, or None once there are no more.
+    fn next_event(&mut self) -> Option<Event>;
+}
+
+impl<F: FnMut() -> Option<Event>> InputSource for F {
+    fn next_event(&mut self) -> Option<Event> {
+        self()
+    }
+}
+
+/// An input source that plays back a list of events in order, built up a step at a time.
+/// ```
+/// # use piston_window::*;
+/// # use simpleterm_profile::input::*;
+/// let mut input: ScriptedInput = ScriptedInput::new().text("hi").press(Key::Return).frames(2);
+/// assert_eq!(input.len(), 5);
+/// assert_eq!(input.next_event().and_then(|e| e.text_args()), Some(String::from("hi")));
+/// assert_eq!(input.next_event().and_then(|e| e.press_args()), Some(Button::Keyboard(Key::Return)));
+/// assert!(input.next_event().and_then(|e| e.release_args()).is_some());
+/// assert!(input.next_event().and_then(|e| e.render_args()).is_some());
+/// ```
+#[derive(Clone)]
+pub struct ScriptedInput {
+    events: VecDeque<Event>,
+    size: [f64; 2],
+}
+
+impl ScriptedInput {
+    /// Creates an empty script for an 800 by 600 window.
+    pub fn new() -> ScriptedInput {
+        ScriptedInput { events: VecDeque::new(), size: [800.0, 600.0] }
+    }
+
+    /// Adds the given event to the end of the script.
+    pub fn event(mut self, event: Event) -> ScriptedInput {
+        self.events.push_back(event);
+        self
+    }
+
+    /// Adds text typed on the keyboard, which can be any length and hold any characters.
+    pub fn text(self, text: &str) -> ScriptedInput {
+        self.event(Event::Input(Input::Text(String::from(text)), None))
+    }
+
+    /// Adds a press and release of the given key.
+    pub fn press(self, key: Key) -> ScriptedInput {
+        let button = |state: ButtonState| Event::Input(Input::Button(ButtonArgs { state, button: Button::Keyboard(key), scancode: None }), None);
+        self.event(button(ButtonState::Press)).event(button(ButtonState::Release))
+    }
+
+    /// Adds a resize of the window to the given width and height. Frames added after it are drawn at that size.
+    pub fn resize(mut self, width: f64, height: f64) -> ScriptedInput {
+        self.size = [width, height];
+        let draw_size: [u32; 2] = [width.max(0.0) as u32, height.max(0.0) as u32];
+        self.event(Event::Input(Input::Resize(ResizeArgs { window_size: [width, height], draw_size }), None))
+    }
+
+    /// Adds the given number of frames to draw.
+    pub fn frames(mut self, count: usize) -> ScriptedInput {
+        let draw_size: [u32; 2] = [self.size[0].max(0.0) as u32, self.size[1].max(0.0) as u32];
+        let render: Event = Event::Loop(Loop::Render(RenderArgs { ext_dt: 0.0, window_size: self.size, draw_size }));
+        self.events.extend((0..count).map(|_| render.clone()));
+        self
+    }
+
+    /// Returns how many events are left to play.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if every event has been played.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl Default for ScriptedInput {
+    fn default() -> ScriptedInput {
+        ScriptedInput::new()
+    }
+}
+
+impl InputSource for ScriptedInput {
+    fn next_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+}
@@ -0,0 +1,122 @@
+use graphics::types::Color;
+use serde_json::Value;
+use std::collections::HashSet;
+
+use crate::text::*;
+
+/// The color of object keys.
+pub const KEY_COLOR: Color = LIGHT_BLUE;
+/// The color of string values.
+pub const STRING_COLOR: Color = EMERALD;
+/// The color of number values.
+pub const NUMBER_COLOR: Color = GOLD;
+/// The color of true, false, and null.
+pub const LITERAL_COLOR: Color = LIGHT_PURPLE;
+
+/// One line of pretty-printed JSON.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonLine {
+    /// The colored pieces of the line, including its indentation.
+    pub spans: Vec<Span>,
+    /// The path of the object or array that opens on this line, if one does. This is what gets folded.
+    pub fold: Option<String>,
+}
+
+/// A pretty-printed JSON value where objects and arrays can be collapsed to a single line and expanded again.
+/// ```
+/// # use simpleterm_profile::json::*;
+/// let mut view: JsonView = JsonView::new(serde_json::json!({"name": "term", "tags": ["a", "b"]}));
+/// assert_eq!(view.text(), vec!["{", "  \"name\": \"term\",", "  \"tags\": [", "    \"a\",", "    \"b\"", "  ]", "}"]);
+///
+/// view.collapse("/tags");
+/// assert_eq!(view.text(), vec!["{", "  \"name\": \"term\",", "  \"tags\": [...] (2)", "}"]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonView {
+    value: Value,
+    collapsed: HashSet<String>,
+}
+
+impl JsonView {
+    /// Creates a view of the given value with everything expanded.
+    pub fn new(value: Value) -> JsonView {
+        JsonView { value, collapsed: HashSet::new() }
+    }
+
+    /// Collapses the object or array at the given path, like "/tags" or "/items/0". The root's path is "".
+    pub fn collapse(&mut self, path: &str) {
+        self.collapsed.insert(String::from(path));
+    }
+
+    /// Expands the object or array at the given path.
+    pub fn expand(&mut self, path: &str) {
+        self.collapsed.remove(path);
+    }
+
+    /// Returns whether the object or array at the given path is collapsed.
+    pub fn is_collapsed(&self, path: &str) -> bool {
+        self.collapsed.contains(path)
+    }
+
+    /// Returns the pretty-printed lines with their colors.
+    pub fn lines(&self) -> Vec<JsonLine> {
+        let mut lines: Vec<JsonLine> = Vec::new();
+        self.push_value(&mut lines, &self.value, "", 0, Vec::new(), false);
+        lines
+    }
+
+    /// Returns the pretty-printed lines as plain text.
+    pub fn text(&self) -> Vec<String> {
+        self.lines().iter().map(|line| line.spans.iter().map(|span| span.text.as_str()).collect()).collect()
+    }
+
+    // Adds the lines for a value, starting with the given prefix (indentation and key) and ending with a comma if needed.
+    fn push_value(&self, lines: &mut Vec<JsonLine>, value: &Value, path: &str, depth: usize, mut prefix: Vec<Span>, comma: bool) {
+        let end: &str = if comma { "," } else { "" };
+        let (open, close, children): (&str, &str, Vec<(Option<&String>, &Value)>) = match value {
+            Value::Object(map) => ("{", "}", map.iter().map(|(key, child)| (Some(key), child)).collect()),
+            Value::Array(items) => ("[", "]", items.iter().map(|child| (None, child)).collect()),
+            _ => {
+                prefix.push(scalar_span(value));
+                prefix.push(Span::plain(end));
+                lines.push(JsonLine { spans: prefix, fold: None });
+                return;
+            },
+        };
+
+        if self.is_collapsed(path) {
+            prefix.push(Span::plain(&format!("{}...{} ({}){}", open, close, children.len(), end)));
+            lines.push(JsonLine { spans: prefix, fold: Some(String::from(path)) });
+            return;
+        }
+
+        prefix.push(Span::plain(open));
+        lines.push(JsonLine { spans: prefix, fold: Some(String::from(path)) });
+
+        let indent: String = "  ".repeat(depth + 1);
+        let count: usize = children.len();
+        for (i, (key, child)) in children.into_iter().enumerate() {
+            let mut child_prefix: Vec<Span> = vec![Span::plain(&indent)];
+            let child_path: String = match key {
+                Some(key) => {
+                    child_prefix.push(Span::colored(&Value::String(key.clone()).to_string(), KEY_COLOR));
+                    child_prefix.push(Span::plain(": "));
+                    format!("{}/{}", path, key)
+                },
+                None => format!("{}/{}", path, i),
+            };
+            self.push_value(lines, child, &child_path, depth + 1, child_prefix, i + 1 < count);
+        }
+
+        lines.push(JsonLine { spans: vec![Span::plain(&format!("{}{}{}", "  ".repeat(depth), close, end))], fold: None });
+    }
+}
+
+// Colors a string, number, bool, or null value.
+fn scalar_span(value: &Value) -> Span {
+    match value {
+        Value::String(_) => Span::colored(&value.to_string(), STRING_COLOR),
+        Value::Number(_) => Span::colored(&value.to_string(), NUMBER_COLOR),
+        _ => Span::colored(&value.to_string(), LITERAL_COLOR),
+    }
+}
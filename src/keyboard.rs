@@ -0,0 +1,166 @@
+use crate::text::Style;
+
+// The character keys of the on-screen keyboard, row by row.
+const CHARACTER_ROWS: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl-", "zxcvbnm,.?"];
+
+/// A key on the on-screen keyboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VirtualKey {
+    /// Types this character, as a capital if shift is on.
+    Char(char),
+    /// Makes the next letter a capital.
+    Shift,
+    /// Types a space.
+    Space,
+    /// Deletes the last character.
+    Backspace,
+    /// Submits the input.
+    Done,
+}
+
+impl VirtualKey {
+    /// Returns the text drawn on this key.
+    pub fn label(self, shift: bool) -> String {
+        match self {
+            VirtualKey::Char(c) if shift => c.to_uppercase().collect(),
+            VirtualKey::Char(c) => c.to_string(),
+            VirtualKey::Shift => String::from("shift"),
+            VirtualKey::Space => String::from("space"),
+            VirtualKey::Backspace => String::from("del"),
+            VirtualKey::Done => String::from("done"),
+        }
+    }
+}
+
+/// An on-screen keyboard for typing without a physical keyboard, as on a kiosk or with a game controller.
+/// One key is selected at a time; it can be moved around with the arrow keys or a d-pad and pressed, or a key can be clicked directly.
+/// ```
+/// # use simpleterm_profile::keyboard::*;
+/// let mut keyboard: VirtualKeyboard = VirtualKeyboard::default();
+/// let mut input: String = String::new();
+/// keyboard.move_cursor(0, 1);
+/// assert_eq!(keyboard.selected(), VirtualKey::Char('q'));
+/// keyboard.press(&mut input);
+/// keyboard.move_cursor(1, 0);
+/// keyboard.press(&mut input);
+/// assert_eq!(input, "qw");
+///
+/// keyboard.move_cursor(0, -2);
+/// assert_eq!(keyboard.selected(), VirtualKey::Shift);
+/// keyboard.move_cursor(-1, 0);
+/// assert_eq!(keyboard.selected(), VirtualKey::Done);
+/// assert!(keyboard.press(&mut input));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VirtualKeyboard {
+    rows: Vec<Vec<VirtualKey>>,
+    cursor: (usize, usize),
+    shift: bool,
+}
+
+impl Default for VirtualKeyboard {
+    fn default() -> VirtualKeyboard {
+        let mut rows: Vec<Vec<VirtualKey>> = CHARACTER_ROWS.iter().map(|row| row.chars().map(VirtualKey::Char).collect()).collect();
+        rows.push(vec![VirtualKey::Shift, VirtualKey::Space, VirtualKey::Backspace, VirtualKey::Done]);
+        VirtualKeyboard { rows, cursor: (0, 0), shift: false }
+    }
+}
+
+impl VirtualKeyboard {
+    /// Returns the selected key.
+    pub fn selected(&self) -> VirtualKey {
+        self.rows[self.cursor.1][self.cursor.0]
+    }
+
+    /// Moves the selection the given number of keys across and down, wrapping around the edges.
+    pub fn move_cursor(&mut self, across: i32, down: i32) {
+        let row: usize = (self.cursor.1 as i32 + down).rem_euclid(self.rows.len() as i32) as usize;
+        let width: usize = self.rows[row].len();
+        let col: usize = if down == 0 {
+            (self.cursor.0 as i32 + across).rem_euclid(width as i32) as usize
+        } else {
+            // Rows have different numbers of keys, so keep the same position along the row rather than the same index.
+            let along: f64 = (self.cursor.0 as f64 + 0.5) / self.rows[self.cursor.1].len() as f64;
+            ((along * width as f64) as usize).min(width - 1)
+        };
+        self.cursor = (col, row);
+    }
+
+    /// Presses the selected key, changing the input it types into. Returns true if the key was Done.
+    pub fn press(&mut self, input: &mut String) -> bool {
+        match self.selected() {
+            VirtualKey::Char(c) => {
+                if self.shift { input.extend(c.to_uppercase()); } else { input.push(c); }
+                self.shift = false;
+            },
+            VirtualKey::Shift => self.shift = !self.shift,
+            VirtualKey::Space => input.push(' '),
+            VirtualKey::Backspace => { input.pop(); },
+            VirtualKey::Done => return true,
+        }
+        false
+    }
+
+    /// Selects the key drawn at the given character column and row of the keyboard's [lines](#method.lines).
+    /// Returns false if there's no key there.
+    /// ```
+    /// # use simpleterm_profile::keyboard::*;
+    /// let mut keyboard: VirtualKeyboard = VirtualKeyboard::default();
+    /// assert!(keyboard.select_at(4, 1));
+    /// assert_eq!(keyboard.selected(), VirtualKey::Char('w'));
+    /// assert!(!keyboard.select_at(40, 1));
+    /// ```
+    pub fn select_at(&mut self, column: usize, row: usize) -> bool {
+        let hit: Option<usize> = self.layout(row).iter().position(|(start, width)| (*start..start + width).contains(&column));
+        if let Some(col) = hit { self.cursor = (col, row); }
+        hit.is_some()
+    }
+
+    /// Returns the keyboard drawn as lines of text, with the selected key drawn inverse.
+    /// ```
+    /// # use simpleterm_profile::keyboard::*;
+    /// let (lines, styles) = VirtualKeyboard::default().lines();
+    /// assert_eq!(lines[0], " 1  2  3  4  5  6  7  8  9  0 ");
+    /// assert_eq!(lines[4], "   shift  space  del  done ");
+    /// assert!(styles[0][1].inverse && !styles[0][4].inverse);
+    /// ```
+    pub fn lines(&self) -> (Vec<String>, Vec<Vec<Style>>) {
+        let mut lines: Vec<String> = Vec::new();
+        let mut styles: Vec<Vec<Style>> = Vec::new();
+        for (row, keys) in self.rows.iter().enumerate() {
+            let mut line: String = String::new();
+            let mut line_styles: Vec<Style> = Vec::new();
+            for ((start, width), (col, key)) in self.layout(row).into_iter().zip(keys.iter().enumerate()) {
+                let padding: usize = start - line.chars().count();
+                line.push_str(&" ".repeat(padding));
+                line_styles.extend(vec![Style::default(); padding]);
+
+                line.push_str(&format!(" {} ", key.label(self.shift)));
+                let inverse: bool = (col, row) == self.cursor;
+                line_styles.extend(vec![Style { inverse, ..Style::default() }; width]);
+            }
+            lines.push(line);
+            styles.push(line_styles);
+        }
+        (lines, styles)
+    }
+
+    // Returns the (starting column, width) of each key in a row, with the row centered under the widest one.
+    fn layout(&self, row: usize) -> Vec<(usize, usize)> {
+        let row_width = |keys: &[VirtualKey]| keys.iter().map(|key| key_width(*key)).sum::<usize>();
+        let widest: usize = self.rows.iter().map(|keys| row_width(keys)).max().unwrap_or(0);
+        let keys: &[VirtualKey] = self.rows.get(row).map_or(&[], |keys| &keys[..]);
+
+        let mut start: usize = (widest - row_width(keys)) / 2;
+        keys.iter().map(|key| {
+            let width: usize = key_width(*key);
+            start += width;
+            (start - width, width)
+        }).collect()
+    }
+}
+
+// Returns how many columns a key takes up, including a space on either side of its label.
+fn key_width(key: VirtualKey) -> usize {
+    key.label(false).chars().count() + 2
+}
@@ -0,0 +1,428 @@
+use piston::{Button, ButtonArgs, Key};
+use std::time::Duration;
+
+/// Something the user can do by pressing a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Continue past a message shown with tell.
+    Continue,
+    /// Submit the current input when asked a question.
+    Submit,
+    /// Delete the last character of the current input.
+    Backspace,
+    /// Show or hide the list of key bindings.
+    Help,
+    /// Speed up typing and timers while held.
+    FastForward,
+    /// Hold to extend a text selection with the selection movement keys.
+    Select,
+    /// Move the selection cursor left while selecting.
+    SelectLeft,
+    /// Move the selection cursor right while selecting.
+    SelectRight,
+    /// Move the selection cursor up while selecting.
+    SelectUp,
+    /// Move the selection cursor down while selecting.
+    SelectDown,
+    /// Move up in a list or tree.
+    MoveUp,
+    /// Move down in a list or tree.
+    MoveDown,
+    /// Collapse the item under the cursor in a tree.
+    Collapse,
+    /// Expand the item under the cursor in a tree.
+    Expand,
+    /// Try a failed operation again.
+    Retry,
+    /// Give up on a failed operation and carry on.
+    Skip,
+    /// Give up on a failed operation and stop.
+    Abort,
+    /// Go back to the previous step of a wizard.
+    Back,
+    /// Close the window. When the same key is also bound to Back, going back wins wherever it's possible: while answering ask,
+    /// in a wizard step that can go back, in choose, and in skippable captions and animations. Anywhere else the key closes the window,
+    /// so Escape cancels a question but closes the window while a message waits to be continued.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use piston_window::Key;
+    /// # use simpleterm_profile::{answer::Answer, backend::Headless, input::ScriptedInput, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// let mut term: Terminal = Terminal::with_screen(Box::new(Headless::new((800, 600))), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+    /// term.type_time = Duration::ZERO;
+    /// term.set_input_source(ScriptedInput::new().frames(2).press(Key::Escape).frames(2).press(Key::Escape).frames(2).text("Ada").press(Key::Return));
+    /// assert_eq!(term.ask("What is your name?"), Answer::Cancelled);
+    /// term.tell("Escape closes the window here.");
+    /// assert!(!term.active);
+    /// ```
+    Quit,
+    /// Lower the setting under the cursor.
+    Decrease,
+    /// Raise the setting under the cursor.
+    Increase,
+    /// Recall the previous answer from the input history.
+    PreviousInput,
+    /// Recall the next answer from the input history.
+    NextInput,
+    /// Complete the current input from the terminal's completions, or cycle to the next one that matches.
+    Complete,
+    /// Move the on-screen keyboard's selection up.
+    KeyboardUp,
+    /// Move the on-screen keyboard's selection down.
+    KeyboardDown,
+    /// Move the on-screen keyboard's selection left.
+    KeyboardLeft,
+    /// Move the on-screen keyboard's selection right.
+    KeyboardRight,
+    /// Press the selected key of the on-screen keyboard.
+    KeyboardPress,
+    /// Scroll back through earlier messages, when the terminal keeps a scrollback buffer.
+    ScrollBack,
+    /// Scroll forward towards the newest message.
+    ScrollForward,
+    /// Jump back a few seconds while playing captions.
+    SeekBack,
+    /// Jump ahead a few seconds while playing captions.
+    SeekForward,
+}
+
+impl Action {
+    /// Returns true if holding a key down performs this action again and again, like deleting or moving through a list.
+    /// ```
+    /// # use simpleterm_profile::keymap::Action;
+    /// assert!(Action::Backspace.repeats());
+    /// assert!(!Action::Submit.repeats());
+    /// ```
+    pub fn repeats(self) -> bool {
+        matches!(
+            self,
+            Action::Backspace | Action::SelectLeft | Action::SelectRight | Action::SelectUp | Action::SelectDown | Action::MoveUp | Action::MoveDown
+                | Action::Decrease | Action::Increase | Action::PreviousInput | Action::NextInput
+                | Action::KeyboardUp | Action::KeyboardDown | Action::KeyboardLeft | Action::KeyboardRight
+                | Action::ScrollBack | Action::ScrollForward
+        )
+    }
+
+    /// Returns a short description of this action, as shown in the help overlay.
+    /// ```
+    /// # use simpleterm_profile::keymap::Action;
+    /// assert_eq!(Action::Help.description(), "Show or hide this help");
+    /// ```
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Continue => "Continue",
+            Action::Submit => "Submit input",
+            Action::Backspace => "Delete a character",
+            Action::Help => "Show or hide this help",
+            Action::FastForward => "Fast forward (hold)",
+            Action::Select => "Select text (hold with arrows)",
+            Action::SelectLeft => "Move selection left",
+            Action::SelectRight => "Move selection right",
+            Action::SelectUp => "Move selection up",
+            Action::SelectDown => "Move selection down",
+            Action::MoveUp => "Move up",
+            Action::MoveDown => "Move down",
+            Action::Collapse => "Collapse",
+            Action::Expand => "Expand",
+            Action::Retry => "Retry",
+            Action::Skip => "Skip",
+            Action::Abort => "Abort",
+            Action::Back => "Go back a step",
+            Action::Quit => "Close the window",
+            Action::Decrease => "Decrease setting",
+            Action::Increase => "Increase setting",
+            Action::PreviousInput => "Previous answer",
+            Action::NextInput => "Next answer",
+            Action::Complete => "Complete the answer",
+            Action::KeyboardUp => "Move keyboard selection up",
+            Action::KeyboardDown => "Move keyboard selection down",
+            Action::KeyboardLeft => "Move keyboard selection left",
+            Action::KeyboardRight => "Move keyboard selection right",
+            Action::KeyboardPress => "Press the selected key",
+            Action::ScrollBack => "Scroll back",
+            Action::ScrollForward => "Scroll forward",
+            Action::SeekBack => "Jump back",
+            Action::SeekForward => "Jump ahead",
+        }
+    }
+}
+
+/// How a held key repeats its action: once when pressed, again after the delay, and then once every interval until it's released.
+/// ```
+/// # use std::time::Duration;
+/// # use simpleterm_profile::keymap::KeyRepeat;
+/// let repeat: KeyRepeat = KeyRepeat { delay: Duration::from_millis(400), interval: Duration::from_millis(50) };
+/// assert_eq!(repeat.repeats_after(Duration::from_millis(399)), 0);
+/// assert_eq!(repeat.repeats_after(Duration::from_millis(400)), 1);
+/// assert_eq!(repeat.repeats_after(Duration::from_millis(520)), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyRepeat {
+    /// How long a key has to be held before it starts repeating.
+    pub delay: Duration,
+    /// How long it takes to repeat again once repeating.
+    pub interval: Duration,
+}
+
+impl Default for KeyRepeat {
+    fn default() -> KeyRepeat {
+        KeyRepeat { delay: Duration::from_millis(400), interval: Duration::from_millis(50) }
+    }
+}
+
+impl KeyRepeat {
+    /// Returns how many times a key held for the given time should have repeated, not counting the first press.
+    pub fn repeats_after(&self, held: Duration) -> u32 {
+        if held < self.delay { return 0; }
+        let repeating: Duration = held - self.delay;
+        1 + (repeating.as_nanos() / self.interval.as_nanos().max(1)) as u32
+    }
+}
+
+/// A key that can be bound to an action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Binding {
+    /// The key that produces this symbol in the current keyboard layout, like the key labelled Z.
+    Key(Key),
+    /// The key at this physical position on the keyboard, whatever the layout calls it.
+    /// The same position is the Z key in QWERTY, Y in QWERTZ, and W in AZERTY.
+    Scancode(i32),
+}
+
+/// Maps keys to the actions they perform. A key can be bound to several actions, and an action can have several keys.
+/// 
+/// The default bindings are Return or keypad Enter to continue and submit, Backspace to delete, F1 for help, and either Ctrl key to fast forward,
+/// either Shift key with the arrow keys to select text, the arrow keys to move around lists and trees, adjust settings, recall earlier answers,
+/// move around the on-screen keyboard, with Return pressing its keys, and seek through captions,
+/// Tab to complete answers, R, S, and A to retry, skip, or abort a failed operation, Escape to go back (cancelling a question, returning to the previous step of a wizard,
+/// leaving a list of choices, or stopping captions and animations early) and to close the window anywhere going back isn't possible,
+/// and Page Up and Page Down to scroll back through earlier messages.
+/// Keys can also be bound by scancode, so shortcuts stay in the same place on every keyboard layout.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyMap {
+    bindings: Vec<(Binding, Action)>,
+}
+
+impl Default for KeyMap {
+    fn default() -> KeyMap {
+        KeyMap {
+            bindings: vec!(
+                (Binding::Key(Key::Return), Action::Continue),
+                (Binding::Key(Key::Return), Action::Submit),
+                (Binding::Key(Key::NumPadEnter), Action::Continue),
+                (Binding::Key(Key::NumPadEnter), Action::Submit),
+                (Binding::Key(Key::Backspace), Action::Backspace),
+                (Binding::Key(Key::F1), Action::Help),
+                (Binding::Key(Key::LCtrl), Action::FastForward),
+                (Binding::Key(Key::RCtrl), Action::FastForward),
+                (Binding::Key(Key::LShift), Action::Select),
+                (Binding::Key(Key::RShift), Action::Select),
+                (Binding::Key(Key::Left), Action::SelectLeft),
+                (Binding::Key(Key::Right), Action::SelectRight),
+                (Binding::Key(Key::Up), Action::SelectUp),
+                (Binding::Key(Key::Down), Action::SelectDown),
+                (Binding::Key(Key::Up), Action::MoveUp),
+                (Binding::Key(Key::Down), Action::MoveDown),
+                (Binding::Key(Key::Left), Action::Collapse),
+                (Binding::Key(Key::Right), Action::Expand),
+                (Binding::Key(Key::R), Action::Retry),
+                (Binding::Key(Key::S), Action::Skip),
+                (Binding::Key(Key::A), Action::Abort),
+                (Binding::Key(Key::Escape), Action::Back),
+                (Binding::Key(Key::Escape), Action::Quit),
+                (Binding::Key(Key::Left), Action::Decrease),
+                (Binding::Key(Key::Right), Action::Increase),
+                (Binding::Key(Key::Up), Action::PreviousInput),
+                (Binding::Key(Key::Down), Action::NextInput),
+                (Binding::Key(Key::Tab), Action::Complete),
+                (Binding::Key(Key::Up), Action::KeyboardUp),
+                (Binding::Key(Key::Down), Action::KeyboardDown),
+                (Binding::Key(Key::Left), Action::KeyboardLeft),
+                (Binding::Key(Key::Right), Action::KeyboardRight),
+                (Binding::Key(Key::Return), Action::KeyboardPress),
+                (Binding::Key(Key::PageUp), Action::ScrollBack),
+                (Binding::Key(Key::PageDown), Action::ScrollForward),
+                (Binding::Key(Key::Left), Action::SeekBack),
+                (Binding::Key(Key::Right), Action::SeekForward),
+            ),
+        }
+    }
+}
+
+impl KeyMap {
+    /// Binds the given key to the given action, in addition to any keys already bound to it.
+    /// ```
+    /// # use piston_window::Key;
+    /// # use simpleterm_profile::keymap::*;
+    /// let mut keymap: KeyMap = KeyMap::default();
+    /// keymap.bind(Key::Space, Action::Continue);
+    /// assert!(keymap.is_bound(Key::Space, Action::Continue));
+    /// assert!(keymap.is_bound(Key::Return, Action::Continue));
+    /// ```
+    pub fn bind(&mut self, key: Key, action: Action) {
+        self.add_binding(Binding::Key(key), action);
+    }
+
+    /// Binds the key at the given physical position to the given action, in addition to any keys already bound to it.
+    /// Scancodes are reported by the platform, so the same position may have a different number on another operating system.
+    /// ```
+    /// # use piston_window::Key;
+    /// # use simpleterm_profile::keymap::*;
+    /// let mut keymap: KeyMap = KeyMap::default();
+    /// keymap.bind_scancode(44, Action::Skip);
+    /// assert!(keymap.is_scancode_bound(44, Action::Skip));
+    /// assert_eq!(keymap.keys(Action::Skip), vec!(Key::S));
+    /// ```
+    pub fn bind_scancode(&mut self, scancode: i32, action: Action) {
+        self.add_binding(Binding::Scancode(scancode), action);
+    }
+
+    /// Removes every binding of the given action, so it can be rebound to different keys.
+    /// ```
+    /// # use piston_window::Key;
+    /// # use simpleterm_profile::keymap::*;
+    /// let mut keymap: KeyMap = KeyMap::default();
+    /// keymap.unbind(Action::Help);
+    /// keymap.bind(Key::H, Action::Help);
+    /// assert_eq!(keymap.keys(Action::Help), vec!(Key::H));
+    /// ```
+    pub fn unbind(&mut self, action: Action) {
+        self.bindings.retain(|(_, bound)| *bound != action);
+    }
+
+    /// Returns true if the given key performs the given action.
+    pub fn is_bound(&self, key: Key, action: Action) -> bool {
+        self.bindings.contains(&(Binding::Key(key), action))
+    }
+
+    /// Returns true if the key at the given physical position performs the given action.
+    pub fn is_scancode_bound(&self, scancode: i32, action: Action) -> bool {
+        self.bindings.contains(&(Binding::Scancode(scancode), action))
+    }
+
+    /// Returns true if the given button event came from a key that performs the given action, either by its key or by its scancode.
+    /// ```
+    /// # use piston_window::{Button, ButtonArgs, ButtonState, Key};
+    /// # use simpleterm_profile::keymap::*;
+    /// let mut keymap: KeyMap = KeyMap::default();
+    /// keymap.bind_scancode(44, Action::Skip);
+    /// let press: ButtonArgs = ButtonArgs { state: ButtonState::Press, button: Button::Keyboard(Key::W), scancode: Some(44) };
+    /// assert!(keymap.is_pressed(&press, Action::Skip));
+    /// assert!(!keymap.is_pressed(&press, Action::Abort));
+    /// ```
+    pub fn is_pressed(&self, button_args: &ButtonArgs, action: Action) -> bool {
+        let by_key: bool = matches!(button_args.button, Button::Keyboard(key) if self.is_bound(key, action));
+        by_key || button_args.scancode.is_some_and(|scancode| self.is_scancode_bound(scancode, action))
+    }
+
+    /// Returns true if the given button event came from a key that performs an action that repeats while held.
+    /// ```
+    /// # use piston_window::{Button, ButtonArgs, ButtonState, Key};
+    /// # use simpleterm_profile::keymap::*;
+    /// let keymap: KeyMap = KeyMap::default();
+    /// let press: ButtonArgs = ButtonArgs { state: ButtonState::Press, button: Button::Keyboard(Key::Backspace), scancode: None };
+    /// assert!(keymap.repeats(&press));
+    /// assert!(!keymap.repeats(&ButtonArgs { button: Button::Keyboard(Key::Return), ..press }));
+    /// ```
+    pub fn repeats(&self, button_args: &ButtonArgs) -> bool {
+        self.bindings.iter().any(|(_, action)| action.repeats() && self.is_pressed(button_args, *action))
+    }
+
+    /// Returns every key bound to the given action, in the order they were bound. Keys bound by scancode aren't included.
+    pub fn keys(&self, action: Action) -> Vec<Key> {
+        self.bindings.iter().filter_map(|(binding, bound)| match binding {
+            Binding::Key(key) if *bound == action => Some(*key),
+            _ => None,
+        }).collect()
+    }
+
+    /// Returns everything bound to the given action, keys and scancodes alike, in the order they were bound.
+    pub fn bindings(&self, action: Action) -> Vec<Binding> {
+        self.bindings.iter().filter(|(_, bound)| *bound == action).map(|(binding, _)| *binding).collect()
+    }
+
+    /// Returns a (keys, description) pair for each of the given actions that has at least one key bound.
+    /// ```
+    /// # use simpleterm_profile::keymap::*;
+    /// let keymap: KeyMap = KeyMap::default();
+    /// assert_eq!(
+    ///     keymap.help(&[Action::Continue, Action::Help]),
+    ///     vec!(
+    ///         (String::from("Return, NumPadEnter"), String::from("Continue")),
+    ///         (String::from("F1"), String::from("Show or hide this help")),
+    ///     )
+    /// );
+    /// ```
+    pub fn help(&self, actions: &[Action]) -> Vec<(String, String)> {
+        actions.iter().filter_map(|action| {
+            let keys: Vec<String> = self.bindings(*action).iter().map(|binding| match binding {
+                Binding::Key(key) => format!("{:?}", key),
+                Binding::Scancode(scancode) => format!("Scancode {}", scancode),
+            }).collect();
+            if keys.is_empty() {
+                None
+            } else {
+                Some((keys.join(", "), String::from(action.description())))
+            }
+        }).collect()
+    }
+
+    // Adds a binding unless it's already there.
+    fn add_binding(&mut self, binding: Binding, action: Action) {
+        if !self.bindings.contains(&(binding, action)) { self.bindings.push((binding, action)); }
+    }
+}
+
+/// Returns the character typed by the given keypad key, if it types one.
+/// ```
+/// # use piston_window::Key;
+/// # use simpleterm_profile::keymap::keypad_char;
+/// assert_eq!(keypad_char(Key::NumPad7), Some('7'));
+/// assert_eq!(keypad_char(Key::NumPadPeriod), Some('.'));
+/// assert_eq!(keypad_char(Key::D7), None);
+/// ```
+pub fn keypad_char(key: Key) -> Option<char> {
+    match key {
+        Key::NumPad0 => Some('0'),
+        Key::NumPad1 => Some('1'),
+        Key::NumPad2 => Some('2'),
+        Key::NumPad3 => Some('3'),
+        Key::NumPad4 => Some('4'),
+        Key::NumPad5 => Some('5'),
+        Key::NumPad6 => Some('6'),
+        Key::NumPad7 => Some('7'),
+        Key::NumPad8 => Some('8'),
+        Key::NumPad9 => Some('9'),
+        Key::NumPadPeriod => Some('.'),
+        Key::NumPadPlus => Some('+'),
+        Key::NumPadMinus => Some('-'),
+        Key::NumPadMultiply => Some('*'),
+        Key::NumPadDivide => Some('/'),
+        _ => None,
+    }
+}
+
+/// Returns which function key the given key is, from 1 for F1 up to 12 for F12.
+/// ```
+/// # use piston_window::Key;
+/// # use simpleterm_profile::keymap::function_key;
+/// assert_eq!(function_key(Key::F2), Some(2));
+/// assert_eq!(function_key(Key::Return), None);
+/// ```
+pub fn function_key(key: Key) -> Option<u8> {
+    match key {
+        Key::F1 => Some(1),
+        Key::F2 => Some(2),
+        Key::F3 => Some(3),
+        Key::F4 => Some(4),
+        Key::F5 => Some(5),
+        Key::F6 => Some(6),
+        Key::F7 => Some(7),
+        Key::F8 => Some(8),
+        Key::F9 => Some(9),
+        Key::F10 => Some(10),
+        Key::F11 => Some(11),
+        Key::F12 => Some(12),
+        _ => None,
+    }
+}
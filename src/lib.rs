@@ -19,23 +19,108 @@
 
 use std::time::Duration;
 
+/// Loads classic ANSI art files, with code page 437 characters and color escape codes.
+pub mod ansi;
+
+/// The ways a question can end, returned by ask.
+pub mod answer;
+
+/// Typing sounds played through the default audio output.
+#[cfg(feature = "audio")]
+pub mod audio;
+
 /// Ascii art strings.
 pub mod art;
 
+/// The script-level interface shared by every terminal backend, and the screens a windowed terminal draws on.
+pub mod backend;
+
+/// Timed caption tracks read from SRT and WebVTT files.
+pub mod captions;
+
+/// Commands queued for the terminal's non-blocking mode.
+pub mod command;
+
 /// Draws rectangles and text on the terminal window.
 pub mod draw;
 
+/// Canned scenes and effects built on top of the terminal.
+pub mod effects;
+
+/// Pretty-prints JSON values with syntax colors and folding.
+#[cfg(feature = "json")]
+pub mod json;
+
+/// Stand-in event sources for driving the terminal from tests and fuzzers.
+pub mod input;
+
+/// An on-screen keyboard for typing without a physical keyboard.
+pub mod keyboard;
+
+/// Maps keys to the actions they perform.
+pub mod keymap;
+
+/// Morse code and the timing for sending it.
+pub mod morse;
+
+/// Draws the terminal in memory with the CPU, for screens with no graphics card to draw with.
+pub mod raster;
+
+/// A seedable random number generator for reproducible effects.
+pub mod rng;
+
+/// End-user settings like font size and theme, and how they're saved.
+pub mod settings;
+
+/// Sprite sheets cut into cells and played as art animations.
+pub mod sprites;
+
+/// A screen that draws with the CPU into a plain window, for machines with no OpenGL driver.
+#[cfg(not(target_arch = "wasm32"))]
+pub mod software;
+
+/// Frame timing statistics collected while the terminal draws.
+pub mod stats;
+
 /// Creates and interacts with a terminal window.
 pub mod terminal;
 
 /// Contains functions related to text color and bounds.
 pub mod text;
 
+/// Renders the terminal inside the real terminal the program was started from.
+#[cfg(feature = "tty")]
+pub mod tty;
+
+/// Live data widgets shown in a corner panel of the terminal.
+pub mod widgets;
+
+/// A screen that draws on a canvas in a web page, for running in the browser.
+#[cfg(feature = "wasm")]
+pub mod web;
+
+/// The default screen, a piston_window window drawn with OpenGL.
+#[cfg(not(target_arch = "wasm32"))]
+pub mod window;
+
+/// Chains questions into a multi-step wizard the user can step back through.
+pub mod wizard;
+
 /// Indicates the x and y offset of the text and surrounding box from the corners of the terminal window.
 pub const TEXT_OFFSET: (f64, f64) = (25.0, 50.0);
 
+/// The smallest window size, in points, that the terminal lays out text for. Windows can't be resized smaller than this,
+/// and windows that end up smaller anyway, like minimized ones, are laid out at this size and cropped.
+pub const MIN_WINDOW_SIZE: (f64, f64) = (200.0, 120.0);
+
+/// How long should the terminal take to fade out when closing with a farewell.
+pub const FADE_TIME: Duration = Duration::from_millis(1000);
+
 /// How long should elements like "Press Enter to Continue" or the input cursor take before toggling their flash state.
 pub const FLASH_TIME: Duration = Duration::from_millis(500);
 
+/// How long the input marker stays lit in the bell color after a key press is ignored, and how long the screen flashes for a bell character.
+pub const BELL_TIME: Duration = Duration::from_millis(150);
+
 /// How long should the terminal take to type a single character when displaying a message.
 pub const TYPE_TIME: Duration = Duration::from_millis(20);
\ No newline at end of file
@@ -3,17 +3,17 @@ use std::time::Duration;
 use simpleterm_profile::{art::*, text::*, terminal::Terminal};
 
 fn main() {
-    // Create a window and display the GEO art (from art.rs) for 2 seconds.
+    // Create a window and cycle through the DANCES art (from art.rs) in different colors.
     let mut term: Terminal = Terminal::new("simpleterm test", (420, 120), DARK_GREY, EMERALD, "LeagueSpartan-Regular.ttf", 32);
     term.art_font_size = 24;
     let c_len: usize = COLORS.len();
 
     while term.active {
         let mut count: usize = 0;
-        for i in 0..16 {
+        for dance in DANCES.iter() {
             for _ in 0..4 {
                 term.fg_color = COLORS[count % c_len];
-                term.display_art(DANCES[i], Duration::from_millis(200));
+                term.display_art(dance, Duration::from_millis(200));
                 count += 1;
             }
         }
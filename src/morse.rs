@@ -0,0 +1,104 @@
+use std::ops::Range;
+
+// The international Morse code for each character it covers.
+const CODES: [(char, &str); 54] = [
+    ('A', ".-"), ('B', "-..."), ('C', "-.-."), ('D', "-.."), ('E', "."), ('F', "..-."), ('G', "--."), ('H', "...."),
+    ('I', ".."), ('J', ".---"), ('K', "-.-"), ('L', ".-.."), ('M', "--"), ('N', "-."), ('O', "---"), ('P', ".--."),
+    ('Q', "--.-"), ('R', ".-."), ('S', "..."), ('T', "-"), ('U', "..-"), ('V', "...-"), ('W', ".--"), ('X', "-..-"),
+    ('Y', "-.--"), ('Z', "--.."),
+    ('0', "-----"), ('1', ".----"), ('2', "..---"), ('3', "...--"), ('4', "....-"),
+    ('5', "....."), ('6', "-...."), ('7', "--..."), ('8', "---.."), ('9', "----."),
+    ('.', ".-.-.-"), (',', "--..--"), ('?', "..--.."), ('\'', ".----."), ('!', "-.-.--"), ('/', "-..-."),
+    ('(', "-.--."), (')', "-.--.-"), ('&', ".-..."), (':', "---..."), (';', "-.-.-."), ('=', "-...-"),
+    ('+', ".-.-."), ('-', "-....-"), ('_', "..--.-"), ('"', ".-..-."), ('$', "...-..-"), ('@', ".--.-."),
+];
+
+/// Returns the Morse code for a character as dots and dashes, ignoring case, or None if Morse has no code for it.
+/// ```
+/// # use simpleterm_profile::morse::code;
+/// assert_eq!(code('s'), Some("..."));
+/// assert_eq!(code('#'), None);
+/// ```
+pub fn code(c: char) -> Option<&'static str> {
+    let c: char = c.to_ascii_uppercase();
+    CODES.iter().find(|(letter, _)| *letter == c).map(|(_, code)| *code)
+}
+
+/// Writes text out in Morse code, with a space between letters and a slash between words.
+/// Characters without a code are left out.
+/// ```
+/// # use simpleterm_profile::morse::encode;
+/// assert_eq!(encode("SOS, help"), "... --- ... --..-- / .... . .-.. .--.");
+/// ```
+pub fn encode(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| word.chars().filter_map(code).collect::<Vec<&str>>().join(" "))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<String>>()
+        .join(" / ")
+}
+
+/// When each character of some lines of text is sent in Morse code, measured in units, the length of a dot.
+/// A dash is three units, the gap inside a letter one, the gap between letters three, and the gap between words seven.
+/// The end of a line counts as a gap between words, and characters without a code are sent instantly.
+/// ```
+/// # use simpleterm_profile::morse::MorseTiming;
+/// let timing: MorseTiming = MorseTiming::new(&[String::from("ET"), String::from("E")]);
+/// assert_eq!(timing.signals, vec![0..1, 4..7, 14..15]);
+/// assert_eq!(timing.char_starts, vec![0, 4, 14]);
+/// assert_eq!(timing.length, 15);
+/// assert!(timing.lit(5.5));
+/// assert_eq!(timing.typed(5.5), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MorseTiming {
+    /// When each character, in order across every line, starts being sent.
+    pub char_starts: Vec<u32>,
+    /// When the signal is on, for each dot and dash.
+    pub signals: Vec<Range<u32>>,
+    /// When the last signal ends.
+    pub length: u32,
+}
+
+impl MorseTiming {
+    /// Works out the timing for sending the given lines of text one after another.
+    pub fn new(lines: &[String]) -> MorseTiming {
+        let mut timing: MorseTiming = MorseTiming::default();
+        let mut time: u32 = 0;
+        let mut gap: u32 = 0;
+        for line in lines {
+            for c in line.chars() {
+                match code(c) {
+                    Some(code) => {
+                        if !timing.signals.is_empty() { time += gap.max(3); }
+                        timing.char_starts.push(time);
+                        for (i, element) in code.chars().enumerate() {
+                            if i > 0 { time += 1; }
+                            let length: u32 = if element == '-' { 3 } else { 1 };
+                            timing.signals.push(time..time + length);
+                            time += length;
+                        }
+                        gap = 0;
+                    },
+                    None => {
+                        if c.is_whitespace() { gap = 7; }
+                        timing.char_starts.push(time + gap);
+                    },
+                }
+            }
+            gap = 7;
+        }
+        timing.length = time;
+        timing
+    }
+
+    /// Returns true if the signal is on at the given time.
+    pub fn lit(&self, time: f64) -> bool {
+        self.signals.iter().any(|signal| signal.start as f64 <= time && time < signal.end as f64)
+    }
+
+    /// Returns how many characters have started being sent by the given time.
+    pub fn typed(&self, time: f64) -> usize {
+        self.char_starts.iter().take_while(|start| **start as f64 <= time).count()
+    }
+}
@@ -0,0 +1,382 @@
+use graphics::{character::CharacterCache, draw_state::DrawState, glyph_cache::rusttype::GlyphCache, math::Matrix2d, rectangle::Shape, types::{Color, FontSize}, *};
+use std::{collections::HashMap, error::Error, ops::Range, path::Path};
+use texture::{CreateTexture, Format, TextureOp, TextureSettings, UpdateTexture};
+
+use crate::{backend::Scene, draw::*};
+
+/// A glyph cache that renders text to [SoftTextures](struct.SoftTexture.html), for drawing on a [Raster](struct.Raster.html).
+pub type SoftGlyphs = GlyphCache<'static, (), SoftTexture>;
+
+/// An image kept in memory for drawing on a [Raster](struct.Raster.html), like a glyph or a scanline pattern.
+/// Textures are always sampled smoothly, whatever filter their settings ask for.
+#[derive(Clone, Debug)]
+pub struct SoftTexture {
+    width: u32,
+    height: u32,
+    pixels: Vec<[u8; 4]>,
+}
+
+impl SoftTexture {
+    // Returns the color of the texture at the given texture coordinate, where (0, 0) is the top left corner and (1, 1) the bottom right,
+    // blended from the four nearest pixels.
+    fn sample(&self, [u, v]: [f32; 2]) -> [f32; 4] {
+        let (x, y): (f32, f32) = ((u * self.width as f32 - 0.5).max(0.0), (v * self.height as f32 - 0.5).max(0.0));
+        let (left, top): (u32, u32) = ((x as u32).min(self.width - 1), (y as u32).min(self.height - 1));
+        let (right, bottom): (u32, u32) = ((left + 1).min(self.width - 1), (top + 1).min(self.height - 1));
+        let (fx, fy): (f32, f32) = ((x - left as f32).min(1.0), (y - top as f32).min(1.0));
+
+        // Colors are blended weighted by their alpha, so transparent pixels don't darken the edges of what they surround.
+        let mut color: [f32; 4] = [0.0; 4];
+        for (px, py, weight) in [(left, top, (1.0 - fx) * (1.0 - fy)), (right, top, fx * (1.0 - fy)), (left, bottom, (1.0 - fx) * fy), (right, bottom, fx * fy)] {
+            let [r, g, b, a] = self.pixels[(py * self.width + px) as usize].map(|channel| channel as f32 / 255.0);
+            color = [color[0] + r * a * weight, color[1] + g * a * weight, color[2] + b * a * weight, color[3] + a * weight];
+        }
+        if color[3] > 0.0 { [color[0] / color[3], color[1] / color[3], color[2] / color[3], color[3]] } else { color }
+    }
+}
+
+impl ImageSize for SoftTexture {
+    fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
+impl TextureOp<()> for SoftTexture {
+    type Error = String;
+}
+
+impl CreateTexture<()> for SoftTexture {
+    fn create<S: Into<[u32; 2]>>(_factory: &mut (), _format: Format, memory: &[u8], size: S, _settings: &TextureSettings) -> Result<SoftTexture, String> {
+        let [width, height] = size.into();
+        let length: usize = (width * height) as usize;
+        if width == 0 || height == 0 || memory.len() < length * 4 {
+            return Err(format!("{} bytes is not enough for a {}x{} texture", memory.len(), width, height));
+        }
+
+        let pixels: Vec<[u8; 4]> = memory.chunks_exact(4).take(length).map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]]).collect();
+        Ok(SoftTexture { width, height, pixels })
+    }
+}
+
+impl UpdateTexture<()> for SoftTexture {
+    fn update<O: Into<[u32; 2]>, S: Into<[u32; 2]>>(&mut self, _factory: &mut (), _format: Format, memory: &[u8], offset: O, size: S) -> Result<(), String> {
+        let ([x, y], [width, height]) = (offset.into(), size.into());
+        if x + width > self.width || y + height > self.height || memory.len() < (width * height * 4) as usize {
+            return Err(format!("a {}x{} update at ({}, {}) doesn't fit a {}x{} texture", width, height, x, y, self.width, self.height));
+        }
+
+        for (row, source) in memory.chunks_exact(width as usize * 4).take(height as usize).enumerate() {
+            let start: usize = ((y + row as u32) * self.width + x) as usize;
+            for (pixel, bytes) in self.pixels[start..start + width as usize].iter_mut().zip(source.chunks_exact(4)) {
+                *pixel = [bytes[0], bytes[1], bytes[2], bytes[3]];
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A frame drawn in memory by the CPU, for screens with no graphics card to draw with.
+/// Pixels are stored as opaque RGBA, row by row from the top left.
+///
+/// ```
+/// # use simpleterm_profile::raster::Raster;
+/// use graphics::{clear, rectangle};
+///
+/// let mut raster: Raster = Raster::new(40, 30);
+/// raster.draw([40.0, 30.0], |c, g| {
+///     clear([0.0, 0.0, 0.0, 1.0], g);
+///     rectangle([1.0, 0.0, 0.0, 1.0], [10.0, 10.0, 20.0, 10.0], c.transform, g);
+/// });
+/// assert_eq!(raster.pixel(15, 15), [255, 0, 0, 255]);
+/// assert_eq!(raster.pixel(5, 5), [0, 0, 0, 255]);
+/// ```
+pub struct Raster {
+    width: u32,
+    height: u32,
+    pixels: Vec<[u8; 4]>,
+}
+
+impl Raster {
+    /// Creates a black raster the given size in pixels.
+    pub fn new(width: u32, height: u32) -> Raster {
+        Raster { width, height, pixels: vec![[0, 0, 0, 255]; (width * height) as usize] }
+    }
+
+    /// Returns the size of this raster in pixels.
+    pub fn size(&self) -> [u32; 2] {
+        [self.width, self.height]
+    }
+
+    /// Changes the size of this raster in pixels, clearing it to black if the size changed.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        if [width, height] != self.size() {
+            *self = Raster::new(width, height);
+        }
+    }
+
+    /// Returns the pixels of this raster.
+    pub fn pixels(&self) -> &[[u8; 4]] {
+        &self.pixels
+    }
+
+    /// Returns the pixel at the given column and row.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        self.pixels[(y * self.width + x) as usize]
+    }
+
+    /// Draws on this raster, calling draw with a context covering a window of the given size in points,
+    /// the same way as [draw_2d](https://docs.rs/piston_window/0.109.0/piston_window/struct.PistonWindow.html#method.draw_2d).
+    pub fn draw<F>(&mut self, window_size: [f64; 2], draw: F)
+    where
+        F: FnOnce(Context, &mut RasterGraphics),
+    {
+        let viewport: Viewport = Viewport { rect: [0, 0, self.width as i32, self.height as i32], draw_size: self.size(), window_size };
+        draw(Context::new_viewport(viewport), &mut RasterGraphics { raster: self });
+    }
+
+    /// Returns a copy of this raster as a texture, so it can be drawn again, like the halves of a split transition.
+    pub fn to_texture(&self) -> SoftTexture {
+        SoftTexture { width: self.width, height: self.height, pixels: self.pixels.clone() }
+    }
+
+    // Blends a color over the pixels of one row from column start up to column end.
+    fn blend_span(&mut self, row: u32, (start, end): (u32, u32), mut color: impl FnMut(u32) -> Option<[f32; 4]>) {
+        let offset: usize = (row * self.width) as usize;
+        for x in start..end {
+            if let Some([r, g, b, a]) = color(x) {
+                let pixel: &mut [u8; 4] = &mut self.pixels[offset + x as usize];
+                let a: f32 = a.clamp(0.0, 1.0);
+                for (channel, value) in pixel.iter_mut().zip([r, g, b]) {
+                    *channel = (value.clamp(0.0, 1.0) * 255.0 * a + *channel as f32 * (1.0 - a) + 0.5) as u8;
+                }
+            }
+        }
+    }
+
+    // Fills a triangle given in normalized device coordinates, where color gives the color at each pixel it covers
+    // from the pixel's weights for each corner.
+    fn fill_triangle(&mut self, draw_state: &DrawState, corners: [[f32; 2]; 3], mut color: impl FnMut([f32; 3]) -> Option<[f32; 4]>) {
+        let points: [[f32; 2]; 3] = corners.map(|[x, y]| [(x + 1.0) / 2.0 * self.width as f32, (1.0 - y) / 2.0 * self.height as f32]);
+        let [[x0, y0], [x1, y1], [x2, y2]] = points;
+        let area: f32 = (x1 - x0) * (y2 - y0) - (x2 - x0) * (y1 - y0);
+        if area == 0.0 || !area.is_finite() { return; }
+
+        let [left, top, right, bottom] = self.clip(draw_state.scissor);
+        let low: f32 = y0.min(y1).min(y2).max(top as f32);
+        let high: f32 = y0.max(y1).max(y2).min(bottom as f32);
+        let (first_row, last_row): (u32, u32) = ((low - 0.5).ceil().max(0.0) as u32, (high - 0.5).ceil().max(0.0) as u32);
+        for row in first_row..last_row {
+            let center: f32 = row as f32 + 0.5;
+            let mut span: (f32, f32) = (f32::INFINITY, f32::NEG_INFINITY);
+            for (a, b) in [(points[0], points[1]), (points[1], points[2]), (points[2], points[0])] {
+                if (a[1] <= center) != (b[1] <= center) {
+                    let x: f32 = a[0] + (center - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+                    span = (span.0.min(x), span.1.max(x));
+                }
+            }
+            if span.0 > span.1 { continue; }
+
+            let start: u32 = ((span.0 - 0.5).ceil().max(left as f32) as u32).min(right);
+            let end: u32 = ((span.1 - 0.5).ceil().max(left as f32) as u32).min(right);
+            self.blend_span(row, (start, end), |x| {
+                let (px, py): (f32, f32) = (x as f32 + 0.5, center);
+                let w1: f32 = ((px - x0) * (y2 - y0) - (x2 - x0) * (py - y0)) / area;
+                let w2: f32 = ((x1 - x0) * (py - y0) - (px - x0) * (y1 - y0)) / area;
+                color([1.0 - w1 - w2, w1, w2])
+            });
+        }
+    }
+
+    // Returns the rows and columns of pixels a rectangle covers, given in the coordinates of a transform that doesn't rotate it,
+    // along with the pixel position of the rectangle's first corner and its size in pixels, which is negative along flipped sides.
+    fn rect_pixels(&self, draw_state: &DrawState, transform: Matrix2d, rect: [f64; 4]) -> (Range<u32>, Range<u32>, [f64; 2], [f64; 2]) {
+        let (width, height): (f64, f64) = (self.width as f64, self.height as f64);
+        let to_pixel = |x: f64, y: f64| -> [f64; 2] {
+            let (nx, ny): (f64, f64) = (transform[0][0] * x + transform[0][2], transform[1][1] * y + transform[1][2]);
+            [(nx + 1.0) / 2.0 * width, (1.0 - ny) / 2.0 * height]
+        };
+        let start: [f64; 2] = to_pixel(rect[0], rect[1]);
+        let end: [f64; 2] = to_pixel(rect[0] + rect[2], rect[1] + rect[3]);
+
+        let [left, top, right, bottom] = self.clip(draw_state.scissor);
+        let column = |x: f64| ((x - 0.5).ceil().max(0.0) as u32).clamp(left, right);
+        let row = |y: f64| ((y - 0.5).ceil().max(0.0) as u32).clamp(top, bottom);
+        let rows: Range<u32> = row(start[1].min(end[1]))..row(start[1].max(end[1]));
+        let columns: Range<u32> = column(start[0].min(end[0]))..column(start[0].max(end[0]));
+        (rows, columns, start, [end[0] - start[0], end[1] - start[1]])
+    }
+
+    // Returns the part of the raster drawing is allowed in as [left, top, right, bottom], limited to the scissor rectangle if there is one.
+    fn clip(&self, scissor: Option<[u32; 4]>) -> [u32; 4] {
+        match scissor {
+            Some([x, y, w, h]) => [x.min(self.width), y.min(self.height), x.saturating_add(w).min(self.width), y.saturating_add(h).min(self.height)],
+            None => [0, 0, self.width, self.height],
+        }
+    }
+}
+
+/// Draws on a [Raster](struct.Raster.html) with piston's graphics, so every draw function works on it.
+pub struct RasterGraphics<'a> {
+    raster: &'a mut Raster,
+}
+
+impl Graphics for RasterGraphics<'_> {
+    type Texture = SoftTexture;
+
+    fn clear_color(&mut self, [r, g, b, _]: Color) {
+        let [r, g, b] = [r, g, b].map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as u8);
+        self.raster.pixels.fill([r, g, b, 255]);
+    }
+
+    fn clear_stencil(&mut self, _value: u8) {}
+
+    fn tri_list<F>(&mut self, draw_state: &DrawState, color: &[f32; 4], mut f: F)
+    where
+        F: FnMut(&mut dyn FnMut(&[[f32; 2]])),
+    {
+        let raster: &mut Raster = self.raster;
+        f(&mut |vertices: &[[f32; 2]]| {
+            for corners in vertices.chunks_exact(3) {
+                raster.fill_triangle(draw_state, [corners[0], corners[1], corners[2]], |_| Some(*color));
+            }
+        });
+    }
+
+    fn tri_list_uv<F>(&mut self, draw_state: &DrawState, color: &[f32; 4], texture: &SoftTexture, mut f: F)
+    where
+        F: FnMut(&mut dyn FnMut(&[[f32; 2]], &[[f32; 2]])),
+    {
+        let raster: &mut Raster = self.raster;
+        f(&mut |vertices: &[[f32; 2]], uvs: &[[f32; 2]]| {
+            for (corners, uv) in vertices.chunks_exact(3).zip(uvs.chunks_exact(3)) {
+                raster.fill_triangle(draw_state, [corners[0], corners[1], corners[2]], |[w0, w1, w2]| {
+                    let point: [f32; 2] = [0, 1].map(|i| (uv[0][i] * w0 + uv[1][i] * w1 + uv[2][i] * w2).clamp(0.0, 1.0));
+                    let texel: [f32; 4] = texture.sample(point);
+                    if texel[3] == 0.0 { return None; }
+                    Some([0, 1, 2, 3].map(|i| color[i] * texel[i]))
+                });
+            }
+        });
+    }
+
+    fn rectangle<R: Into<types::Rectangle>>(&mut self, r: &Rectangle, rectangle: R, draw_state: &DrawState, transform: Matrix2d) {
+        // Plain rectangles that aren't rotated are filled a row at a time instead of as two triangles, since most of a frame is made of them.
+        if r.border.is_some() || !matches!(r.shape, Shape::Square) || rotates(transform) {
+            r.draw_tri(rectangle, draw_state, transform, self);
+            return;
+        }
+
+        let (rows, columns, _, _) = self.raster.rect_pixels(draw_state, transform, rectangle.into());
+        for y in rows {
+            self.raster.blend_span(y, (columns.start, columns.end), |_| Some(r.color));
+        }
+    }
+
+    fn image(&mut self, image: &Image, texture: &SoftTexture, draw_state: &DrawState, transform: Matrix2d) {
+        if rotates(transform) {
+            image.draw_tri(texture, draw_state, transform, self);
+            return;
+        }
+
+        let (width, height): (f64, f64) = (texture.width as f64, texture.height as f64);
+        let source: [f64; 4] = image.source_rectangle.unwrap_or([0.0, 0.0, width, height]);
+        let color: Color = image.color.unwrap_or([1.0; 4]);
+        let tint = move |texel: [f32; 4]| if texel[3] > 0.0 { Some([0, 1, 2, 3].map(|i| color[i] * texel[i])) } else { None };
+
+        let (rows, columns, origin, size) = self.raster.rect_pixels(draw_state, transform, image.rectangle.unwrap_or([0.0, 0.0, source[2], source[3]]));
+        let u = |x: u32| ((source[0] + (x as f64 + 0.5 - origin[0]) / size[0] * source[2]) / width) as f32;
+        for y in rows {
+            let v: f32 = ((source[1] + (y as f64 + 0.5 - origin[1]) / size[1] * source[3]) / height) as f32;
+            if texture.width == 1 {
+                // Textures one pixel wide, like scanlines, are the same color all the way across each row.
+                let texel: [f32; 4] = texture.sample([0.5, v]);
+                self.raster.blend_span(y, (columns.start, columns.end), |_| tint(texel));
+            } else {
+                self.raster.blend_span(y, (columns.start, columns.end), |x| tint(texture.sample([u(x), v])));
+            }
+        }
+    }
+}
+
+// Returns true if the transform rotates or skews what it draws, rather than only moving and scaling it.
+fn rotates(transform: Matrix2d) -> bool {
+    transform[0][1] != 0.0 || transform[1][0] != 0.0
+}
+
+/// Draws whole [Scenes](../backend/struct.Scene.html) on a [Raster](struct.Raster.html), keeping the fonts and scanlines they need between frames.
+/// Screens with no graphics card to draw with, like the browser canvas, draw with one and then show its raster.
+pub struct SoftwareRenderer {
+    raster: Raster,
+    fonts: HashMap<String, SoftGlyphs>,
+    embedded_fonts: HashMap<String, &'static [u8]>,
+    keyboard_glyphs: Option<(String, SoftGlyphs)>,
+    scanlines: Option<Scanlines<SoftTexture>>,
+}
+
+impl Default for SoftwareRenderer {
+    fn default() -> SoftwareRenderer {
+        SoftwareRenderer::new()
+    }
+}
+
+impl SoftwareRenderer {
+    /// Creates a renderer with no fonts loaded yet.
+    pub fn new() -> SoftwareRenderer {
+        SoftwareRenderer { raster: Raster::new(1, 1), fonts: HashMap::new(), embedded_fonts: HashMap::new(), keyboard_glyphs: None, scanlines: None }
+    }
+
+    /// Adds a font kept in memory under the given name, which is then used instead of the file of that name in the resources folder.
+    /// This is how fonts are loaded where there's no file system, like in a browser.
+    pub fn add_font(&mut self, name: &str, data: &'static [u8]) {
+        self.embedded_fonts.insert(String::from(name), data);
+        self.fonts.remove(name);
+    }
+
+    /// Loads the given font, if it isn't loaded already, or returns an error if it can't be loaded.
+    pub fn load_font(&mut self, font: &str) -> Result<(), Box<dyn Error>> {
+        if !self.fonts.contains_key(font) {
+            let glyphs: SoftGlyphs = self.open_font(font)?;
+            self.fonts.insert(String::from(font), glyphs);
+        }
+        Ok(())
+    }
+
+    /// Returns how wide the given text is in the given font and size, loading the font first if it isn't loaded yet.
+    pub fn text_width(&mut self, font: &str, size: FontSize, text: &str) -> Option<f64> {
+        self.load_font(font).ok()?;
+        self.fonts.get_mut(font)?.width(size, text).ok()
+    }
+
+    /// Draws a frame of the scene at the given size in pixels, and returns the raster it was drawn on.
+    /// The scene's size is the window's size in points, which may differ from its size in pixels on high-DPI displays.
+    pub fn render(&mut self, scene: &Scene, draw_size: [u32; 2]) -> &Raster {
+        self.load_font(scene.font).unwrap();
+        if scene.keyboard.is_some() && self.keyboard_glyphs.as_ref().is_none_or(|(font, _)| font != scene.keyboard_font) {
+            self.keyboard_glyphs = Some((String::from(scene.keyboard_font), self.open_font(scene.keyboard_font).unwrap()));
+        }
+        if scene.scanlines && !self.scanlines.as_ref().is_some_and(|lines| lines.matches(scene.size, scene.bg, scene.fg)) {
+            self.scanlines = Scanlines::new(&mut (), scene.size, scene.bg, scene.fg);
+        }
+
+        self.raster.resize(draw_size[0].max(1), draw_size[1].max(1));
+        let window_size: [f64; 2] = [scene.size.width, scene.size.height];
+        let scanlines: Option<&Scanlines<SoftTexture>> = self.scanlines.as_ref().filter(|_| scene.scanlines);
+        let glyphs: &mut SoftGlyphs = self.fonts.get_mut(scene.font).unwrap();
+        let keyboard_glyphs: Option<&mut SoftGlyphs> = self.keyboard_glyphs.as_mut().map(|(_, glyphs)| glyphs).filter(|_| scene.keyboard.is_some());
+        self.raster.draw(window_size, |c, g| draw_scene(scene, glyphs, keyboard_glyphs, scanlines, c, g));
+
+        if let Some(split) = scene.split {
+            let frame: SoftTexture = self.raster.to_texture();
+            self.raster.draw(window_size, |c, g| draw_split_scene(scene, (&frame, false), split, scanlines, c, g));
+        }
+        &self.raster
+    }
+
+    // Opens the glyph cache for a font, from memory if it was added with add_font and from the resources folder otherwise.
+    fn open_font(&self, font: &str) -> Result<SoftGlyphs, Box<dyn Error>> {
+        match self.embedded_fonts.get(font) {
+            Some(data) => SoftGlyphs::from_bytes(data, (), TextureSettings::new()).map_err(|_| format!("{} isn't a font", font).into()),
+            None => Ok(SoftGlyphs::new(Path::new("resources").join(font), (), TextureSettings::new())?),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use web_time::{SystemTime, UNIX_EPOCH};
+
+/// A small, fast pseudo-random number generator (xorshift64). It's not suitable for anything security related,
+/// but the same seed always produces the same numbers, so "random" effects can be replayed exactly.
+/// ```
+/// # use simpleterm_profile::rng::Rng;
+/// let mut first: Rng = Rng::new(42);
+/// let mut second: Rng = Rng::new(42);
+/// let rolls: Vec<u64> = (0..5).map(|_| first.below(6)).collect();
+/// assert_eq!(rolls, (0..5).map(|_| second.below(6)).collect::<Vec<u64>>());
+/// assert!(rolls.iter().all(|roll| *roll < 6));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Default for Rng {
+    /// Creates a generator seeded from the current time.
+    fn default() -> Rng {
+        Rng::new(SystemTime::now().duration_since(UNIX_EPOCH).map_or(1, |time| time.as_nanos() as u64))
+    }
+}
+
+impl Rng {
+    /// Creates a generator from the given seed.
+    pub fn new(seed: u64) -> Rng {
+        // Xorshift gets stuck on a state of zero, so every seed is made odd.
+        Rng { state: seed | 1 }
+    }
+
+    /// Returns the next pseudo-random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Returns a pseudo-random number below max, or 0 if max is 0.
+    pub fn below(&mut self, max: u64) -> u64 {
+        self.next_u64() % max.max(1)
+    }
+
+    /// Returns a pseudo-random number from min up to and including max.
+    /// ```
+    /// # use simpleterm_profile::rng::Rng;
+    /// let mut rng: Rng = Rng::new(7);
+    /// assert!((0..100).map(|_| rng.range(3, 5)).all(|n| (3..=5).contains(&n)));
+    /// ```
+    pub fn range(&mut self, min: u64, max: u64) -> u64 {
+        min + self.below(max.saturating_sub(min) + 1)
+    }
+
+    /// Returns true with the given probability, from 0.0 (never) to 1.0 (always).
+    /// ```
+    /// # use simpleterm_profile::rng::Rng;
+    /// let mut rng: Rng = Rng::new(7);
+    /// assert!(!rng.chance(0.0));
+    /// assert!(rng.chance(1.0));
+    /// ```
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Returns a pseudo-randomly chosen item from the given slice, or None if it's empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        items.get(self.below(items.len() as u64) as usize)
+    }
+}
@@ -0,0 +1,140 @@
+use graphics::types::{Color, FontSize};
+use std::time::Duration;
+
+use crate::{text::*, TYPE_TIME};
+
+/// The color themes offered by the settings screen, as (name, background, foreground).
+pub const THEMES: [(&str, Color, Color); 6] = [
+    ("Amber", DARK_GREY, GOLD),
+    ("Phosphor", DARK_GREY, EMERALD),
+    ("Ice", DARK_GREY, LIGHT_BLUE),
+    ("Violet", DARK_GREY, LIGHT_PURPLE),
+    ("Paper", OFF_WHITE, DARK_GREY),
+    ("Alert", DARK_GREY, CRIMSON),
+];
+
+/// The smallest and largest font sizes the settings screen allows.
+pub const FONT_SIZE_RANGE: (FontSize, FontSize) = (12, 64);
+
+/// The longest time per character the settings screen allows, in milliseconds.
+pub const MAX_TYPE_MILLIS: u64 = 100;
+
+/// The smallest font size text is drawn at in high visibility mode, whatever the font size setting.
+pub const HIGH_VISIBILITY_FONT_SIZE: FontSize = 40;
+
+/// The most characters on a line of a message in high visibility mode.
+pub const HIGH_VISIBILITY_LINE_LENGTH: usize = 40;
+
+/// How far apart lines are drawn in high visibility mode, as a multiple of the font size.
+pub const HIGH_VISIBILITY_LINE_SPACING: f64 = 1.2;
+
+/// Setting this environment variable to anything but "0" or "false" starts terminals in high visibility mode.
+pub const HIGH_VISIBILITY_VAR: &str = "SIMPLETERM_HIGH_VISIBILITY";
+
+/// The terminal settings an end user can change from the settings screen.
+/// Settings can be saved to and loaded from a simple text format with one "name value" pair per line.
+/// ```
+/// # use std::time::Duration;
+/// # use simpleterm_profile::{settings::*, text::*};
+/// let settings: Settings = Settings { font_size: 24, bg_color: DARK_GREY, fg_color: EMERALD, type_time: Duration::from_millis(35), scanlines: false, high_visibility: true };
+/// assert_eq!(settings.theme_name(), "Phosphor");
+/// assert_eq!(Settings::parse(&settings.to_text(), Settings::default()), settings);
+///
+/// let partial: Settings = Settings::parse("font_size 40\nnonsense here", Settings::default());
+/// assert_eq!(partial.font_size, 40);
+/// assert!(partial.scanlines);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Settings {
+    /// The font size of normal text.
+    pub font_size: FontSize,
+    /// The background color.
+    pub bg_color: Color,
+    /// The foreground color.
+    pub fg_color: Color,
+    /// How long typing a single character takes.
+    pub type_time: Duration,
+    /// Whether scanlines are drawn.
+    pub scanlines: bool,
+    /// Whether text is drawn large, on short lines spaced further apart, for readers with low vision.
+    pub high_visibility: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings { font_size: 32, bg_color: DARK_GREY, fg_color: GOLD, type_time: TYPE_TIME, scanlines: true, high_visibility: false }
+    }
+}
+
+impl Settings {
+    /// Reads settings from text written by [to_text](#method.to_text). Anything missing or unreadable keeps its value from defaults.
+    pub fn parse(text: &str, defaults: Settings) -> Settings {
+        let mut settings: Settings = defaults;
+        for line in text.lines() {
+            let mut words = line.split_whitespace();
+            let name: &str = words.next().unwrap_or_default();
+            let values: Vec<&str> = words.collect();
+
+            match (name, &values[..]) {
+                ("font_size", [size]) => if let Ok(size) = size.parse() { settings.font_size = size; },
+                ("bg_color", [_, _, _, _]) => if let Some(color) = parse_color(&values) { settings.bg_color = color; },
+                ("fg_color", [_, _, _, _]) => if let Some(color) = parse_color(&values) { settings.fg_color = color; },
+                ("type_time", [millis]) => if let Ok(millis) = millis.parse() { settings.type_time = Duration::from_millis(millis); },
+                ("scanlines", [on]) => if let Ok(on) = on.parse() { settings.scanlines = on; },
+                ("high_visibility", [on]) => if let Ok(on) = on.parse() { settings.high_visibility = on; },
+                _ => {},
+            }
+        }
+        settings
+    }
+
+    /// Writes these settings as text, one "name value" pair per line.
+    pub fn to_text(&self) -> String {
+        format!(
+            "font_size {}\nbg_color {}\nfg_color {}\ntype_time {}\nscanlines {}\nhigh_visibility {}\n",
+            self.font_size, color_text(self.bg_color), color_text(self.fg_color), self.type_time.as_millis(), self.scanlines, self.high_visibility,
+        )
+    }
+
+    /// Returns the name of the theme matching these colors, or "Custom" if none does.
+    pub fn theme_name(&self) -> &'static str {
+        self.theme_index().map_or("Custom", |i| THEMES[i].0)
+    }
+
+    /// Switches to the theme the given number of steps away from the current one, wrapping around the list.
+    /// Custom colors count as sitting just before the first theme.
+    /// ```
+    /// # use simpleterm_profile::{settings::*, text::*};
+    /// let mut settings: Settings = Settings::default();
+    /// settings.cycle_theme(1);
+    /// assert_eq!(settings.theme_name(), "Phosphor");
+    /// settings.cycle_theme(-2);
+    /// assert_eq!(settings.theme_name(), "Alert");
+    /// ```
+    pub fn cycle_theme(&mut self, steps: i32) {
+        let count: i32 = THEMES.len() as i32;
+        let current: i32 = self.theme_index().map_or(-1, |i| i as i32);
+        let next: usize = (current + steps).rem_euclid(count) as usize;
+        self.bg_color = THEMES[next].1;
+        self.fg_color = THEMES[next].2;
+    }
+
+    // Finds the theme whose colors match these settings.
+    fn theme_index(&self) -> Option<usize> {
+        THEMES.iter().position(|(_, bg, fg)| *bg == self.bg_color && *fg == self.fg_color)
+    }
+}
+
+// Writes a color as four space-separated numbers.
+fn color_text(color: Color) -> String {
+    color.iter().map(|channel| channel.to_string()).collect::<Vec<String>>().join(" ")
+}
+
+// Reads a color from four numbers.
+fn parse_color(values: &[&str]) -> Option<Color> {
+    let channels: Vec<f32> = values.iter().filter_map(|value| value.parse().ok()).collect();
+    match channels[..] {
+        [r, g, b, a] => Some([r, g, b, a]),
+        _ => None,
+    }
+}
@@ -0,0 +1,334 @@
+use glutin_window::{map_key, map_mouse};
+use graphics::types::FontSize;
+use piston::*;
+use std::{any::Any, collections::VecDeque, error::Error, thread, time::{Duration, Instant}};
+use winit::{dpi::{LogicalPosition, LogicalSize}, ElementState, EventsLoop, KeyboardInput, MouseScrollDelta, WindowBuilder, WindowEvent};
+
+use crate::{backend::{Scene, Screen}, raster::SoftwareRenderer, window::*, MIN_WINDOW_SIZE};
+
+// How long the screen waits between frames, for about 60 frames a second.
+const FRAME_TIME: Duration = Duration::from_micros(16_667);
+
+/// A [Screen](../backend/trait.Screen.html) that draws with the CPU and copies each frame into a plain window,
+/// for machines with no OpenGL driver at all. [Terminal::try_new](../terminal/struct.Terminal.html#method.try_new) falls back to it when
+/// a [PistonScreen](../window/struct.PistonScreen.html) can't be opened.
+///
+/// Frames are copied in with Xlib on Linux and the BSDs, where Wayland sessions are served through XWayland,
+/// and with GDI on Windows. Other platforms have no way to show a frame, so the screen can't be opened there.
+///
+/// ```no_run
+/// # use simpleterm_profile::{software::SoftwareScreen, text::*};
+/// # use simpleterm_profile::terminal::Terminal;
+/// let screen: SoftwareScreen = SoftwareScreen::new("simpleterm test", (800, 600)).unwrap();
+/// let mut term: Terminal = Terminal::with_screen(Box::new(screen), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+/// term.tell("No graphics card needed.");
+/// ```
+pub struct SoftwareScreen {
+    events_loop: EventsLoop,
+    window: winit::Window,
+    title: String,
+    renderer: SoftwareRenderer,
+    frame: Vec<u32>,
+    events: VecDeque<Event>,
+    next_frame: Instant,
+    monitor: Option<usize>,
+    closed: bool,
+    wants_attention: bool,
+}
+
+impl SoftwareScreen {
+    /// Opens a window with the given title and size, or returns an error if there's no way to show frames in it on this platform.
+    pub fn new(title: &str, size: (u32, u32)) -> Result<SoftwareScreen, Box<dyn Error>> {
+        SoftwareScreen::open(title, size, None)
+    }
+
+    /// Opens a window like [new](#method.new), shown centered on the monitor with the given index.
+    /// If there's no monitor with that index, the window opens wherever the system puts it.
+    pub fn on_monitor(title: &str, size: (u32, u32), monitor: usize) -> Result<SoftwareScreen, Box<dyn Error>> {
+        SoftwareScreen::open(title, size, Some(monitor))
+    }
+
+    // Opens a window with the given title and size, centered on the monitor with the given index if there is one.
+    pub(crate) fn open(title: &str, size: (u32, u32), monitor: Option<usize>) -> Result<SoftwareScreen, Box<dyn Error>> {
+        let events_loop: EventsLoop = new_events_loop()?;
+        let window: winit::Window = build_window(&events_loop, title, size.into(), monitor)?;
+        Ok(SoftwareScreen {
+            events_loop,
+            window,
+            title: String::from(title),
+            renderer: SoftwareRenderer::new(),
+            frame: Vec::new(),
+            events: VecDeque::new(),
+            next_frame: Instant::now(),
+            monitor,
+            closed: false,
+            wants_attention: false,
+        })
+    }
+
+    // Queues the input for every event the window has received since the last check.
+    fn read_events(&mut self) {
+        let mut inputs: Vec<Input> = Vec::new();
+        let hidpi: f64 = self.window.get_hidpi_factor();
+        self.events_loop.poll_events(|event| {
+            if let winit::Event::WindowEvent { event, .. } = event {
+                inputs.extend(window_input(event, hidpi));
+            }
+        });
+
+        for input in inputs {
+            match input {
+                Input::Close(_) => {
+                    self.closed = true;
+                    self.window.hide();
+                }
+                Input::Focus(true) if self.wants_attention => {
+                    self.wants_attention = false;
+                    set_attention(&self.window, false);
+                }
+                _ => {}
+            }
+            self.events.push_back(Event::Input(input, None));
+        }
+    }
+
+    // Returns a render event if it's time for the next frame.
+    fn frame_due(&mut self) -> Option<Event> {
+        let now: Instant = Instant::now();
+        if now < self.next_frame { return None; }
+        self.next_frame = (self.next_frame + FRAME_TIME).max(now);
+
+        let hidpi: f64 = self.window.get_hidpi_factor();
+        let window_size: LogicalSize = self.window.get_inner_size()?;
+        let draw_size = window_size.to_physical(hidpi);
+        let args: RenderArgs = RenderArgs {
+            ext_dt: 0.0,
+            window_size: [window_size.width, window_size.height],
+            draw_size: [draw_size.width as u32, draw_size.height as u32],
+        };
+        Some(Event::Loop(Loop::Render(args)))
+    }
+}
+
+impl Screen for SoftwareScreen {
+    fn next_event(&mut self) -> Option<Event> {
+        loop {
+            if let Some(event) = self.events.pop_front() { return Some(event); }
+            if self.closed { return None; }
+
+            self.read_events();
+            if !self.events.is_empty() { continue; }
+            if let Some(event) = self.frame_due() { return Some(event); }
+            thread::sleep(self.next_frame.saturating_duration_since(Instant::now()));
+        }
+    }
+
+    fn poll_event(&mut self) -> Option<Event> {
+        if self.events.is_empty() && !self.closed { self.read_events(); }
+        if let Some(event) = self.events.pop_front() { return Some(event); }
+        if self.closed { return Some(Event::Input(Input::Close(CloseArgs), None)); }
+        self.frame_due()
+    }
+
+    fn draw(&mut self, event: &Event, scene: &Scene) -> bool {
+        let args: RenderArgs = match event.render_args() {
+            Some(args) => args,
+            None => return false,
+        };
+
+        let raster = self.renderer.render(scene, args.draw_size);
+        let [width, height] = raster.size();
+        self.frame.clear();
+        self.frame.extend(raster.pixels().iter().map(|[r, g, b, _]| u32::from_be_bytes([0, *r, *g, *b])));
+        present(&self.window, &mut self.frame, (width, height));
+        true
+    }
+
+    fn size(&self) -> Size {
+        self.window.get_inner_size().map_or(Size { width: 0.0, height: 0.0 }, |size| Size { width: size.width, height: size.height })
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.window.set_inner_size(LogicalSize::new(size.width, size.height));
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+        self.window.hide();
+    }
+
+    fn reopen(&mut self, size: Size) {
+        self.window = build_window(&self.events_loop, &self.title, size, self.monitor).unwrap();
+        self.events.clear();
+        self.closed = false;
+    }
+
+    fn load_font(&mut self, font: &str) -> Result<(), Box<dyn Error>> {
+        self.renderer.load_font(font)
+    }
+
+    fn text_width(&mut self, font: &str, size: FontSize, text: &str) -> Option<f64> {
+        self.renderer.text_width(font, size, text)
+    }
+
+    fn position(&self) -> Option<(i32, i32)> {
+        self.window.get_position().map(|pos| (pos.x as i32, pos.y as i32))
+    }
+
+    fn set_position(&mut self, x: i32, y: i32) {
+        self.window.set_position(LogicalPosition::new(x as f64, y as f64));
+    }
+
+    fn monitor_count(&self) -> usize {
+        self.window.get_available_monitors().count()
+    }
+
+    fn monitor_bounds(&self, index: Option<usize>) -> Option<(f64, f64, f64, f64)> {
+        monitor_bounds(&self.window, index)
+    }
+
+    fn set_fullscreen(&mut self, monitor: Option<usize>) {
+        set_fullscreen(&self.window, monitor);
+    }
+
+    fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.window.set_always_on_top(always_on_top);
+    }
+
+    fn set_minimized(&mut self, minimized: bool) {
+        set_minimized(&self.window, minimized);
+    }
+
+    fn request_attention(&mut self) {
+        self.wants_attention = true;
+        set_attention(&self.window, true);
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+// Builds a window with the given title and size that can't be resized below the minimum window size.
+// A window for a monitor is built hidden and only shown once it's been moved there, so it never shows up anywhere else first.
+fn build_window(events_loop: &EventsLoop, title: &str, size: Size, monitor: Option<usize>) -> Result<winit::Window, Box<dyn Error>> {
+    let window: winit::Window = WindowBuilder::new()
+        .with_title(title)
+        .with_dimensions(LogicalSize::new(size.width, size.height))
+        .with_min_dimensions(MIN_WINDOW_SIZE.into())
+        .with_visibility(monitor.is_none())
+        .build(events_loop)?;
+    if let Some(index) = monitor {
+        center_on_monitor(&window, index);
+        window.show();
+    }
+    Ok(window)
+}
+
+// Returns the piston input a window event stands for, if it stands for anything the terminal handles.
+// Control characters are left out of text input, the same way piston_window's windows leave them out.
+fn window_input(event: WindowEvent, hidpi: f64) -> Option<Input> {
+    match event {
+        WindowEvent::Resized(size) => {
+            let draw_size = size.to_physical(hidpi);
+            Some(Input::Resize(ResizeArgs { window_size: [size.width, size.height], draw_size: [draw_size.width as u32, draw_size.height as u32] }))
+        }
+        WindowEvent::ReceivedCharacter(c) if !c.is_control() => Some(Input::Text(c.to_string())),
+        WindowEvent::Focused(focused) => Some(Input::Focus(focused)),
+        WindowEvent::KeyboardInput { input: KeyboardInput { state, virtual_keycode: Some(key), scancode, .. }, .. } => {
+            Some(Input::Button(ButtonArgs { state: button_state(state), button: Button::Keyboard(map_key(key)), scancode: Some(scancode as i32) }))
+        }
+        WindowEvent::MouseInput { state, button, .. } => {
+            Some(Input::Button(ButtonArgs { state: button_state(state), button: Button::Mouse(map_mouse(button)), scancode: None }))
+        }
+        WindowEvent::CursorMoved { position, .. } => Some(Input::Move(Motion::MouseCursor([position.x, position.y]))),
+        WindowEvent::CursorEntered { .. } => Some(Input::Cursor(true)),
+        WindowEvent::CursorLeft { .. } => Some(Input::Cursor(false)),
+        WindowEvent::MouseWheel { delta: MouseScrollDelta::LineDelta(x, y), .. } => Some(Input::Move(Motion::MouseScroll([x as f64, y as f64]))),
+        WindowEvent::MouseWheel { delta: MouseScrollDelta::PixelDelta(pos), .. } => Some(Input::Move(Motion::MouseScroll([pos.x, pos.y]))),
+        WindowEvent::DroppedFile(path) => Some(Input::FileDrag(FileDrag::Drop(path))),
+        WindowEvent::HoveredFile(path) => Some(Input::FileDrag(FileDrag::Hover(path))),
+        WindowEvent::HoveredFileCancelled => Some(Input::FileDrag(FileDrag::Cancel)),
+        WindowEvent::CloseRequested => Some(Input::Close(CloseArgs)),
+        _ => None,
+    }
+}
+
+fn button_state(state: ElementState) -> ButtonState {
+    match state {
+        ElementState::Pressed => ButtonState::Press,
+        ElementState::Released => ButtonState::Release,
+    }
+}
+
+// Opens the event loop for the screen's windows. Frames are copied in with Xlib, so this always connects to the X server,
+// which Wayland sessions provide through XWayland.
+#[cfg(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+fn new_events_loop() -> Result<EventsLoop, Box<dyn Error>> {
+    use winit::os::unix::EventsLoopExt;
+
+    EventsLoop::new_x11().map_err(|e| format!("Couldn't connect to the X server: {:?}", e).into())
+}
+
+#[cfg(target_os = "windows")]
+fn new_events_loop() -> Result<EventsLoop, Box<dyn Error>> {
+    Ok(EventsLoop::new())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd", target_os = "windows")))]
+fn new_events_loop() -> Result<EventsLoop, Box<dyn Error>> {
+    Err("Software rendering can't show frames on this platform".into())
+}
+
+// Copies a frame of 0RGB pixels into the window with Xlib.
+#[cfg(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+fn present(window: &winit::Window, frame: &mut [u32], (width, height): (u32, u32)) {
+    use std::ptr;
+    use winit::os::unix::WindowExt;
+    use x11_dl::xlib::ZPixmap;
+
+    if let (Some(xconn), Some(xwindow), Some(screen)) = (window.get_xlib_xconnection(), window.get_xlib_window(), window.get_xlib_screen_id()) {
+        unsafe {
+            let visual = (xconn.xlib.XDefaultVisual)(xconn.display, screen);
+            let depth = (xconn.xlib.XDefaultDepth)(xconn.display, screen) as u32;
+            let image = (xconn.xlib.XCreateImage)(xconn.display, visual, depth, ZPixmap, 0, frame.as_mut_ptr() as *mut _, width, height, 32, 0);
+            if image.is_null() { return; }
+
+            (xconn.xlib.XPutImage)(xconn.display, xwindow, (xconn.xlib.XDefaultGC)(xconn.display, screen), image, 0, 0, 0, 0, width, height);
+            // The pixels belong to the screen, so they're taken back before destroying the image would free them.
+            (*image).data = ptr::null_mut();
+            (xconn.xlib.XDestroyImage)(image);
+            (xconn.xlib.XFlush)(xconn.display);
+        }
+    }
+}
+
+// Copies a frame of 0RGB pixels into the window with GDI.
+#[cfg(target_os = "windows")]
+fn present(window: &winit::Window, frame: &mut [u32], (width, height): (u32, u32)) {
+    use std::mem;
+    use winapi::um::{wingdi::{StretchDIBits, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS, SRCCOPY}, winuser::{GetDC, ReleaseDC}};
+    use winit::os::windows::WindowExt;
+
+    let mut info: BITMAPINFO = unsafe { mem::zeroed() };
+    info.bmiHeader = BITMAPINFOHEADER {
+        biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+        biWidth: width as i32,
+        // A negative height stores the rows from the top down, like the raster does.
+        biHeight: -(height as i32),
+        biPlanes: 1,
+        biBitCount: 32,
+        biCompression: BI_RGB,
+        ..unsafe { mem::zeroed() }
+    };
+
+    let hwnd = window.get_hwnd() as _;
+    unsafe {
+        let hdc = GetDC(hwnd);
+        StretchDIBits(hdc, 0, 0, width as i32, height as i32, 0, 0, width as i32, height as i32, frame.as_ptr() as *const _, &info, DIB_RGB_COLORS, SRCCOPY);
+        ReleaseDC(hwnd, hdc);
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd", target_os = "windows")))]
+fn present(_window: &winit::Window, _frame: &mut [u32], _size: (u32, u32)) {}
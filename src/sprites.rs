@@ -0,0 +1,127 @@
+#[cfg(feature = "sprites")]
+use image::DynamicImage;
+#[cfg(feature = "sprites")]
+use std::path::Path;
+use std::time::Duration;
+
+use crate::art::{from_luminance, Dither};
+
+/// A grid of equally sized animation cells cut from one image, numbered left to right and then top to bottom.
+/// Cells are turned into art by sampling their brightness, so light pixels become dense characters on the terminal's dark background.
+/// ```
+/// # use simpleterm_profile::{art::*, sprites::*};
+/// // Two 2x2 cells side by side: one dark, one light.
+/// let pixels: Vec<f32> = vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0];
+/// let sheet: SpriteSheet = SpriteSheet::from_luminance(pixels, 4, (2, 2));
+/// assert_eq!(sheet.cell_count(), 2);
+/// assert_eq!(sheet.cell_art(0, 2, " #", Dither::None), Some(String::from("  ")));
+/// assert_eq!(sheet.cell_art(1, 2, " #", Dither::None), Some(String::from("##")));
+/// assert_eq!(sheet.cell_art(2, 2, " #", Dither::None), None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpriteSheet {
+    pixels: Vec<f32>,
+    width: usize,
+    height: usize,
+    cell_size: (usize, usize),
+}
+
+impl SpriteSheet {
+    /// Creates a sheet from grayscale pixels given row by row, with 0.0 as black and 1.0 as white, cut into cells of the given (width, height).
+    pub fn from_luminance(pixels: Vec<f32>, width: usize, cell_size: (usize, usize)) -> SpriteSheet {
+        let height: usize = pixels.len().checked_div(width).unwrap_or(0);
+        SpriteSheet { pixels, width, height, cell_size: (cell_size.0.max(1), cell_size.1.max(1)) }
+    }
+
+    /// Creates a sheet from an image, cut into cells of the given (width, height). Transparent pixels count as black.
+    #[cfg(feature = "sprites")]
+    pub fn from_image(image: &DynamicImage, cell_size: (usize, usize)) -> SpriteSheet {
+        let gray = image.to_luma_alpha8();
+        let pixels: Vec<f32> = gray.pixels().map(|pixel| pixel[0] as f32 / 255.0 * pixel[1] as f32 / 255.0).collect();
+        SpriteSheet::from_luminance(pixels, gray.width() as usize, cell_size)
+    }
+
+    /// Loads a sheet from an image file, like a PNG, cut into cells of the given (width, height).
+    #[cfg(feature = "sprites")]
+    pub fn open<P: AsRef<Path>>(path: P, cell_size: (usize, usize)) -> image::ImageResult<SpriteSheet> {
+        Ok(SpriteSheet::from_image(&image::open(path)?, cell_size))
+    }
+
+    /// The number of whole cells in the sheet.
+    pub fn cell_count(&self) -> usize {
+        (self.width / self.cell_size.0) * (self.height / self.cell_size.1)
+    }
+
+    /// Converts the given cell into art the given number of characters wide, using a character ramp from darkest to lightest.
+    /// Characters are about twice as tall as they are wide, so each character covers twice as many pixel rows as columns.
+    /// Returns None if there's no such cell.
+    pub fn cell_art(&self, cell: usize, columns: usize, ramp: &str, dither: Dither) -> Option<String> {
+        if cell >= self.cell_count() || columns == 0 { return None; }
+
+        let (cell_width, cell_height): (usize, usize) = self.cell_size;
+        let cells_across: usize = self.width / cell_width;
+        let left: usize = (cell % cells_across) * cell_width;
+        let top: usize = (cell / cells_across) * cell_height;
+
+        let columns: usize = columns.min(cell_width);
+        let scale: f32 = cell_width as f32 / columns as f32;
+        let rows: usize = ((cell_height as f32 / (scale * 2.0)).round() as usize).max(1);
+
+        let mut samples: Vec<f32> = Vec::new();
+        for row in 0..rows {
+            let y0: usize = top + row * cell_height / rows;
+            let y1: usize = (top + (row + 1) * cell_height / rows).max(y0 + 1);
+            for col in 0..columns {
+                let x0: usize = left + col * cell_width / columns;
+                let x1: usize = (left + (col + 1) * cell_width / columns).max(x0 + 1);
+
+                let mut total: f32 = 0.0;
+                for y in y0..y1 {
+                    total += self.pixels[y * self.width + x0..y * self.width + x1].iter().sum::<f32>();
+                }
+                samples.push(total / ((y1 - y0) * (x1 - x0)) as f32);
+            }
+        }
+
+        Some(from_luminance(&samples, columns, ramp, dither))
+    }
+}
+
+/// Which cells of a sprite sheet make up an animation, and how it plays.
+/// ```
+/// # use std::time::Duration;
+/// # use simpleterm_profile::sprites::SpriteAnimation;
+/// let walk: SpriteAnimation = SpriteAnimation { cells: vec![4, 5, 6], fps: 10.0, looping: true };
+/// assert_eq!(walk.cell_at(Duration::from_millis(150)), Some(5));
+/// assert_eq!(walk.cell_at(Duration::from_millis(350)), Some(4));
+///
+/// let jump: SpriteAnimation = SpriteAnimation { looping: false, ..walk };
+/// assert_eq!(jump.cell_at(Duration::from_millis(350)), Some(6));
+/// assert_eq!(jump.duration(), Duration::from_millis(300));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpriteAnimation {
+    /// The cells to show, in order.
+    pub cells: Vec<usize>,
+    /// How many cells are shown per second.
+    pub fps: f32,
+    /// Whether the animation starts over after its last cell, or stays on it.
+    pub looping: bool,
+}
+
+impl SpriteAnimation {
+    /// Returns the cell that should be on screen after the given amount of time has passed, or None if there are no cells.
+    pub fn cell_at(&self, elapsed: Duration) -> Option<usize> {
+        if self.cells.is_empty() { return None; }
+
+        let frame: usize = (elapsed.as_secs_f32() * self.fps.max(0.0)) as usize;
+        let index: usize = if self.looping { frame % self.cells.len() } else { frame.min(self.cells.len() - 1) };
+        Some(self.cells[index])
+    }
+
+    /// How long it takes to play every cell once.
+    pub fn duration(&self) -> Duration {
+        if self.fps <= 0.0 { return Duration::ZERO; }
+        Duration::from_nanos((self.cells.len() as f64 * 1e9 / self.fps as f64).round() as u64)
+    }
+}
@@ -0,0 +1,66 @@
+use std::{collections::VecDeque, time::Duration};
+
+/// How many of the most recent frames are kept for averages and percentiles.
+pub const STATS_WINDOW: usize = 600;
+
+/// Timing statistics for the frames a terminal has drawn. Frame times measure how long building and flushing each frame took,
+/// not time spent waiting for the next event, so they show the cost of effects and fonts directly.
+/// ```
+/// # use std::time::Duration;
+/// # use simpleterm_profile::stats::FrameStats;
+/// let mut stats: FrameStats = FrameStats::default();
+/// for millis in 1..=10 {
+///     stats.record(Duration::from_millis(millis), 100);
+/// }
+/// assert_eq!(stats.frames(), 10);
+/// assert_eq!(stats.average(), Duration::from_micros(5500));
+/// assert_eq!(stats.percentile(0.9), Duration::from_millis(9));
+/// assert_eq!(stats.percentile(1.0), Duration::from_millis(10));
+/// assert_eq!(stats.glyphs_per_frame(), 100.0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    frames: u64,
+    recent: VecDeque<(Duration, usize)>,
+}
+
+impl FrameStats {
+    /// Records a frame that took the given time to draw and drew the given number of glyphs.
+    pub fn record(&mut self, time: Duration, glyphs: usize) {
+        self.frames += 1;
+        if self.recent.len() == STATS_WINDOW { self.recent.pop_front(); }
+        self.recent.push_back((time, glyphs));
+    }
+
+    /// Forgets every recorded frame, e.g. before measuring a new scene.
+    pub fn reset(&mut self) {
+        *self = FrameStats::default();
+    }
+
+    /// The total number of frames drawn since the stats were created or reset.
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// The average time recent frames took to draw.
+    pub fn average(&self) -> Duration {
+        if self.recent.is_empty() { return Duration::ZERO; }
+        self.recent.iter().map(|(time, _)| *time).sum::<Duration>() / self.recent.len() as u32
+    }
+
+    /// The time that the given fraction of recent frames drew within, e.g. 0.99 for the 99th percentile.
+    pub fn percentile(&self, fraction: f64) -> Duration {
+        let mut times: Vec<Duration> = self.recent.iter().map(|(time, _)| *time).collect();
+        if times.is_empty() { return Duration::ZERO; }
+        times.sort();
+
+        let rank: usize = (fraction.clamp(0.0, 1.0) * times.len() as f64).ceil() as usize;
+        times[rank.clamp(1, times.len()) - 1]
+    }
+
+    /// The average number of glyphs recent frames drew.
+    pub fn glyphs_per_frame(&self) -> f64 {
+        if self.recent.is_empty() { return 0.0; }
+        self.recent.iter().map(|(_, glyphs)| *glyphs).sum::<usize>() as f64 / self.recent.len() as f64
+    }
+}
@@ -1,479 +1,4229 @@
-use piston_window::{*, types::{Color, FontSize}};
-use std::{thread, time::{Duration, Instant}};
+use graphics::types::{Color, FontSize};
+use piston::*;
+use regex::Regex;
+use std::{collections::{HashMap, VecDeque}, env, error::Error, fs::{self, File}, io::{self, BufRead, Read, Seek, SeekFrom}, mem, path::{Path, PathBuf}, str::FromStr, sync::{atomic::{AtomicBool, AtomicU64, Ordering}, mpsc::{self, Receiver, TryRecvError}, Arc}, thread, time::Duration};
+use web_time::{Instant, SystemTime, UNIX_EPOCH};
 
-use crate::{draw::*, text::*, TYPE_TIME};
+#[cfg(feature = "audio")]
+use crate::audio::TypeSounds;
+#[cfg(feature = "json")]
+use crate::json::*;
+#[cfg(not(target_arch = "wasm32"))]
+use piston_window::PistonWindow;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::window::PistonScreen;
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "tty")))]
+use crate::software::SoftwareScreen;
+#[cfg(feature = "tty")]
+use crate::tty::TtyScreen;
+use crate::{ansi::{from_cp437_text, AnsiArt}, answer::Answer, art::{self, Animation, ArtScene, Dither}, backend::{Scene, SceneContent, Screen}, captions::CaptionTrack, command::Command, draw::*, effects::Reveal, input::InputSource, keyboard::VirtualKeyboard, keymap::*, morse::MorseTiming, rng::Rng, settings::*, sprites::*, stats::FrameStats, text::*, widgets::*, wizard::StepInput, BELL_TIME, FADE_TIME, TEXT_OFFSET, TYPE_TIME};
 
-/// A terminal stores a PistonWindow, background and foreground colors,
-/// a font, fontsize, and glyph cache, and the current message and input strings.
+/// The indentation used for the body of a message spoken with [say](struct.Terminal.html#method.say).
+const SPEAKER_INDENT: &str = "    ";
+const ROLL_FRAMES: u32 = 8;
+const ROLL_FRAME_TIME: Duration = Duration::from_millis(80);
+const POLL_TIME: Duration = Duration::from_millis(16);
+const DIFF_CONTEXT: usize = 3;
+const SPINNER_FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+const SPINNER_TIME: Duration = Duration::from_millis(100);
+/// The most answers kept in the input history.
+const HISTORY_LIMIT: usize = 1000;
+/// The most lines kept in the scrollback buffer.
+const SCROLLBACK_LIMIT: usize = 1000;
+/// How many lines one notch of the mouse wheel scrolls.
+const WHEEL_LINES: usize = 3;
+const SEEK_TIME: Duration = Duration::from_secs(5);
+const MORSE_UNIT: Duration = Duration::from_millis(80);
+const UNFOCUSED_FADE: f32 = 0.5;
+const SCROLL_TIME: f64 = 0.05;
+/// How long a playing demo takes to press each key.
+const DEMO_KEY_TIME: Duration = Duration::from_millis(120);
+/// How long a playing demo waits before and after typing an answer.
+const DEMO_PAUSE: Duration = Duration::from_millis(600);
+
+/// How an operation run with [fetch_with_retry](struct.Terminal.html#method.fetch_with_retry) ended.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome<T, E> {
+    /// The operation succeeded with this value.
+    Done(T),
+    /// The operation failed with this error and the user chose to skip it.
+    Skipped(E),
+    /// The operation failed with this error and the user chose to abort, or the window was closed.
+    Aborted(E),
+}
+
+// The look and pacing of a terminal, saved by push_style so pop_style can bring it back.
+struct SavedStyle {
+    bg_color: Color,
+    fg_color: Color,
+    message_color: Color,
+    font: String,
+    font_size: FontSize,
+    line_spacing: f64,
+    art_font: String,
+    art_font_size: FontSize,
+    scanlines: bool,
+    chat_boxes: bool,
+    border_animation: BorderAnimation,
+    type_time: Duration,
+    fast_forward_speed: u32,
+    reading_speed: u32,
+}
+
+/// What a frame drawn with [render_frame](struct.Terminal.html#method.render_frame) shows inside the terminal box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FrameContent<'a> {
+    /// The current message, with highlight rules applied.
+    Message,
+    /// The first given number of characters of the current message, followed by a typing cursor.
+    Typing(usize),
+    /// The current message with the given selection shown.
+    Selecting(Selection),
+    /// The current message drawn as centered art in the art font size.
+    Art,
+    /// The given lines drawn as centered art in the art font size.
+    ArtLines(&'a [String]),
+    /// The given lines drawn as centered art in the art font size, with a style for each character, like a rendered [ArtScene](../art/struct.ArtScene.html).
+    StyledArt(&'a [String], &'a [Vec<Style>]),
+    /// The given lines centered across the terminal, with the first line's baseline the given height from the top of the window, like rolling credits.
+    Credits(&'a [String], f64),
+}
+
+/// Everything about a single frame that isn't part of the terminal's settings, for [render_frame](struct.Terminal.html#method.render_frame).
+/// The default shows the current message with no input line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameState<'a> {
+    /// What's drawn inside the terminal box.
+    pub content: FrameContent<'a>,
+    /// Whether the chat conversation is drawn.
+    pub chat: bool,
+    /// The text drawn on the input line, if any.
+    pub input: Option<&'a str>,
+    /// Whether the "> " marker is drawn at the start of the input line.
+    pub input_marker: bool,
+    /// The color of the input marker, or None to use the foreground color.
+    pub marker_color: Option<Color>,
+    /// Whether blinking text is visible this frame.
+    pub flash: bool,
+    /// The (key, description) pairs shown in the help box, if it's open.
+    pub help: Option<&'a [(String, String)]>,
+    /// How far the terminal has faded to the background color, from 0.0 to 1.0.
+    pub fade: f32,
+    /// How many lines below its usual place the message is drawn, for scrolling smoothly instead of a line at a time.
+    pub scroll: f64,
+    /// The on-screen keyboard drawn above the input line, if any.
+    pub keyboard: Option<&'a VirtualKeyboard>,
+    /// A completion drawn dimmed on the input line right after the first text, which is drawn there already, if any.
+    pub ghost: Option<(&'a str, &'a str)>,
+    /// Whether the border is lit up, like a signal lamp sending Morse code.
+    pub signal: bool,
+    /// How the frame is split in two, and how far from 0.0 to 1.0 the halves have slid off the window, for a split transition.
+    pub split: Option<(Split, f64)>,
+}
+
+impl<'a> Default for FrameState<'a> {
+    fn default() -> FrameState<'a> {
+        FrameState { content: FrameContent::Message, chat: false, input: None, input_marker: false, marker_color: None, flash: true, help: None, fade: 0.0, scroll: 0.0, keyboard: None, ghost: None, signal: false, split: None }
+    }
+}
+
+/// Closes a terminal from a callback or another thread, ending whatever it's waiting on. Get one with
+/// [close_handle](struct.Terminal.html#method.close_handle). The window closes right away, without an outro.
+#[derive(Clone, Debug, Default)]
+pub struct CloseHandle {
+    requested: Arc<AtomicBool>,
+}
+
+impl CloseHandle {
+    /// Asks the terminal to close the next time it checks for events.
+    pub fn close(&self) {
+        self.requested.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Updates a progress bar started with [progress](struct.Terminal.html#method.progress), from any thread.
+/// The bar is drawn whenever the terminal draws, until it's finished.
+/// ```
+/// # use simpleterm_profile::terminal::ProgressHandle;
+/// let handle: ProgressHandle = ProgressHandle::default();
+/// handle.set(0.25);
+/// assert_eq!(handle.fraction(), 0.25);
+/// handle.set(3.0);
+/// assert_eq!(handle.fraction(), 1.0);
+/// handle.finish();
+/// assert!(handle.is_finished());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ProgressHandle {
+    state: Arc<ProgressState>,
+}
+
+// The shared state behind a progress handle, with the fraction stored as the bits of an f64.
+#[derive(Debug, Default)]
+struct ProgressState {
+    fraction: AtomicU64,
+    finished: AtomicBool,
+}
+
+impl ProgressHandle {
+    /// Fills the bar to the given fraction, from 0.0 for empty to 1.0 for full.
+    pub fn set(&self, fraction: f64) {
+        let fraction: f64 = if fraction.is_nan() { 0.0 } else { fraction.clamp(0.0, 1.0) };
+        self.state.fraction.store(fraction.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Returns how full the bar is, from 0.0 to 1.0.
+    pub fn fraction(&self) -> f64 {
+        f64::from_bits(self.state.fraction.load(Ordering::Relaxed))
+    }
+
+    /// Removes the bar the next time the terminal draws.
+    pub fn finish(&self) {
+        self.state.finished.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true once the bar has been finished.
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.load(Ordering::Relaxed)
+    }
+}
+
+/// Stops a spinner started with [spinner](struct.Terminal.html#method.spinner), from any thread.
+/// ```
+/// # use simpleterm_profile::terminal::SpinnerHandle;
+/// let handle: SpinnerHandle = SpinnerHandle::default();
+/// assert!(!handle.is_stopped());
+/// handle.stop();
+/// assert!(handle.is_stopped());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SpinnerHandle {
+    stopped: Arc<AtomicBool>,
+}
+
+impl SpinnerHandle {
+    /// Removes the spinner the next time the terminal draws.
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true once the spinner has been stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+}
+
+// What a composed frame shows inside the terminal box.
+enum FrameBody {
+    Current,
+    Message(Vec<String>, Vec<Vec<Style>>),
+    Art(Vec<String>),
+}
+
+/// Several changes to what the terminal shows, composed with [frame](struct.Terminal.html#method.frame) and presented together
+/// by [draw](#method.draw), so no frame ever shows only some of them.
+pub struct Frame<'t> {
+    term: &'t mut Terminal,
+    body: FrameBody,
+    status: Option<String>,
+}
+
+impl<'t> Frame<'t> {
+    /// Shows the given message, wrapped to fit the window, replacing any art given before.
+    pub fn message(mut self, message: &str) -> Frame<'t> {
+        let max_chars: usize = self.term.get_max_message_characters();
+        let (lines, styles) = wrap_spans(&[Span::plain(message)], max_chars, self.term.split_strategy);
+        self.body = FrameBody::Message(lines, styles);
+        self
+    }
+
+    /// Shows the given ascii art centered in the art font, replacing any message given before.
+    pub fn art(mut self, art: &str) -> Frame<'t> {
+        self.body = FrameBody::Art(art.split('\n').map(String::from).collect());
+        self
+    }
+
+    /// Shows the given text on the line at the bottom of the terminal, where input usually goes.
+    pub fn status(mut self, status: &str) -> Frame<'t> {
+        self.status = Some(String::from(status));
+        self
+    }
+
+    /// Draws everything composed so far in a single frame. A message stays on the terminal afterward, like one shown with
+    /// [show](struct.Terminal.html#method.show). Returns false if the window was closed before the frame could be drawn.
+    pub fn draw(self) -> bool {
+        let Frame { term, body, status } = self;
+        if !term.active { return false; }
+
+        let art: Vec<String> = match body {
+            FrameBody::Current => Vec::new(),
+            FrameBody::Message(lines, styles) => {
+                term.use_text_font();
+                term.message = lines;
+                term.message_styles = styles;
+                Vec::new()
+            },
+            FrameBody::Art(lines) => {
+                term.use_art_font();
+                lines
+            },
+        };
+        let content: FrameContent = if art.is_empty() { FrameContent::Message } else { FrameContent::ArtLines(&art) };
+
+        let mut active: bool = term.active;
+        let mut drawn: bool = false;
+        while let Some(e) = term.next_event() {
+            e.close(|_| { active = false; });
+            if !active { break; }
+            if term.render_frame(&e, &FrameState { content, input: status.as_deref(), ..FrameState::default() }) {
+                drawn = true;
+                break;
+            }
+        }
+        term.active = active;
+
+        drawn
+    }
+}
+
+// The queued command update is partway through.
+struct RunningCommand {
+    command: Command,
+    started: Instant,
+    typed_at: Option<Instant>,
+    flash_start: Instant,
+    input: String,
+    announced: usize,
+}
+
+// A key being held down, so its action can repeat.
+struct HeldKey {
+    button: ButtonArgs,
+    pressed: Instant,
+    repeats: u32,
+}
+
+/// A terminal stores the screen it draws on, background and foreground colors,
+/// a font and font size, and the current message and input strings.
+/// 
+/// Terminals used to own their piston_window window in a public `window` field. Now that they can draw on any
+/// [Screen](../backend/trait.Screen.html), that field is gone, and [window](#method.window) returns the window instead
+/// whenever the screen is a [PistonScreen](../window/struct.PistonScreen.html).
 pub struct Terminal {
-    title: String,
     /// If false, execution will end.
     pub active: bool,
-    /// The window that displays our terminal.
-    pub window: PistonWindow,
+    /// The screen that displays our terminal and reads its events.
+    pub screen: Box<dyn Screen>,
     /// The background color of our terminal.
     pub bg_color: Color,
     /// The foreground color of our terminal.
     pub fg_color: Color,
+    message_color: Color,
     /// Whether or not to use scanlines
     pub scanlines: bool,
-    glyphs: Glyphs,
+    /// Whether or not to draw a box around each chat message
+    pub chat_boxes: bool,
+    /// The keys used to continue, submit input, and so on.
+    pub keymap: KeyMap,
+    /// How words too long to fit on a line are split.
+    pub split_strategy: SplitStrategy,
+    /// How many times faster typing and timers run while the fast forward key is held.
+    pub fast_forward_speed: u32,
+    fast_forwarding: bool,
+    /// Whether messages continue on their own once there has been time to read them.
+    pub auto_advance: bool,
+    /// The reading speed used by auto advance, in words per minute.
+    pub reading_speed: u32,
+    /// The most lines per second [stream](#method.stream) adds, or 0 for no limit. Lines that arrive faster wait their turn.
+    pub stream_rate: f64,
+    /// How long the terminal takes to type a single character when displaying a message.
+    pub type_time: Duration,
+    /// How long a dot lasts when sending Morse code with [morse](#method.morse). Every other part of the code is a multiple of it.
+    pub morse_unit: Duration,
+    /// When set, the input marker briefly lights up in this color whenever a key press is ignored,
+    /// like typing while a message is shown or pressing Backspace with no input.
+    pub bell_color: Option<Color>,
     font: String,
     art_font: String,
-    /// The font size of normal text in our terminal.
+    /// The font size of normal text in our terminal. High visibility mode draws text larger without changing it.
     pub font_size: FontSize,
+    /// How far apart lines of text are drawn, as a multiple of the font size. Art is always drawn at
+    /// [DEFAULT_LINE_SPACING](../text/constant.DEFAULT_LINE_SPACING.html), and high visibility mode never draws lines closer than
+    /// [HIGH_VISIBILITY_LINE_SPACING](../settings/constant.HIGH_VISIBILITY_LINE_SPACING.html).
+    pub line_spacing: f64,
     /// The font size of art in our terminal.
     pub art_font_size: FontSize,
-    art_mode: bool,
+    /// Whether art is read as code page 437, so DOS art pasted or loaded as Latin-1 shows its box drawing, shading, and symbols.
+    pub cp437_art: bool,
+    art_fonts: HashMap<String, (String, FontSize)>,
+    loaded_font: String,
     message: Vec<String>,
+    message_styles: Vec<Vec<Style>>,
+    speakers: HashMap<String, Color>,
+    chat_log: Vec<Bubble>,
     input: String,
+    window_file: Option<PathBuf>,
+    settings_file: Option<PathBuf>,
+    history: Vec<String>,
+    history_file: Option<PathBuf>,
+    completions: Vec<String>,
+    overprint: Option<(usize, Vec<Span>)>,
+    bell_rung: Option<Instant>,
+    /// How many columns apart tab stops are when a message contains tabs.
+    pub tab_width: usize,
+    always_on_top: bool,
+    attract: Option<(Animation, Duration)>,
+    rng: Rng,
+    /// How the border around the terminal text is animated.
+    pub border_animation: BorderAnimation,
+    started: Instant,
+    selected_text: String,
+    /// An optional column of line numbers shown beside messages.
+    pub gutter: Option<Gutter>,
+    first_line: usize,
+    /// When set, each message is prefixed with the current time in this strftime-like format (see `text::format_time`),
+    /// drawn dimmer than the message.
+    pub timestamp_format: Option<String>,
+    highlights: Vec<(Regex, Color)>,
+    allow_back: bool,
+    went_back: bool,
+    /// How long [ask](#method.ask) waits for an answer before giving up with Answer::TimedOut, or None to wait as long as it takes.
+    pub answer_timeout: Option<Duration>,
+    answer_deadline: Option<Instant>,
+    timed_out: bool,
+    /// The color of added lines in `show_diff`.
+    pub diff_added_color: Color,
+    /// The color of removed lines in `show_diff`.
+    pub diff_removed_color: Color,
+    widgets: Vec<Box<dyn Widget>>,
+    frame_stats: FrameStats,
+    style_stack: Vec<SavedStyle>,
+    /// Which corner the widget panel is drawn in.
+    pub widget_corner: Corner,
+    focused: bool,
+    focus_callback: Option<Box<dyn FnMut(bool)>>,
+    function_key_callback: Option<Box<dyn FnMut(u8)>>,
+    type_callback: Option<Box<dyn FnMut(char, usize)>>,
+    /// Whether the terminal is dimmed, with blinking paused, while the window doesn't have focus.
+    pub dim_unfocused: bool,
+    /// The sounds played while typing, once turned on with [enable_sounds](#method.enable_sounds).
+    #[cfg(feature = "audio")]
+    pub sounds: Option<TypeSounds>,
+    /// How loud typing sounds play, from 0.0 for silent to 1.0 for full volume.
+    #[cfg(feature = "audio")]
+    pub volume: f32,
+    /// Whether timed shows, typing, and timers stop counting down while the window doesn't have focus.
+    pub pause_unfocused: bool,
+    /// How held keys repeat actions like Backspace and moving through lists, or None to only act once per press.
+    pub key_repeat: Option<KeyRepeat>,
+    held_key: Option<HeldKey>,
+    high_visibility: bool,
+    /// Whether an on-screen keyboard is shown while asking for input, for typing with the mouse, arrow keys, or a game controller.
+    pub virtual_keyboard: bool,
+    keyboard: VirtualKeyboard,
+    close_handle: CloseHandle,
+    progress_bar: Option<(String, ProgressHandle)>,
+    spinner: Option<(String, SpinnerHandle)>,
+    pins: Vec<(String, Anchor, String)>,
+    commands: VecDeque<Command>,
+    placement: Placement,
+    /// Whether each message is added below the ones before it instead of replacing them, with the lines that scroll off the top kept
+    /// so the user can scroll back through them with Page Up and Page Down or the mouse wheel, like a real terminal.
+    pub scrollback: bool,
+    scrollback_lines: Vec<String>,
+    scrollback_styles: Vec<Vec<Style>>,
+    scrolled_back: usize,
+    last_text: Vec<String>,
+    reflow_source: Option<(Vec<Span>, Vec<String>)>,
+    typed_before: usize,
+    /// The character drawn in place of each typed character when asking with [ask_hidden](#method.ask_hidden).
+    pub mask_char: char,
+    hidden: bool,
+    demo: Option<VecDeque<String>>,
+    input_source: Option<Box<dyn InputSource>>,
+    running: Option<RunningCommand>,
 }
 
 impl Terminal {
-    /// Creates a new window with the given title, colors, and font info
+    /// Creates a new window with the given title, colors, and font info.
+    /// On a machine with no usable OpenGL driver, the window is drawn with the CPU instead (see [try_new](#method.try_new)).
+    /// With the tty feature enabled, the terminal is drawn inside the real terminal the program was started from instead of a window.
     /// 
     /// ```no_run
-    /// # use simpleterm::text::*;
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
     /// let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
     /// ```
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(title: &str, size: (u32, u32), bg: Color, fg: Color, font: &str, font_size: u32) -> Terminal {
-        let mut new_window: PistonWindow = WindowSettings::new(title, size).exit_on_esc(true).build().unwrap();
-        let loaded_glyphs = load_font(&mut new_window, font);
+        Terminal::try_new(title, size, bg, fg, font, font_size).unwrap()
+    }
 
-        Terminal {
-            title: String::from(title),
+    /// Creates a new window like [new](#method.new), but returns an error instead of panicking if no window can be opened or the font can't be loaded.
+    /// An OpenGL window is tried first, falling back from OpenGL 3.2 to 2.1 for older drivers. If no OpenGL context can be created at all,
+    /// like on many VMs and CI machines, the terminal draws with the CPU on a [SoftwareScreen](../software/struct.SoftwareScreen.html) instead.
+    /// With the tty feature enabled, no window is opened and the terminal draws on a TtyScreen from the tty module instead, ignoring the size.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// match Terminal::try_new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32) {
+    ///     Ok(mut term) => term.tell("We have a window!"),
+    ///     Err(e) => println!("Couldn't open a window: {}", e),
+    /// }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn try_new(title: &str, size: (u32, u32), bg: Color, fg: Color, font: &str, font_size: u32) -> Result<Terminal, Box<dyn Error>> {
+        Terminal::with_screen(open_screen(title, size, None)?, bg, fg, font, font_size)
+    }
+
+    /// Creates a new window like [new](#method.new), opened centered on the monitor with the given index,
+    /// for installations where the terminal has to run on a secondary display. If there's no monitor with that index,
+    /// the window opens wherever the system puts it. Use [move_to_monitor](#method.move_to_monitor) to move it later.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// let mut term: Terminal = Terminal::new_on_monitor("simpleterm test", (800, 600), 1, DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell("This opened on the second monitor!");
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new_on_monitor(title: &str, size: (u32, u32), monitor: usize, bg: Color, fg: Color, font: &str, font_size: u32) -> Terminal {
+        Terminal::try_new_on_monitor(title, size, monitor, bg, fg, font, font_size).unwrap()
+    }
+
+    /// Creates a new window like [new_on_monitor](#method.new_on_monitor), but returns an error instead of panicking
+    /// if no window can be opened or the font can't be loaded, like [try_new](#method.try_new).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn try_new_on_monitor(title: &str, size: (u32, u32), monitor: usize, bg: Color, fg: Color, font: &str, font_size: u32) -> Result<Terminal, Box<dyn Error>> {
+        Terminal::with_screen(open_screen(title, size, Some(monitor))?, bg, fg, font, font_size)
+    }
+
+    /// Returns the piston_window window the terminal draws on, or None if it draws on another kind of screen.
+    /// Code written for the old `window` field can use this instead, so `term.window.hide()` becomes `term.window().unwrap().hide()`.
+    /// 
+    /// ```
+    /// # use simpleterm_profile::{backend::Headless, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// let mut term: Terminal = Terminal::with_screen(Box::new(Headless::new((800, 600))), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+    /// assert!(term.window().is_none());
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn window(&mut self) -> Option<&mut PistonWindow> {
+        let screen: &mut PistonScreen = self.screen.as_any_mut()?.downcast_mut()?;
+        Some(&mut screen.window)
+    }
+
+    /// Creates a terminal that draws on the given screen instead of opening a piston_window window, with the given colors and font info.
+    /// Returns an error if the font can't be loaded.
+    /// 
+    /// ```
+    /// # use simpleterm_profile::{backend::Headless, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// let term: Terminal = Terminal::with_screen(Box::new(Headless::new((800, 600))), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+    /// assert!(term.active);
+    /// ```
+    pub fn with_screen(mut screen: Box<dyn Screen>, bg: Color, fg: Color, font: &str, font_size: u32) -> Result<Terminal, Box<dyn Error>> {
+        screen.load_font(font)?;
+
+        let mut term: Terminal = Terminal {
             active: true,
-            window: new_window,
+            screen,
             bg_color: bg,
             fg_color: fg,
+            message_color: fg,
             scanlines: true,
-            glyphs: loaded_glyphs,
+            chat_boxes: true,
+            keymap: KeyMap::default(),
+            split_strategy: SplitStrategy::Chop,
+            fast_forward_speed: 5,
+            fast_forwarding: false,
+            auto_advance: false,
+            reading_speed: 200,
+            stream_rate: 20.0,
+            type_time: TYPE_TIME,
+            morse_unit: MORSE_UNIT,
+            bell_color: None,
             font: String::from(font),
             art_font: String::from("LeagueMono-Regular.ttf"),
             font_size,
+            line_spacing: DEFAULT_LINE_SPACING,
             art_font_size: 10,
-            art_mode: false,
+            cp437_art: false,
+            art_fonts: HashMap::new(),
+            loaded_font: String::from(font),
             message: Vec::new(),
+            message_styles: Vec::new(),
+            speakers: HashMap::new(),
+            chat_log: Vec::new(),
             input: String::default(),
-        }
+            window_file: None,
+            settings_file: None,
+            history: Vec::new(),
+            history_file: None,
+            completions: Vec::new(),
+            overprint: None,
+            bell_rung: None,
+            tab_width: 8,
+            always_on_top: false,
+            attract: None,
+            border_animation: BorderAnimation::None,
+            started: Instant::now(),
+            selected_text: String::default(),
+            gutter: None,
+            first_line: 1,
+            timestamp_format: None,
+            highlights: Vec::new(),
+            allow_back: false,
+            went_back: false,
+            answer_timeout: None,
+            answer_deadline: None,
+            timed_out: false,
+            diff_added_color: EMERALD,
+            diff_removed_color: CRIMSON,
+            widgets: Vec::new(),
+            frame_stats: FrameStats::default(),
+            style_stack: Vec::new(),
+            widget_corner: Corner::TopRight,
+            focused: true,
+            focus_callback: None,
+            function_key_callback: None,
+            type_callback: None,
+            dim_unfocused: true,
+            #[cfg(feature = "audio")]
+            sounds: None,
+            #[cfg(feature = "audio")]
+            volume: 1.0,
+            pause_unfocused: false,
+            key_repeat: Some(KeyRepeat::default()),
+            held_key: None,
+            virtual_keyboard: false,
+            keyboard: VirtualKeyboard::default(),
+            close_handle: CloseHandle::default(),
+            progress_bar: None,
+            spinner: None,
+            pins: Vec::new(),
+            commands: VecDeque::new(),
+            placement: Placement::Top,
+            scrollback: false,
+            scrollback_lines: Vec::new(),
+            scrollback_styles: Vec::new(),
+            scrolled_back: 0,
+            last_text: Vec::new(),
+            reflow_source: None,
+            typed_before: 0,
+            mask_char: '*',
+            hidden: false,
+            demo: None,
+            input_source: None,
+            running: None,
+            high_visibility: false,
+            rng: Rng::default(),
+        };
+        if env::var(HIGH_VISIBILITY_VAR).is_ok_and(|value| value != "0" && value != "false") { term.set_high_visibility(true); }
+        Ok(term)
     }
 
-    /// Types out the given message, then waits for the user to type something and returns Some(input string).
-    /// If the window is closed before input can be returned, returns None.
+    /// Types out the given message, then waits for the user to type something and returns how the question ended:
+    /// with the text they typed, cancelled by pressing Back, timed out after [answer_timeout](#structfield.answer_timeout),
+    /// or with the window closed. The up and down arrows recall earlier answers to edit and submit again, like in a shell.
     /// 
     /// ```no_run
-    /// # use simpleterm::text::*;
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::{answer::Answer, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
     /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
-    /// let user_input: String = term.ask("This will wait for the user enter input!").unwrap();
+    /// match term.ask("This will wait for the user enter input!") {
+    ///     Answer::Text(input) => term.tell(&format!("You said {}.", input)),
+    ///     Answer::Cancelled => term.tell("Never mind, then."),
+    ///     Answer::TimedOut => term.tell("Too slow!"),
+    ///     Answer::WindowClosed => {},
+    /// }
     /// ```
-    pub fn ask(&mut self, message: &str) -> Option<String> {
-        if self.active {
-            if self.art_mode {
-                self.glyphs = load_font(&mut self.window, &self.font);
-                self.art_mode = false;
-            }
-
-            self.new_message(message);
-            self.wait_for_input();
-            Some(self.input.clone())
-        } else {
-            None
-        }
+    pub fn ask(&mut self, message: &str) -> Answer {
+        self.ask_styled(&[Span::plain(message)])
     }
 
-    /// Displays an ascii art string centered on the terminal. This uses 10pt font and a monospace font.
+    /// Asks until the validator accepts the answer, and returns what it made of it. Each time the validator rejects an answer,
+    /// its error is shown in crimson above the prompt and the question is asked again.
+    /// Returns None if the user pressed Back, the answer timed out, or the window was closed.
     /// 
     /// ```no_run
-    /// # use std::time::Duration;
-    /// # use simpleterm::{art::*, text::*};
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
     /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
-    /// term.display_art(GEO, Duration::from_secs(2));
+    /// let door: Option<u32> = term.ask_validated("Pick a door from 1 to 3.", |input| match input.trim().parse::<u32>() {
+    ///     Ok(door) if (1..=3).contains(&door) => Ok(door),
+    ///     _ => Err(format!("There's no door {}.", input)),
+    /// });
+    /// if let Some(door) = door {
+    ///     term.tell(&format!("You open door {}.", door));
+    /// }
     /// ```
-    pub fn display_art(&mut self, art: &str, time: Duration) {
-        if self.active {
-            if !self.art_mode {
-                self.glyphs = load_font(&mut self.window, &self.art_font);
-                self.art_mode = true;
+    pub fn ask_validated<T, F>(&mut self, prompt: &str, validator: F) -> Option<T>
+    where
+        F: Fn(&str) -> Result<T, String>,
+    {
+        let mut spans: Vec<Span> = vec![Span::plain(prompt)];
+        loop {
+            let input: String = self.ask_styled(&spans).text()?;
+            match validator(&input) {
+                Ok(value) => return Some(value),
+                Err(error) => spans = vec![Span::colored(&error, CRIMSON), Span::plain("\n"), Span::plain(prompt)],
             }
-
-            self.message = art.split('\n').map(String::from).collect();
-            self.input = String::default();
-            self.show_art(time);
         }
     }
-    
-    /// Types out the given message, then waits for the given amount of time to continue.
+
+    /// Asks until the answer parses as the given type, and returns the parsed value. Answers that don't parse are
+    /// rejected the way [ask_validated](#method.ask_validated) rejects them, with a message saying what went wrong.
+    /// Returns None if the user pressed Back, the answer timed out, or the window was closed.
     /// 
     /// ```no_run
-    /// # use std::time::Duration;
-    /// # use simpleterm::text::*;
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
     /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
-    /// term.show("This will wait for 1 second!", Duration::from_secs(1));
+    /// fn birthday(term: &mut Terminal) -> Option<u32> {
+    ///     let age: u32 = term.ask_parse("How old are you?")?;
+    ///     Some(age + 1)
+    /// }
+    /// if let Some(age) = birthday(&mut term) {
+    ///     term.tell(&format!("Next year you'll be {}.", age));
+    /// }
     /// ```
-    pub fn show(&mut self, message: &str, time: Duration) {
-        if self.active {
-            if self.art_mode {
-                self.glyphs = load_font(&mut self.window, &self.font);
-                self.art_mode = false;
-            }
-
-            self.new_message(message);
-            self.wait_for_timer(time);
-        }
+    pub fn ask_parse<T: FromStr>(&mut self, prompt: &str) -> Option<T> {
+        self.ask_validated(prompt, |input| input.trim().parse::<T>().map_err(|_| format!("\"{}\" isn't a valid answer.", input)))
     }
 
-    /// Types out the given message, then waits for the user to press Enter to continue.
+    /// Asks like [ask_parse](#method.ask_parse), but shows the given error message whenever an answer doesn't parse.
     /// 
     /// ```no_run
-    /// # use simpleterm::text::*;
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
     /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
-    /// term.tell("This will wait for the user to hit enter!");
+    /// if let Some(gold) = term.ask_parse_or::<f64>("How much gold will you bet?", "That's not an amount of gold.") {
+    ///     term.tell(&format!("You bet {} gold.", gold));
+    /// }
     /// ```
-    pub fn tell(&mut self, message: &str) {
+    pub fn ask_parse_or<T: FromStr>(&mut self, prompt: &str, error: &str) -> Option<T> {
+        self.ask_validated(prompt, |input| input.trim().parse::<T>().map_err(|_| String::from(error)))
+    }
+
+    // Asks with a styled message, the way ask does.
+    fn ask_styled(&mut self, spans: &[Span]) -> Answer {
         if self.active {
-            if self.art_mode {
-                self.glyphs = load_font(&mut self.window, &self.font);
-                self.art_mode = false;
+            self.use_text_font();
+
+            self.message_color = self.fg_color;
+            self.new_styled_message(spans);
+            if self.play_demo_answer() {
+                self.remember_input();
+                return Answer::Text(self.input.clone());
             }
+            self.allow_back = true;
+            self.answer_deadline = self.answer_timeout.map(|timeout| Instant::now() + timeout);
+            while self.wait_for_input() { self.play_attract(); }
+            self.allow_back = false;
+            self.answer_deadline = None;
 
-            self.new_message(message);
-            self.input = String::from("Press Follow to Continue");
-            self.wait_for_continue();
+            if !self.active { return Answer::WindowClosed; }
+            if self.went_back { return Answer::Cancelled; }
+            if self.timed_out { return Answer::TimedOut; }
+            self.remember_input();
+            Answer::Text(self.input.clone())
+        } else {
+            Answer::WindowClosed
         }
     }
 
-    /// Closes the current window and creates a new one with the given (x, y) Size.
+    /// Asks like [ask](#method.ask), but draws each typed character as [mask_char](#structfield.mask_char) so secrets aren't shown on screen.
+    /// The real answer is returned, and isn't added to the input history.
     /// 
     /// ```no_run
-    /// # use simpleterm::text::*;
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::{answer::Answer, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
     /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
-    /// term.resize((800, 600).into());
+    /// if let Answer::Text(password) = term.ask_hidden("Password:") {
+    ///     term.tell(&format!("Your password is {} characters long.", password.chars().count()));
+    /// }
     /// ```
-    pub fn resize(&mut self, new_size: Size) {
-        if self.active {
-            let new_window: PistonWindow = WindowSettings::new(self.title.clone(), new_size).exit_on_esc(true).build().unwrap();
-            self.window = new_window;
-        }
+    pub fn ask_hidden(&mut self, message: &str) -> Answer {
+        self.hidden = true;
+        let answer: Answer = self.ask(message);
+        self.hidden = false;
+        answer
     }
 
-    /// Loads a new font from the given font filename and sets the given font size
+    /// Shows the prompt above a list of options and lets the user pick one with the up and down arrows, highlighting the current one.
+    /// Returns the index of the option picked with Enter, or None if the user pressed Back or the window was closed.
+    /// Lists too long for the window scroll to keep the current option in view.
     /// 
     /// ```no_run
-    /// # use simpleterm::text::*;
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
     /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
-    /// term.set_font("LeagueSpartan-Regular.ttf", 24);
+    /// let doors: [&str; 3] = ["The red door", "The blue door", "Neither"];
+    /// if let Some(choice) = term.choose("Which door do you open?", &doors) {
+    ///     term.tell(&format!("You chose: {}", doors[choice]));
+    /// }
     /// ```
-    pub fn set_font(&mut self, font: &str, size: FontSize) {
-        if self.active {
-            if !self.art_mode { self.glyphs = load_font(&mut self.window, font); }
-            self.font = String::from(font);
-            self.font_size = size;
+    pub fn choose(&mut self, prompt: &str, options: &[&str]) -> Option<usize> {
+        let mut cursor: usize = 0;
+        let mut first: usize = 0;
+
+        while self.active && !options.is_empty() {
+            self.use_text_font();
+            self.message_color = self.fg_color;
+            self.overprint = None;
+            self.scrolled_back = 0;
+
+            let max_chars: usize = self.get_max_message_characters();
+            let (mut message, _) = wrap_spans(&[Span::plain(prompt)], max_chars, self.split_strategy);
+            message.push(String::default());
+            let shown: usize = max_lines(self.win_size().height, self.text_size(), self.spacing()).saturating_sub(message.len()).max(1);
+            first = first.clamp(cursor.saturating_sub(shown - 1), cursor);
+
+            self.message_styles = vec![Vec::new(); message.len()];
+            for (i, option) in options.iter().enumerate().skip(first).take(shown) {
+                let row: String = format!("{} {}", if i == cursor { ">" } else { " " }, option);
+                let span: Span = if i == cursor { Span::plain(&row).inverse() } else { Span::plain(&row) };
+                self.message_styles.push(vec![span.style; row.chars().count()]);
+                message.push(row);
+            }
+            self.message = message;
+            self.input = String::default();
+
+            let action: Option<Action> = match self.demo_choice(options, cursor) {
+                Some(action) => {
+                    self.wait_for_timer(if action == Action::Continue { DEMO_PAUSE } else { DEMO_KEY_TIME });
+                    Some(action)
+                },
+                None => self.wait_for_action(&[Action::MoveUp, Action::MoveDown, Action::Continue, Action::Back]),
+            };
+            match action {
+                Some(Action::MoveUp) => cursor = cursor.saturating_sub(1),
+                Some(Action::MoveDown) => cursor = (cursor + 1).min(options.len() - 1),
+                Some(Action::Continue) => return Some(cursor),
+                _ => break,
+            }
         }
+        None
     }
 
-    /// Loads a new art font from the given font filename and sets the given font size.
-    /// You probably want to use a mono-space font here, and a small size.
-    /// 
-    /// The default is LeagueMono-Regular.ttf at 10pt.
+    /// Plays the rest of the script as a demo, answering each question with the next of the given answers as if the user had typed it,
+    /// and continuing past each message once there's been time to read it. [choose](#method.choose) takes an option's text or its number, counting from 1.
+    /// Once the answers run out, or an answer isn't one of the options offered, the user takes over again.
+    /// This makes it easy to watch a whole script play itself from start to finish.
     /// 
     /// ```no_run
-    /// # use simpleterm::text::*;
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
     /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
-    /// term.set_art_font("LeagueMono-Regular.ttf", 10);
+    /// term.start_demo(&["Ada", "The blue door"]);
+    /// let name: Option<String> = term.ask("What's your name?").text();
+    /// let door: Option<usize> = term.choose("Which door do you open?", &["The red door", "The blue door"]);
+    /// assert_eq!(door, Some(1));
     /// ```
-    pub fn set_art_font(&mut self, font: &str, size: FontSize) {
-        if self.active {
-            if self.art_mode { self.glyphs = load_font(&mut self.window, font); }
-            self.art_font = String::from(font);
-            self.art_font_size = size;
-        }
+    pub fn start_demo(&mut self, answers: &[&str]) {
+        self.demo = if answers.is_empty() { None } else { Some(answers.iter().map(|answer| String::from(*answer)).collect()) };
     }
 
-    /// Changes the terminal's background and foreground to the given colors. The change will be apparent in the next text command.
+    /// Starts a demo like [start_demo](#method.start_demo) with the answers in the given fixture file, one per line.
+    /// Blank lines and lines starting with # are skipped, so a fixture can note which question each answer is for.
     /// 
     /// ```no_run
-    /// # use simpleterm::text::*;
-    /// # use simpleterm::terminal::Terminal;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
     /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
-    /// term.set_colors(DARK_GREY, CRIMSON);
+    /// if std::env::args().any(|arg| arg == "--demo") {
+    ///     term.load_demo("demo.txt").expect("couldn't read the demo fixture");
+    /// }
     /// ```
-    pub fn set_colors(&mut self, bgc: Color, fgc: Color) {
-        self.bg_color = bgc;
-        self.fg_color = fgc;
+    pub fn load_demo<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let fixture: String = fs::read_to_string(path)?;
+        let answers: Vec<&str> = fixture.lines().map(str::trim_end).filter(|line| !line.is_empty() && !line.starts_with('#')).collect();
+        self.start_demo(&answers);
+        Ok(())
     }
 
-    // Displays an art string along with the rest of the terminal.
-    fn show_art(&mut self, timer: Duration) {
-        let bgc: Color = self.bg_color;
-        let fgc: Color = self.fg_color;
+    /// Returns true if a demo is playing and has answers left.
+    pub fn is_demo_playing(&self) -> bool {
+        self.demo.is_some()
+    }
 
-        let art: &Vec<String> = &self.message;
-        let glyphs: &mut Glyphs = &mut self.glyphs;
-        let font_size: FontSize = self.art_font_size;
-        let use_filter: bool = self.scanlines;
-        
-        let start: Instant = Instant::now();
+    // Takes the next answer from the playing demo, ending the demo once its answers run out.
+    fn next_demo_answer(&mut self) -> Option<String> {
+        let answer: Option<String> = self.demo.as_mut().and_then(|demo| demo.pop_front());
+        if self.demo.as_ref().is_some_and(|demo| demo.is_empty()) { self.demo = None; }
+        answer
+    }
+
+    // Types the next answer from the playing demo onto the input line a key at a time, leaving it in the input.
+    // Returns false without doing anything if no demo is playing.
+    fn play_demo_answer(&mut self) -> bool {
+        let answer: String = match self.next_demo_answer() {
+            Some(answer) => answer,
+            None => return false,
+        };
+
+        let typing: Duration = DEMO_KEY_TIME * answer.chars().count() as u32;
+        let mask: Option<char> = if self.hidden { Some(self.mask_char) } else { None };
+        let mut elapsed: Duration = Duration::from_secs(0);
+        let mut last_frame: Instant = Instant::now();
         let mut active: bool = self.active;
-        while let Some(e) = self.window.next() {
+        while let Some(e) = self.next_event() {
             e.close(|_| { active = false; });
 
-            let win_size: Size = self.window.window.size();
-
             let now: Instant = Instant::now();
-            if now.duration_since(start) > timer { break; }
+            if self.clock_running() { elapsed += now.duration_since(last_frame); }
+            last_frame = now;
+            if !active || elapsed > DEMO_PAUSE * 2 + typing { break; }
 
-            self.window.draw_2d(&e, |c, g, device| {
-                clear(bgc, g);
-
-                draw_background(win_size, bgc, fgc, use_filter, c, g);
-                draw_art(win_size, art, glyphs, font_size, fgc, c, g);
-                draw_foreground(win_size, bgc, use_filter, c, g);
-            
-                glyphs.factory.encoder.flush(device);
-            });
+            let typed: usize = (elapsed.saturating_sub(DEMO_PAUSE).as_nanos() / DEMO_KEY_TIME.as_nanos()) as usize;
+            let shown: String = answer.chars().take(typed).map(|c| mask.unwrap_or(c)).collect();
+            self.render_frame(&e, &FrameState { input: Some(&shown), input_marker: true, ..FrameState::default() });
         }
         self.active = active;
-    }
 
-    // Types a message one character at a time, waiting TYPE_TIME between each character.
-    fn type_message(&mut self) {
-        let bgc: Color = self.bg_color;
-        let fgc: Color = self.fg_color;
-        let current_input: &str = &(self.input[..]);
-        let glyphs = &mut self.glyphs;
-        let font_size: FontSize = self.font_size;
+        self.input = answer;
+        self.went_back = false;
+        self.timed_out = false;
+        active
+    }
 
-        let mut typed_message: Vec<String> = Vec::new();
-        let use_filter: bool = self.scanlines;
+    // Returns the key the playing demo presses next to pick its next answer from the given options, taking the answer once it's picked.
+    // Returns None if no demo is playing, or ends the demo and returns None if the answer isn't one of the options.
+    fn demo_choice(&mut self, options: &[&str], cursor: usize) -> Option<Action> {
+        let answer: &str = self.demo.as_ref()?.front()?;
+        let target: Option<usize> = options.iter().position(|option| option.eq_ignore_ascii_case(answer))
+            .or_else(|| answer.parse::<usize>().ok().filter(|number| (1..=options.len()).contains(number)).map(|number| number - 1));
 
-        let mut active: bool = self.active;
-        for (i, line) in self.message.iter().enumerate() {
-            typed_message.push(String::default());
-
-            let line_len: usize = line.len();
-            for j in 1..line_len {
-                typed_message[i] = String::from(&line[..=j]);
-                typed_message[i].push_str("[]");
-                if let Some(e) = self.window.next() {
-                    e.close(|_| { active = false; });
-
-                    let win_size: Size = self.window.window.size();
-
-                    self.window.draw_2d(&e, |c, g, device| {
-                        clear(bgc, g);
-
-                        draw_background(win_size, bgc, fgc, use_filter, c, g);
-                        draw_message(&typed_message, glyphs, font_size, fgc, c, g);
-                        draw_input(win_size, current_input, glyphs, font_size, fgc, c, g);
-                        draw_foreground(win_size, bgc, use_filter, c, g);
-                    
-                        glyphs.factory.encoder.flush(device);
-                    });
-                    thread::sleep(TYPE_TIME);
-                }
-                typed_message[i].pop();
-                typed_message[i].pop();
-            }
+        match target {
+            None => {
+                self.demo = None;
+                None
+            },
+            Some(target) if target < cursor => Some(Action::MoveUp),
+            Some(target) if target > cursor => Some(Action::MoveDown),
+            Some(_) => {
+                self.next_demo_answer();
+                Some(Action::Continue)
+            },
         }
-        self.active = active;
     }
 
-    // Displays the current terminal until the user presses Enter.
-    fn wait_for_continue(&mut self) {
-        let mut ready: bool = false;
+    // Adds the answer just given to the input history, unless it repeats the last one.
+    fn remember_input(&mut self) {
+        if self.active && !self.hidden && !self.input.is_empty() && self.history.last() != Some(&self.input) {
+            self.history.push(self.input.clone());
+            self.trim_history();
+        }
+    }
 
-        let bgc: Color = self.bg_color;
-        let fgc: Color = self.fg_color;
+    // Drops the oldest answers once the history is over its limit.
+    fn trim_history(&mut self) {
+        let excess: usize = self.history.len().saturating_sub(HISTORY_LIMIT);
+        self.history.drain(..excess);
+    }
 
-        let message: &Vec<String> = &self.message;
-        let current_input: &str = &(self.input);
-        let glyphs: &mut Glyphs = &mut self.glyphs;
-        let font_size: FontSize = self.font_size;
-        let use_filter: bool = self.scanlines;
-        
-        let mut start: Instant = Instant::now();
-        let mut active: bool = self.active;
-        while let Some(e) = self.window.next() {
-            e.close(|_| { active = false; });
+    // Asks a question like ask, but if can_go_back is true the user can also press the back key instead of answering.
+    // Returns None if the window was closed.
+    pub(crate) fn ask_step(&mut self, message: &str, can_go_back: bool) -> Option<StepInput> {
+        if self.active {
+            self.use_text_font();
 
-            let win_size: Size = self.window.window.size();
+            self.message_color = self.fg_color;
+            self.new_message(message);
+            if self.play_demo_answer() { return Some(StepInput::Answer(self.input.clone())); }
+            self.allow_back = can_go_back;
+            while self.wait_for_input() { self.play_attract(); }
+            self.allow_back = false;
 
-            e.button(|button_args| {
-                if let Button::Keyboard(key) = button_args.button {
-                    if button_args.state == ButtonState::Press && key == Key::Return { ready = true; }
-                }
-            });
+            if !self.active { return None; }
+            Some(if self.went_back { StepInput::Back } else { StepInput::Answer(self.input.clone()) })
+        } else {
+            None
+        }
+    }
 
-            if ready { break; }
+    /// Displays an ascii art string centered on the terminal. This uses 10pt font and a monospace font.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{art::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.display_art(DANCE_1, Duration::from_secs(2));
+    /// ```
+    pub fn display_art(&mut self, art: &str, time: Duration) {
+        if self.active {
+            self.use_art_font();
 
-            let now: Instant = Instant::now();
-            self.window.draw_2d(&e, |c, g, device| {
-                clear(bgc, g);
-
-                draw_background(win_size, bgc, fgc, use_filter, c, g);
-                draw_message(message, glyphs, font_size, fgc, c, g);
-                draw_input_marker(win_size, glyphs, font_size, fgc, c, g);
-                if check_flash(now, &mut start) { draw_input(win_size, current_input, glyphs, font_size, fgc, c, g); }
-                draw_foreground(win_size, bgc, use_filter, c, g);
-            
-                glyphs.factory.encoder.flush(device);
-            });
+            self.message = art.split('\n').map(String::from).collect();
+            self.message_styles = Vec::new();
+            self.input = String::default();
+            self.show_art(time, false);
         }
-        self.active = active;
     }
 
-    // Displays the current terminal until the user submits some input.
-    fn wait_for_input(&mut self) {
-        let mut input_string: String = String::default();
-        let mut input_accepted: bool = false;
+    /// Plays art frames one after another, showing each for frame_time, and goes through them the given number of times,
+    /// or over and over if loops is 0. Pressing Back stops it early, and fast forward speeds it up.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{art::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.play_animation(&DANCES, Duration::from_millis(200), 3);
+    /// ```
+    pub fn play_animation(&mut self, frames: &[&str], frame_time: Duration, loops: usize) {
+        let frames: Vec<Vec<String>> = frames.iter().map(|frame| frame.split('\n').map(String::from).collect()).collect();
+        let time: Duration = match loops {
+            0 => Duration::MAX,
+            loops => frame_time.saturating_mul((frames.len() * loops).min(u32::MAX as usize) as u32),
+        };
 
-        let bgc: Color = self.bg_color;
-        let fgc: Color = self.fg_color;
+        let frame_nanos: u128 = frame_time.as_nanos().max(1);
+        let last_frame: usize = if loops == 0 { usize::MAX } else { (frames.len() * loops).saturating_sub(1) };
+        self.play_art_frames(time, true, |elapsed| {
+            let index: usize = ((elapsed.as_nanos() / frame_nanos) as usize).min(last_frame);
+            (frames.get(index % frames.len().max(1)).cloned().unwrap_or_default(), Vec::new())
+        });
+    }
 
-        let message: &Vec<String> = &self.message;
-        let glyphs: &mut Glyphs = &mut self.glyphs;
-        let font_size: FontSize = self.font_size;
-        let use_filter: bool = self.scanlines;
-        
-        let mut start: Instant = Instant::now();
+    /// Displays ascii art read from a text file centered on the terminal, like [display_art](#method.display_art).
+    /// Returns an error without showing anything if the file can't be read.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.display_art_file("resources/art/castle.txt", Duration::from_secs(3)).expect("couldn't read the castle");
+    /// ```
+    pub fn display_art_file<P: AsRef<Path>>(&mut self, path: P, time: Duration) -> io::Result<()> {
+        let art: String = art::load_art(path.as_ref())?;
+        self.display_art(&art, time);
+        Ok(())
+    }
+
+    /// Displays an ascii art string centered on the terminal for one period, while its color blends smoothly through the given colors
+    /// and back to the first. If no colors are given, the art is shown in the foreground color.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{art::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.display_art_cycled(DANCE_1, &COLORS, Duration::from_secs(5));
+    /// ```
+    pub fn display_art_cycled(&mut self, art: &str, colors: &[Color], period: Duration) {
+        let lines: Vec<String> = art.split('\n').map(String::from).collect();
+        self.play_art_frames(period, false, |elapsed| {
+            let style: Style = Style { color: cycle_color(colors, period, elapsed), ..Style::default() };
+            let styles: Vec<Vec<Style>> = lines.iter().map(|line| vec![style; line.chars().count()]).collect();
+            (lines.clone(), styles)
+        });
+    }
+
+    /// Displays colored ANSI art centered on the terminal for the given amount of time.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{ansi::AnsiArt, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let logo: AnsiArt = AnsiArt::open("resources/logo.ans").unwrap();
+    /// term.display_ansi(&logo, Duration::from_secs(5));
+    /// ```
+    pub fn display_ansi(&mut self, art: &AnsiArt, time: Duration) {
+        self.play_art_frames(time, false, |_| (art.lines.clone(), art.styles.clone()));
+    }
+
+    /// Plays a track of timed captions, showing each cue's text near the bottom of the terminal from its start time to its end time,
+    /// so the terminal can follow along with audio or video playing elsewhere. Continue skips to the next cue, SeekBack and SeekForward
+    /// (the left and right arrows by default) jump back and ahead a few seconds, and the Back action (Escape by default) stops early.
+    /// Playback pauses along with timers while the window is unfocused if [pause_unfocused](#structfield.pause_unfocused) is set.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{captions::CaptionTrack, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let track: CaptionTrack = CaptionTrack::open("resources/intro.srt").unwrap();
+    /// term.play_captions(&track);
+    /// ```
+    pub fn play_captions(&mut self, track: &CaptionTrack) {
+        if !self.active { return; }
+        self.use_text_font();
+
+        let keymap: KeyMap = self.keymap.clone();
+        let placement: Placement = mem::replace(&mut self.placement, Placement::Bottom);
+        let allow_back: bool = mem::replace(&mut self.allow_back, true);
+        self.message_color = self.fg_color;
+        self.input = String::default();
+
+        let mut position: Duration = Duration::ZERO;
+        let mut shown: Option<Option<usize>> = None;
+        let mut stopped: bool = false;
+        let mut last_frame: Instant = Instant::now();
         let mut active: bool = self.active;
-        while let Some(e) = self.window.next() {
+        while let Some(e) = self.next_event() {
             e.close(|_| { active = false; });
-
-            let win_size: Size = self.window.window.size();
-            
-            e.text(|text| input_string.push_str(text));
-            e.button(|button_args| {
-                if let Button::Keyboard(key) = button_args.button {
-                    if button_args.state == ButtonState::Press {
-                        if key == Key::Backspace { input_string.pop(); }
-                        if key == Key::Return && input_string != "" { input_accepted = true; }
-                    }
+            e.button(|button_args| if button_args.state == ButtonState::Press {
+                if keymap.is_pressed(&button_args, Action::Continue) {
+                    position = track.next_cue(position).map_or(track.end(), |next| track.cues[next].start);
                 }
+                if keymap.is_pressed(&button_args, Action::SeekBack) { position = position.saturating_sub(SEEK_TIME); }
+                if keymap.is_pressed(&button_args, Action::SeekForward) { position += SEEK_TIME; }
+                if keymap.is_pressed(&button_args, Action::Back) { stopped = true; }
             });
 
-            if input_accepted {
-                self.input = input_string.clone();
-                input_string = String::default();
-            }
-            
             let now: Instant = Instant::now();
-            self.window.draw_2d(&e, |c, g, device| {
-                clear(bgc, g);
-
-                draw_background(win_size, bgc, fgc, use_filter, c, g);
-                draw_message(message, glyphs, font_size, fgc, c, g);
-                draw_input_marker(win_size, glyphs, font_size, fgc, c, g);
-
-                if check_flash(now, &mut start) {
-                    input_string.push_str("[]");
-                    draw_input(win_size, &input_string[..], glyphs, font_size, fgc, c, g);
-                    input_string.pop();
-                    input_string.pop();
-                } else {
-                    draw_input(win_size, &input_string[..], glyphs, font_size, fgc, c, g);
+            if self.clock_running() { position += now.duration_since(last_frame); }
+            last_frame = now;
+            if stopped || position >= track.end() { break; }
+
+            let current: Option<usize> = track.cue_at(position);
+            if shown != Some(current) {
+                let text: &str = current.map_or("", |i| &track.cues[i].text);
+                let (lines, styles) = wrap_spans(&[Span::plain(text)], self.get_max_message_characters(), self.split_strategy);
+                self.message = lines;
+                self.message_styles = styles;
+                shown = Some(current);
+            }
+            self.render_frame(&e, &FrameState::default());
+        }
+        self.active = active;
+        self.placement = placement;
+        self.allow_back = allow_back;
+    }
+
+    /// Plays a scene built from several pieces of art for the given amount of time, animating any animated pieces.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{art::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let scene: ArtScene = ArtScene::new()
+    ///     .piece("sun", SUN, (0, 0), Some(GOLD))
+    ///     .piece("cloud", CLOUD, (14, 2), Some(OFF_WHITE))
+    ///     .animated_piece("rain", Animation::new(&[RAIN, SNOW], Duration::from_millis(300)), (14, 8), Some(LIGHT_BLUE));
+    /// term.play_scene(&scene, Duration::from_secs(5));
+    /// ```
+    pub fn play_scene(&mut self, scene: &ArtScene, time: Duration) {
+        self.play_art_frames(time, false, |elapsed| scene.render(elapsed));
+    }
+
+    /// Converts an image file, like a PNG or JPEG, into shaded art as wide as the window allows with [from_image](../art/fn.from_image.html),
+    /// then displays it centered on the terminal for the given amount of time. Returns an error without showing anything if the image can't be read.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.display_image("resources/portrait.png", Duration::from_secs(5)).expect("couldn't read the portrait");
+    /// ```
+    #[cfg(feature = "sprites")]
+    pub fn display_image<P: AsRef<Path>>(&mut self, path: P, time: Duration) -> ::image::ImageResult<()> {
+        let art: String = art::from_image(path, self.get_max_art_characters(), art::SHADE_RAMP)?;
+        self.display_art(&art, time);
+        Ok(())
+    }
+
+    /// Plays an animation from a sprite sheet as art for the given amount of time. Each cell is converted to shaded art
+    /// as wide as the window allows, up to one character per pixel. Animations that don't loop stay on their last cell.
+    /// 
+    /// ```no_run
+    /// # #[cfg(feature = "sprites")] {
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{sprites::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let sheet: SpriteSheet = SpriteSheet::open("walk.png", (32, 32)).unwrap();
+    /// let walk: SpriteAnimation = SpriteAnimation { cells: vec![0, 1, 2, 3], fps: 8.0, looping: true };
+    /// term.play_sprite(&sheet, &walk, Duration::from_secs(3));
+    /// # }
+    /// ```
+    pub fn play_sprite(&mut self, sheet: &SpriteSheet, animation: &SpriteAnimation, time: Duration) {
+        let columns: usize = self.get_max_art_characters();
+        let mut frames: HashMap<usize, Vec<String>> = HashMap::new();
+        for cell in animation.cells.iter() {
+            if let Some(art) = sheet.cell_art(*cell, columns, art::SHADE_RAMP, Dither::None) {
+                frames.insert(*cell, art.split('\n').map(String::from).collect());
+            }
+        }
+
+        self.play_art_frames(time, false, |elapsed| {
+            let lines: Vec<String> = animation.cell_at(elapsed).and_then(|cell| frames.get(&cell)).cloned().unwrap_or_default();
+            (lines, Vec::new())
+        });
+    }
+
+    /// Displays an ascii art string centered on the terminal using the named art font registered with `add_art_font`.
+    /// If no art font has that name, the default art font is used.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{art::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.add_art_font("fine", "LeagueMono-Regular.ttf", 6);
+    /// term.display_art_with(DANCE_1, "fine", Duration::from_secs(2));
+    /// ```
+    pub fn display_art_with(&mut self, art: &str, font_name: &str, time: Duration) {
+        let (font, size) = match self.art_fonts.get(font_name) {
+            Some(art_font) => art_font.clone(),
+            None => (self.art_font.clone(), self.art_font_size),
+        };
+        let default_font: String = mem::replace(&mut self.art_font, font);
+        let default_size: FontSize = mem::replace(&mut self.art_font_size, size);
+
+        self.display_art(art, time);
+
+        self.art_font = default_font;
+        self.art_font_size = default_size;
+    }
+    
+    /// Types out the given message, then waits for the given amount of time to continue.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.show("This will wait for 1 second!", Duration::from_secs(1));
+    /// ```
+    pub fn show(&mut self, message: &str, time: Duration) {
+        let fgc: Color = self.fg_color;
+        self.show_colored(message, fgc, time);
+    }
+
+    /// Types out the given message in the given color, then waits for the given amount of time to continue.
+    /// The terminal's foreground color is left unchanged for the next message.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.show_colored("This will be crimson for 1 second!", CRIMSON, Duration::from_secs(1));
+    /// ```
+    pub fn show_colored(&mut self, message: &str, color: Color, time: Duration) {
+        if self.active {
+            self.use_text_font();
+
+            self.message_color = color;
+            self.new_message(message);
+            self.wait_for_timer(time);
+        }
+    }
+
+    /// Types out the given message at the given height on the terminal, then waits for the given amount of time to continue.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{draw::Placement, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.show_placed("CHAPTER ONE", Placement::Center, Duration::from_secs(2));
+    /// ```
+    pub fn show_placed(&mut self, message: &str, placement: Placement, time: Duration) {
+        let previous: Placement = mem::replace(&mut self.placement, placement);
+        self.show(message, time);
+        self.placement = previous;
+    }
+
+    /// Types out the given message, then waits for the user to press Enter to continue.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell("This will wait for the user to hit enter!");
+    /// ```
+    pub fn tell(&mut self, message: &str) {
+        let fgc: Color = self.fg_color;
+        self.tell_colored(message, fgc);
+    }
+
+    /// Types out the given message in the given color, then waits for the user to press Enter to continue.
+    /// The terminal's foreground color is left unchanged for the next message.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell_colored("Who goes there?", LIGHT_BLUE);
+    /// term.tell_colored("It is I, the crimson knight!", CRIMSON);
+    /// ```
+    pub fn tell_colored(&mut self, message: &str, color: Color) {
+        if self.active {
+            self.use_text_font();
+
+            self.message_color = color;
+            self.new_message(message);
+            self.finish_message(false);
+        }
+    }
+
+    /// Types out the given message at the given height on the terminal, then waits for the user to press Enter to continue.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{draw::Placement, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell_placed("THE END", Placement::Center);
+    /// term.tell_placed("...or is it?", Placement::Bottom);
+    /// ```
+    pub fn tell_placed(&mut self, message: &str, placement: Placement) {
+        let previous: Placement = mem::replace(&mut self.placement, placement);
+        self.tell(message);
+        self.placement = previous;
+    }
+
+    /// Types out the given styled spans, then waits for the user to press Enter to continue.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell_styled(&[Span::colored("WARNING:", CRIMSON).blink(), Span::plain(" reactor core "), Span::plain("unstable").inverse()]);
+    /// ```
+    pub fn tell_styled(&mut self, spans: &[Span]) {
+        if self.active {
+            self.use_text_font();
+
+            self.message_color = self.fg_color;
+            self.new_styled_message(spans);
+            self.finish_message(false);
+        }
+    }
+
+    /// Types out the given lines one after another for a dramatic reveal, then waits for the user to press Enter to continue.
+    /// Each line stays on screen under the ones before it, typed at its own speed and in its own color as set by the reveal,
+    /// so the lines can build up to a slow, accented finish.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{effects::Reveal, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.reveal(&["The butler was in the library.", "The library was locked from the inside.", "The butler did it."], &Reveal::default());
+    /// ```
+    pub fn reveal(&mut self, lines: &[&str], reveal: &Reveal) {
+        if self.active {
+            self.use_text_font();
+
+            self.message_color = self.fg_color;
+            self.overprint = None;
+            self.scrolled_back = 0;
+            self.reflow_source = None;
+            self.first_line = 1;
+            self.message = Vec::new();
+            self.message_styles = Vec::new();
+            self.input = String::default();
+
+            let type_time: Duration = self.type_time;
+            for (i, line) in lines.iter().enumerate() {
+                if !self.active { break; }
+                if i > 0 { self.wait_for_timer(reveal.pause); }
+
+                let (time, color) = reveal.line(i, lines.len(), type_time, self.fg_color);
+                let (wrapped, styles) = wrap_spans(&[Span::colored(line, color)], self.get_max_message_characters(), self.split_strategy);
+                let page_lines: usize = max_lines(self.win_size().height, self.text_size(), self.spacing());
+                self.scroll_off((self.message.len() + wrapped.len()).saturating_sub(page_lines));
+
+                self.typed_before = self.message.iter().map(|line| line.chars().count()).sum();
+                self.message_styles.resize(self.message.len(), Vec::new());
+                self.message.extend(wrapped);
+                self.message_styles.extend(styles);
+                self.type_time = time;
+                self.type_message();
+            }
+            self.type_time = type_time;
+            self.last_text = self.message.clone();
+
+            self.finish_message(false);
+        }
+    }
+
+    /// Types out a message written with [markup](../text/fn.parse_markup.html) tags, then waits for the user to press Enter to continue.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell_markup("[color=crimson][blink]WARNING:[/blink][/color] reactor core [inverse]unstable[/inverse]");
+    /// ```
+    pub fn tell_markup(&mut self, message: &str) {
+        self.tell_styled(&parse_markup(message));
+    }
+
+    /// Types out the given message in step with its Morse code, flashing the border for each dot and dash,
+    /// then waits for the user to press Enter to continue. Characters without a code are typed without a signal.
+    /// Fast forward speeds up the code along with the typing.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.morse_unit = Duration::from_millis(120);
+    /// term.morse("SOS");
+    /// ```
+    pub fn morse(&mut self, message: &str) {
+        if self.active {
+            self.use_text_font();
+
+            self.message_color = self.fg_color;
+            self.overprint = None;
+            self.scrolled_back = 0;
+            self.first_line = 1;
+
+            let max_chars: usize = self.get_max_message_characters();
+            let page_lines: usize = max_lines(self.win_size().height, self.text_size(), self.spacing());
+            let (message, styles) = wrap_spans(&[Span::plain(message)], max_chars, self.split_strategy);
+            for (i, page) in message.chunks(page_lines).enumerate() {
+                if !self.active { break; }
+                if i > 0 { self.scroll_off(page_lines); }
+                self.message = page.to_vec();
+                self.message_styles = styles.iter().skip(i * page_lines).take(page_lines).cloned().collect();
+                self.last_text = self.message.clone();
+                self.input = String::default();
+                self.send_morse();
+
+                self.finish_message(false);
+            }
+        }
+    }
+
+    /// Types out the given styled spans, then waits for the given amount of time to continue.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.show_styled(&[Span::plain("Loading "), Span::plain("...").blink()], Duration::from_secs(3));
+    /// ```
+    pub fn show_styled(&mut self, spans: &[Span], time: Duration) {
+        if self.active {
+            self.use_text_font();
+
+            self.message_color = self.fg_color;
+            self.new_styled_message(spans);
+            self.wait_for_timer(time);
+        }
+    }
+
+    /// Types out the given message indented under the speaker's name, then waits for the user to press Enter to continue.
+    /// The message is wrapped to fit beside the indent, so every line of it stays indented.
+    /// The name is drawn in the speaker's color. Speakers without a color set by [set_speaker_color](#method.set_speaker_color)
+    /// are given the next color from [COLORS](../text/constant.COLORS.html) the first time they speak.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_speaker_color("Knight", CRIMSON);
+    /// term.say("Guard", "Who goes there?");
+    /// term.say("Knight", "It is I, the crimson knight!");
+    /// ```
+    pub fn say(&mut self, speaker: &str, message: &str) {
+        if self.active {
+            self.use_text_font();
+
+            let next_color: Color = COLORS[self.speakers.len() % COLORS.len()];
+            let color: Color = *self.speakers.entry(String::from(speaker)).or_insert(next_color);
+            let lines: Vec<String> = message.split('\n').map(String::from).collect();
+            let body: Vec<String> = wrap_indented(&lines, SPEAKER_INDENT, self.get_max_message_characters(), self.split_strategy);
+
+            self.message_color = self.fg_color;
+            self.new_styled_message(&[Span::colored(&format!("[{}]\n", speaker), color), Span::plain(&body.join("\n"))]);
+            self.finish_message(false);
+        }
+    }
+
+    /// Sets the color used to draw the given speaker's name in [say](#method.say).
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_speaker_color("Narrator", LIGHT_PURPLE);
+    /// ```
+    pub fn set_speaker_color(&mut self, speaker: &str, color: Color) {
+        self.speakers.insert(String::from(speaker), color);
+    }
+
+    /// Adds a message to the chat conversation and displays the conversation until the user presses Enter to continue.
+    /// Messages from [Side::Them](../text/enum.Side.html) are aligned left and messages from Side::Me are aligned right,
+    /// like a messenger app. The conversation is kept until [clear_chat](#method.clear_chat) is called.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.chat(Side::Them, "hey, are you still coming tonight?");
+    /// term.chat(Side::Me, "yes! be there at 8");
+    /// term.clear_chat();
+    /// ```
+    pub fn chat(&mut self, side: Side, message: &str) {
+        if self.active {
+            self.use_text_font();
+
+            let max_chars: usize = self.get_max_characters() * 2 / 3;
+            let lines: Vec<String> = message.split('\n').map(String::from).collect();
+            self.chat_log.push(Bubble { side, lines: wrap_lines_with(&lines, max_chars, self.split_strategy), color: self.fg_color });
+
+            self.message = Vec::new();
+            self.message_styles = Vec::new();
+            self.finish_message(true);
+        }
+    }
+
+    /// Removes every message from the chat conversation.
+    pub fn clear_chat(&mut self) {
+        self.chat_log.clear();
+    }
+
+    /// Displays a bar chart of the given labels and values, with the bars sized to fit the window, for the given amount of time.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.show_chart(&["mon", "tue", "wed"], &[3.0, 7.5, 5.0], Duration::from_secs(3));
+    /// ```
+    pub fn show_chart(&mut self, labels: &[&str], values: &[f64], time: Duration) {
+        let label_width: usize = labels.iter().map(|label| label.chars().count()).max().unwrap_or(0);
+        let value_width: usize = values.iter().map(|value| value.to_string().len()).max().unwrap_or(0);
+        let width: usize = self.get_max_art_characters().saturating_sub(label_width + value_width + 2);
+
+        self.display_art(&art::bar_chart(labels, values, width), time);
+    }
+
+    /// Displays a big-digit clock counting down from the given duration, updating every second until it reaches zero.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.countdown(Duration::from_secs(90));
+    /// term.tell("Time's up!");
+    /// ```
+    pub fn countdown(&mut self, duration: Duration) {
+        let start: Instant = Instant::now();
+        while self.active {
+            let remaining: Duration = duration.saturating_sub(start.elapsed());
+            if remaining.is_zero() { break; }
+
+            let seconds: u64 = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+            let clock: String = if seconds >= 3600 {
+                format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+            } else {
+                format!("{:02}:{:02}", seconds / 60, seconds % 60)
+            };
+
+            let until_tick: Duration = remaining - Duration::from_secs(seconds - 1);
+            self.display_art(&art::big_digits(&clock), until_tick);
+        }
+    }
+
+    /// Shows bytes as a classic offset, hex, and ascii dump in the art font, a page at a time,
+    /// waiting for the user to continue after each page.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.show_hex(b"\x7fELF\x02\x01\x01\x00 not really an executable");
+    /// ```
+    pub fn show_hex(&mut self, bytes: &[u8]) {
+        if self.active {
+            let lines: Vec<String> = art::hex_dump(bytes).lines().map(String::from).collect();
+            self.show_art_pages(lines);
+        }
+    }
+
+    /// Types out a unified diff between two texts, with added lines in the diff added color and removed lines in the
+    /// diff removed color. Long lines are hard wrapped so their spacing is kept, and continuation lines repeat the +/- marker.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.show_diff("fn main() {\n    old();\n}", "fn main() {\n    new();\n}");
+    /// ```
+    pub fn show_diff(&mut self, old: &str, new: &str) {
+        if self.active {
+            self.use_text_font();
+
+            let max_chars: usize = self.get_max_message_characters();
+            let mut message: Vec<String> = Vec::new();
+            let mut styles: Vec<Vec<Style>> = Vec::new();
+            for line in unified_diff(old, new, DIFF_CONTEXT) {
+                let (prefix, color) = match line {
+                    DiffLine::Added(_) => ("+", Some(self.diff_added_color)),
+                    DiffLine::Removed(_) => ("-", Some(self.diff_removed_color)),
+                    DiffLine::Hunk(_) => ("", Some(self.fg_color)),
+                    DiffLine::Context(_) => (" ", None),
+                };
+                for wrapped in wrap_exact(&line.to_line(), max_chars, prefix) {
+                    styles.push(vec![Style { color, ..Style::default() }; wrapped.chars().count()]);
+                    message.push(wrapped);
+                }
+            }
+
+            self.new_wrapped_message(message, styles);
+            self.finish_message(false);
+        }
+    }
+
+    /// Pretty-prints a JSON value with syntax colors. The user can move between lines with the up and down arrows,
+    /// collapse and expand objects and arrays with the left and right arrows, and continue when they're done.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.show_json(&serde_json::json!({"player": {"name": "Ada", "inventory": ["lamp", "key"]}}));
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn show_json(&mut self, value: &serde_json::Value) {
+        let mut view: JsonView = JsonView::new(value.clone());
+        let mut cursor: usize = 0;
+        let mut top: usize = 0;
+
+        while self.active {
+            self.use_text_font();
+
+            let lines: Vec<JsonLine> = view.lines();
+            cursor = cursor.min(lines.len().saturating_sub(1));
+            let page_lines: usize = max_lines(self.win_size().height, self.text_size(), self.spacing());
+            if cursor < top { top = cursor; }
+            if cursor >= top + page_lines { top = cursor + 1 - page_lines; }
+
+            self.message = Vec::new();
+            self.message_styles = Vec::new();
+            for (i, line) in lines.iter().enumerate().skip(top).take(page_lines) {
+                let mut spans: Vec<Span> = line.spans.clone();
+                if i == cursor { spans = spans.into_iter().map(Span::inverse).collect(); }
+
+                let text: String = spans.iter().map(|span| span.text.as_str()).collect();
+                self.message_styles.push(spans.iter().flat_map(|span| vec![span.style; span.text.chars().count()]).collect());
+                self.message.push(text);
+            }
+            self.first_line = top + 1;
+            self.input = String::from("Press Follow to Continue");
+
+            match self.wait_for_action(&[Action::MoveUp, Action::MoveDown, Action::Collapse, Action::Expand, Action::Continue]) {
+                Some(Action::MoveUp) => cursor = cursor.saturating_sub(1),
+                Some(Action::MoveDown) => cursor += 1,
+                Some(Action::Collapse) => if let Some(path) = &lines[cursor].fold { view.collapse(path); },
+                Some(Action::Expand) => if let Some(path) = &lines[cursor].fold { view.expand(path); },
+                _ => break,
+            }
+        }
+    }
+
+    /// Runs an operation on a background thread while showing the label with a spinner. If it fails, the error is shown
+    /// and the user can retry it, skip it, or abort. Returns how it ended. If the operation panics, the panic carries on in the calling thread.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::{Outcome, Terminal};
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// match term.fetch_with_retry("Downloading save file", || std::fs::read_to_string("save.txt")) {
+    ///     Outcome::Done(save) => term.tell(&save),
+    ///     Outcome::Skipped(_) => term.tell("Starting a new game."),
+    ///     Outcome::Aborted(_) => term.active = false,
+    /// }
+    /// ```
+    pub fn fetch_with_retry<T, E, F>(&mut self, label: &str, mut op: F) -> Outcome<T, E>
+    where
+        T: Send,
+        E: std::fmt::Display + Send,
+        F: FnMut() -> Result<T, E> + Send,
+    {
+        loop {
+            self.use_text_font();
+
+            let result: Result<T, E> = thread::scope(|scope| {
+                let worker = scope.spawn(&mut op);
+                let mut frame: usize = 0;
+                while !worker.is_finished() && self.active {
+                    self.message = vec![format!("{} {}", label, SPINNER_FRAMES[frame % SPINNER_FRAMES.len()])];
+                    self.message_styles = Vec::new();
+                    self.input = String::default();
+                    self.wait_for_timer(SPINNER_TIME);
+                    frame += 1;
+                }
+                worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            });
+
+            let error: E = match result {
+                Ok(value) => return Outcome::Done(value),
+                Err(error) => error,
+            };
+            if !self.active { return Outcome::Aborted(error); }
+
+            let failure: String = format!("{} failed: {}", label, error);
+            let (message, styles) = wrap_spans(&[Span::colored(&failure, CRIMSON)], self.get_max_message_characters(), self.split_strategy);
+            self.message = message;
+            self.message_styles = styles;
+            self.input = String::from("[R]etry, [S]kip, or [A]bort?");
+
+            match self.wait_for_action(&[Action::Retry, Action::Skip, Action::Abort]) {
+                Some(Action::Retry) => continue,
+                Some(Action::Skip) => return Outcome::Skipped(error),
+                _ => return Outcome::Aborted(error),
+            }
+        }
+    }
+
+    /// Reseeds the terminal's random number generator. Random effects like dice rolls repeat exactly when given the same seed,
+    /// which makes recordings and replays reproducible. Otherwise the generator is seeded from the time the terminal was created.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.seed(1234);
+    /// let first: Vec<u32> = term.roll_dice(3, 6);
+    /// term.seed(1234);
+    /// assert_eq!(first, term.roll_dice(3, 6));
+    /// ```
+    pub fn seed(&mut self, seed: u64) {
+        self.rng = Rng::new(seed);
+    }
+
+    /// Returns the terminal's random number generator, so scripts and custom effects can share its seed.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let greetings: [&str; 3] = ["Hello!", "Howdy!", "Greetings!"];
+    /// let greeting: &str = term.rng().pick(&greetings).unwrap();
+    /// term.tell(greeting);
+    /// ```
+    pub fn rng(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+
+    /// Rolls n dice with the given number of sides, tumbling through a few random faces before settling.
+    /// The final faces stay on screen briefly, and the rolled values are returned.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let rolls: Vec<u32> = term.roll_dice(2, 6);
+    /// term.tell(&format!("You rolled {}!", rolls.iter().sum::<u32>()));
+    /// ```
+    pub fn roll_dice(&mut self, n: usize, sides: u32) -> Vec<u32> {
+        let mut rolls: Vec<u32> = Vec::new();
+        if self.active {
+            for frame in 0..=ROLL_FRAMES {
+                rolls = (0..n).map(|_| self.rng.range(1, sides.max(1) as u64) as u32).collect();
+                let faces: Vec<String> = rolls.iter().map(|roll| art::die_face(*roll)).collect();
+                let faces: Vec<&str> = faces.iter().map(String::as_str).collect();
+
+                let time: Duration = if frame == ROLL_FRAMES { FADE_TIME } else { ROLL_FRAME_TIME };
+                self.display_art(&art::beside(&faces, 2), time);
+            }
+        }
+        rolls
+    }
+
+    /// Plays a final message and piece of art for the given amount of time each, fades the art out to the background color,
+    /// and then closes the window. The terminal is inactive afterwards, so a script's main loop will end.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{art::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.farewell("Thanks for playing!", DANCE_5, Duration::from_secs(2));
+    /// assert!(!term.active);
+    /// ```
+    pub fn farewell(&mut self, message: &str, art: &str, time: Duration) {
+        if self.active {
+            self.show(message, time);
+            self.display_art(art, time);
+            self.close(true);
+        }
+    }
+
+    /// Returns the lines the given message would be wrapped into with the current window size, font size, and split strategy,
+    /// without displaying anything. Useful for paginating long text or checking how a message will fit.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let lines: Vec<String> = term.preview_wrap("How many lines will this message take up?");
+    /// println!("{} lines", lines.len());
+    /// ```
+    pub fn preview_wrap(&self, message: &str) -> Vec<String> {
+        wrap_spans(&[Span::plain(message)], self.get_max_message_characters(), self.split_strategy).0
+    }
+
+    /// Closes the current window and creates a new one with the given (x, y) Size.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.resize((800, 600).into());
+    /// ```
+    pub fn resize(&mut self, new_size: Size) {
+        if self.active { self.screen.reopen(new_size); }
+    }
+
+    /// Loads a new font from the given font filename and sets the given font size
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_font("LeagueSpartan-Regular.ttf", 24);
+    /// ```
+    pub fn set_font(&mut self, font: &str, size: FontSize) {
+        if self.active {
+            if self.loaded_font == self.font { self.switch_font(font); }
+            self.font = String::from(font);
+            self.font_size = size;
+        }
+    }
+
+    /// Loads a new art font from the given font filename and sets the given font size.
+    /// You probably want to use a mono-space font here, and a small size.
+    /// 
+    /// The default is LeagueMono-Regular.ttf at 10pt.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_art_font("LeagueMono-Regular.ttf", 10);
+    /// ```
+    pub fn set_art_font(&mut self, font: &str, size: FontSize) {
+        if self.active {
+            if self.loaded_font == self.art_font { self.switch_font(font); }
+            self.art_font = String::from(font);
+            self.art_font_size = size;
+        }
+    }
+
+    /// Registers an art font under the given name so `display_art_with` can use it,
+    /// e.g. a blocky font for logos alongside a fine font for detailed images.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.add_art_font("blocky", "LeagueMono-Regular.ttf", 16);
+    /// ```
+    pub fn add_art_font(&mut self, name: &str, font: &str, size: FontSize) {
+        self.art_fonts.insert(String::from(name), (String::from(font), size));
+    }
+
+    /// Adds an informational message below what's already on screen, tagged and colored by [Level::Info](../text/enum.Level.html).
+    /// Log messages appear immediately instead of being typed out, and don't wait for the user to continue,
+    /// so a script can report progress while it works.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.info("Connecting to server...");
+    /// term.warn("Connection is slow");
+    /// term.error("Connection lost");
+    /// term.success("Reconnected");
+    /// ```
+    pub fn info(&mut self, message: &str) {
+        self.log(Level::Info, message);
+    }
+
+    /// Adds a warning below what's already on screen. See `info`.
+    pub fn warn(&mut self, message: &str) {
+        self.log(Level::Warn, message);
+    }
+
+    /// Adds an error below what's already on screen. See `info`.
+    pub fn error(&mut self, message: &str) {
+        self.log(Level::Error, message);
+    }
+
+    /// Adds a success message below what's already on screen. See `info`.
+    pub fn success(&mut self, message: &str) {
+        self.log(Level::Success, message);
+    }
+
+    /// Adds a message below what's already on screen, like a teletype. A carriage return goes back to the start of the line,
+    /// and a message ending in one is rewritten in place by the next message printed, which is handy for counters and spinners.
+    /// As in every message, tabs line up on tab stops, a bell character flashes the screen, and a form feed clears it.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// for percent in 0..=100 {
+    ///     term.print(&format!("Downloading... {}%\r", percent));
+    /// }
+    /// term.print("\nDownload complete!");
+    /// ```
+    pub fn print(&mut self, message: &str) {
+        if self.active {
+            self.use_text_font();
+            self.append_message(&[Span::plain(message)]);
+        }
+    }
+
+    /// Adds a message at the given level below what's already on screen, prefixed with the level's tag and drawn in its color.
+    pub fn log(&mut self, level: Level, message: &str) {
+        if self.active {
+            self.use_text_font();
+            self.append_message(&[Span::colored(&format!("{} {}", level.tag(), message), level.color())]);
+        }
+    }
+
+    /// Streams everything piped into the program's standard input onto the terminal line by line, scrolling as it fills up.
+    /// Once the input ends, waits for the user to continue so the output stays on screen.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// // Run as `some_command | my_program` to prettify some_command's output.
+    /// term.pipe_stdin();
+    /// ```
+    pub fn pipe_stdin(&mut self) {
+        if self.active {
+            self.use_text_font();
+
+            let (tx, rx) = mpsc::channel();
+            thread::spawn(move || {
+                for line in io::stdin().lock().lines().map_while(Result::ok) {
+                    if tx.send(line).is_err() { break; }
+                }
+            });
+            self.follow_lines(rx);
+
+            self.finish_message(false);
+        }
+    }
+
+    /// Adds each line received from the channel below what's already on screen, until the sender hangs up and every line has been shown.
+    /// Lines are added no faster than [stream_rate](#structfield.stream_rate) allows and scroll smoothly into place,
+    /// so a busy producer on another thread can't flood the display.
+    /// 
+    /// ```no_run
+    /// # use std::{sync::mpsc, thread, time::Duration};
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let (tx, rx) = mpsc::channel();
+    /// thread::spawn(move || {
+    ///     for i in 0..500 {
+    ///         tx.send(format!("Processed item {}", i)).unwrap();
+    ///     }
+    /// });
+    /// term.stream_rate = 10.0;
+    /// term.stream(rx);
+    /// term.tell("All done!");
+    /// ```
+    pub fn stream(&mut self, rx: Receiver<String>) {
+        if self.active {
+            self.use_text_font();
+            self.input = String::default();
+
+            let rate: f64 = self.stream_rate;
+            let mut waiting: VecDeque<String> = VecDeque::new();
+            let mut connected: bool = true;
+            let mut allowance: f64 = 1.0;
+            let mut scroll: f64 = 0.0;
+
+            let mut last_frame: Instant = Instant::now();
+            let mut active: bool = self.active;
+            while let Some(e) = self.next_event() {
+                e.close(|_| { active = false; });
+                if !active { break; }
+
+                while connected {
+                    match rx.try_recv() {
+                        Ok(line) => waiting.push_back(line),
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => connected = false,
+                    }
+                }
+
+                let now: Instant = Instant::now();
+                let frame_time: f64 = now.duration_since(last_frame).as_secs_f64();
+                last_frame = now;
+
+                allowance = if rate > 0.0 { (allowance + frame_time * rate).min(waiting.len().max(1) as f64) } else { waiting.len() as f64 };
+                while allowance >= 1.0 {
+                    let Some(line) = waiting.pop_front() else { break };
+                    let first_line: usize = self.first_line;
+                    self.push_message(&[Span::plain(&line)]);
+                    scroll += (self.first_line - first_line) as f64;
+                    allowance -= 1.0;
+                }
+
+                scroll *= (-frame_time / SCROLL_TIME).exp();
+                if scroll < 0.01 { scroll = 0.0; }
+                if !connected && waiting.is_empty() && scroll == 0.0 { break; }
+
+                self.render_frame(&e, &FrameState { scroll, ..FrameState::default() });
+            }
+            self.active = active;
+        }
+    }
+
+    /// Rolls the given lines up the terminal like end credits, moving smoothly at the given speed in lines per second,
+    /// then waits for the user to press Enter to continue. Each line is centered across the terminal, and the roll stops
+    /// once the last line reaches the middle of the window, so it can be a final card like "THE END".
+    /// Pressing Enter while the credits roll skips to that card.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.credits(&["Written by", "Ada", "", "Music by", "Grace", "", "", "", "THE END"], 1.5);
+    /// ```
+    pub fn credits(&mut self, lines: &[&str], speed: f64) {
+        if self.active {
+            self.use_text_font();
+            self.message_color = self.fg_color;
+
+            let (lines, _) = wrap_spans(&[Span::plain(&lines.join("\n"))], self.get_max_message_characters(), self.split_strategy);
+            let continue_text: String = String::from("Press Follow to Continue");
+            let keymap: KeyMap = self.keymap.clone();
+            let fast_speed: u32 = self.fast_forward_speed;
+            let mut fast_forward: bool = self.fast_forwarding;
+            let mut skipped: bool = false;
+            let mut ready: bool = false;
+
+            let mut last_frame: Instant = Instant::now();
+            let mut flash_start: Instant = last_frame;
+            let mut traveled: f64 = 0.0;
+            let mut active: bool = self.active;
+            while let Some(e) = self.next_event() {
+                e.close(|_| { active = false; });
+                e.button(|button_args| {
+                    track_fast_forward(&keymap, button_args, &mut fast_forward);
+                    if button_args.state == ButtonState::Press && keymap.is_pressed(&button_args, Action::Continue) {
+                        if skipped { ready = true; } else { skipped = true; }
+                    }
+                });
+                if !active || ready { break; }
+
+                let now: Instant = Instant::now();
+                if self.clock_running() { traveled += scaled_time(now.duration_since(last_frame), fast_forward, fast_speed).as_secs_f64() * speed.max(0.0) * line_height(self.text_size(), self.spacing()); }
+                last_frame = now;
+
+                let win_size: Size = self.win_size();
+                let end: f64 = credits_offset(win_size, lines.len(), self.text_size(), self.spacing(), f64::INFINITY);
+                let top: f64 = if skipped { end } else { credits_offset(win_size, lines.len(), self.text_size(), self.spacing(), traveled) };
+                skipped = top <= end;
+
+                let flash: bool = check_flash(now, &mut flash_start);
+                let input: Option<&str> = if skipped { Some(&continue_text) } else { None };
+                self.render_frame(&e, &FrameState { content: FrameContent::Credits(&lines, top), input, flash, ..FrameState::default() });
+            }
+            self.active = active;
+            self.fast_forwarding = fast_forward;
+        }
+    }
+
+    /// Splits whatever the terminal is showing in two and slides the halves off the window over the given time,
+    /// leaving an empty terminal for the next scene to be typed into. The frame is rendered to a texture and drawn in halves,
+    /// so everything on screen, from the message to the widgets, splits together.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{draw::Split, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell("The doors slide open...");
+    /// term.split_away(Split::Vertical, Duration::from_millis(800));
+    /// term.tell("...revealing the bridge of the ship.");
+    /// ```
+    pub fn split_away(&mut self, split: Split, time: Duration) {
+        if self.active {
+            let art: bool = self.loaded_font == self.art_font;
+            let keymap: KeyMap = self.keymap.clone();
+            let speed: u32 = self.fast_forward_speed;
+            let mut fast_forward: bool = self.fast_forwarding;
+
+            let mut last_frame: Instant = Instant::now();
+            let mut elapsed: Duration = Duration::from_secs(0);
+            let mut active: bool = self.active;
+            while let Some(e) = self.next_event() {
+                e.close(|_| { active = false; });
+                e.button(|button_args| track_fast_forward(&keymap, button_args, &mut fast_forward));
+
+                let now: Instant = Instant::now();
+                if self.clock_running() { elapsed += scaled_time(now.duration_since(last_frame), fast_forward, speed); }
+                last_frame = now;
+                if elapsed >= time || !active { break; }
+
+                let content: FrameContent = if art { FrameContent::Art } else { FrameContent::Message };
+                let progress: f64 = (elapsed.as_secs_f64() / time.as_secs_f64()).powi(2);
+                self.render_frame(&e, &FrameState { content, split: Some((split, progress)), ..FrameState::default() });
+            }
+            self.active = active;
+            self.fast_forwarding = fast_forward;
+
+            self.message = Vec::new();
+            self.message_styles = Vec::new();
+            self.last_text = Vec::new();
+            self.reflow_source = None;
+            self.input = String::default();
+        }
+    }
+
+    /// Follows a growing file like `tail -f`, first showing its last lines and then each new line as it's written.
+    /// Each line is colored by the first rule whose pattern matches it. This keeps going until the window is closed.
+    /// 
+    /// ```no_run
+    /// # use std::path::Path;
+    /// # use piston_window::types::Color;
+    /// # use regex::Regex;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let rules: Vec<(Regex, Color)> = vec![(Regex::new("ERROR").unwrap(), CRIMSON), (Regex::new("WARN").unwrap(), GOLD)];
+    /// term.tail(Path::new("server.log"), &rules).unwrap();
+    /// ```
+    pub fn tail(&mut self, path: &Path, rules: &[(Regex, Color)]) -> io::Result<()> {
+        if self.active {
+            self.use_text_font();
+
+            let mut file: File = File::open(path)?;
+            let mut position: u64 = 0;
+            let mut partial: String = String::new();
+            self.message = Vec::new();
+            self.message_styles = Vec::new();
+            self.first_line = 1;
+            self.input = String::default();
+
+            while self.active {
+                let length: u64 = file.metadata()?.len();
+                if length < position {
+                    position = 0;
+                    partial.clear();
+                }
+
+                if length > position {
+                    let mut added: Vec<u8> = Vec::new();
+                    file.seek(SeekFrom::Start(position))?;
+                    position += file.read_to_end(&mut added)? as u64;
+                    partial.push_str(&String::from_utf8_lossy(&added));
+
+                    while let Some(end) = partial.find('\n') {
+                        let line: String = partial[..end].trim_end_matches('\r').to_string();
+                        partial.drain(..=end);
+                        let color: Color = line_color(&line, rules).unwrap_or(self.message_color);
+                        self.push_message(&[Span::colored(&line, color)]);
+                    }
+                }
+
+                self.wait_for_timer(POLL_TIME);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a rule that draws every match of the pattern in the given color wherever text is shown, so things like
+    /// log levels, IP addresses, or keywords stand out. Earlier rules win where matches overlap.
+    /// 
+    /// ```no_run
+    /// # use regex::Regex;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.add_highlight(Regex::new(r"\bERROR\b").unwrap(), CRIMSON);
+    /// term.add_highlight(Regex::new(r"\d+(\.\d+){3}").unwrap(), LIGHT_BLUE);
+    /// term.tell("ERROR: could not reach 10.0.0.1");
+    /// ```
+    pub fn add_highlight(&mut self, pattern: Regex, color: Color) {
+        self.highlights.push((pattern, color));
+    }
+
+    /// Removes every highlight rule.
+    pub fn clear_highlights(&mut self) {
+        self.highlights.clear();
+    }
+
+    /// Returns the text the user last selected by holding Shift and using the arrow keys while a message was shown,
+    /// ready to be copied to the clipboard.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell("Select part of this message, then continue.");
+    /// let selected: String = term.selected_text().to_string();
+    /// ```
+    pub fn selected_text(&self) -> &str {
+        &self.selected_text
+    }
+
+    /// Adds a widget to the panel in the corner of the terminal. The panel only appears once a widget is added,
+    /// and every widget's value is refreshed each frame.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{text::*, widgets::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.add_widget(Box::new(Clock));
+    /// term.add_widget(Box::new(CpuUsage::default()));
+    /// term.widget_corner = Corner::BottomRight;
+    /// ```
+    pub fn add_widget(&mut self, widget: Box<dyn Widget>) {
+        self.widgets.push(widget);
+    }
+
+    /// Removes every widget, hiding the widget panel.
+    pub fn clear_widgets(&mut self) {
+        self.widgets.clear();
+    }
+
+    /// Pins a short label at the given anchor, where it stays on every frame apart from the messages, until it's unpinned.
+    /// Pinning again with the same name changes that label's anchor and text, so labels like a score can be updated in place.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{draw::Anchor, text::*, widgets::Corner};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.pin("score", Corner::TopRight, "Score: 10");
+    /// term.pin("hint", Anchor::Cell(0, 4), "(try the north door)");
+    /// term.tell("You found a coin!");
+    /// term.pin("score", Corner::TopRight, "Score: 11");
+    /// ```
+    pub fn pin<A: Into<Anchor>>(&mut self, name: &str, anchor: A, label: &str) {
+        let pin: (String, Anchor, String) = (String::from(name), anchor.into(), String::from(label));
+        match self.pins.iter_mut().find(|(pinned, _, _)| pinned == name) {
+            Some(existing) => *existing = pin,
+            None => self.pins.push(pin),
+        }
+    }
+
+    /// Removes the label pinned with the given name, if there is one.
+    pub fn unpin(&mut self, name: &str) {
+        self.pins.retain(|(pinned, _, _)| pinned != name);
+    }
+
+    /// Removes every pinned label.
+    pub fn clear_pins(&mut self) {
+        self.pins.clear();
+    }
+
+    /// Returns timing statistics for the frames drawn so far, for measuring how much effects and fonts cost.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{stats::FrameStats, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.reset_frame_stats();
+    /// term.tell("How long does this take to draw?");
+    /// let stats: &FrameStats = term.frame_stats();
+    /// println!("{} frames, {:?} average, {:?} p99, {:.0} glyphs per frame", stats.frames(), stats.average(), stats.percentile(0.99), stats.glyphs_per_frame());
+    /// ```
+    pub fn frame_stats(&self) -> &FrameStats {
+        &self.frame_stats
+    }
+
+    /// Forgets the frames recorded so far, so the next scene can be measured on its own.
+    pub fn reset_frame_stats(&mut self) {
+        self.frame_stats.reset();
+    }
+
+    /// Calls the given function with the number of any function key, from 1 for F1 up to 12 for F12, pressed while the terminal is asking a question.
+    /// The question stays open, so a prompt can offer shortcuts alongside typed answers. This replaces any function given before.
+    /// 
+    /// ```no_run
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use simpleterm_profile::{answer::Answer, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let rename: Rc<Cell<bool>> = Rc::new(Cell::new(false));
+    /// let pressed: Rc<Cell<bool>> = rename.clone();
+    /// term.on_function_key(move |number| if number == 2 { pressed.set(true); });
+    /// let answer: Answer = term.ask("Which file? (press F2 to rename it afterward)");
+    /// if rename.get() { term.tell("We'll rename it next."); }
+    /// ```
+    pub fn on_function_key<F: FnMut(u8) + 'static>(&mut self, callback: F) {
+        self.function_key_callback = Some(Box::new(callback));
+    }
+
+    /// Fades out whatever the terminal is showing if outro is true, then closes the window.
+    /// The terminal is inactive afterwards, so every method returns right away and a script's main loop will end.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// if term.ask("Quit? (y/n)").as_deref() == Some("y") {
+    ///     term.close(true);
+    /// }
+    /// ```
+    pub fn close(&mut self, outro: bool) {
+        if self.active {
+            if outro { self.fade_out(); }
+
+            self.screen.close();
+            self.active = false;
+        }
+    }
+
+    /// Returns a handle that can close the terminal while it's busy, like from a function key callback or a background thread.
+    /// Whatever the terminal was waiting on ends as if the window had been closed.
+    /// 
+    /// ```no_run
+    /// # use std::{thread, time::Duration};
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::{CloseHandle, Terminal};
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let handle: CloseHandle = term.close_handle();
+    /// thread::spawn(move || {
+    ///     thread::sleep(Duration::from_secs(60));
+    ///     handle.close();
+    /// });
+    /// term.ask("You have one minute to answer.");
+    /// ```
+    pub fn close_handle(&self) -> CloseHandle {
+        self.close_handle.clone()
+    }
+
+    /// Shows a progress bar with the given label along the bottom of the terminal, and returns a handle for filling it in.
+    /// The handle can be sent to another thread doing long work, and the bar keeps drawing under whatever the terminal shows
+    /// until the handle is finished. Starting another progress bar or a [spinner](#method.spinner) replaces this one.
+    /// 
+    /// ```no_run
+    /// # use std::{thread, time::Duration};
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::{ProgressHandle, Terminal};
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let handle: ProgressHandle = term.progress("Downloading");
+    /// let worker: ProgressHandle = handle.clone();
+    /// thread::spawn(move || {
+    ///     for step in 1..=100 {
+    ///         thread::sleep(Duration::from_millis(50));
+    ///         worker.set(step as f64 / 100.0);
+    ///     }
+    ///     worker.finish();
+    /// });
+    /// term.tell("Feel free to read this while the download runs.");
+    /// term.wait_for_progress(&handle);
+    /// ```
+    pub fn progress(&mut self, label: &str) -> ProgressHandle {
+        let handle: ProgressHandle = ProgressHandle::default();
+        self.spinner = None;
+        self.progress_bar = Some((String::from(label), handle.clone()));
+        handle
+    }
+
+    /// Keeps showing the current screen until the given progress bar is finished or the window is closed.
+    pub fn wait_for_progress(&mut self, handle: &ProgressHandle) {
+        while self.active && !handle.is_finished() {
+            self.wait_for_timer(POLL_TIME);
+        }
+    }
+
+    /// Shows the given message with a spinning `|/-\\` next to it along the bottom of the terminal, and returns a handle for stopping it.
+    /// Unlike [show](#method.show), it doesn't need to know how long the work will take: the spinner keeps turning under whatever
+    /// the terminal shows until the handle is stopped, which can happen on any thread. It takes the place of any progress bar, and
+    /// starting a progress bar takes its place.
+    /// 
+    /// ```no_run
+    /// # use std::{thread, time::Duration};
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::{SpinnerHandle, Terminal};
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let handle: SpinnerHandle = term.spinner("Connecting");
+    /// let worker: SpinnerHandle = handle.clone();
+    /// thread::spawn(move || {
+    ///     thread::sleep(Duration::from_secs(3));
+    ///     worker.stop();
+    /// });
+    /// term.wait_for_spinner(&handle);
+    /// term.tell("Connected!");
+    /// ```
+    pub fn spinner(&mut self, message: &str) -> SpinnerHandle {
+        let handle: SpinnerHandle = SpinnerHandle::default();
+        self.progress_bar = None;
+        self.spinner = Some((String::from(message), handle.clone()));
+        handle
+    }
+
+    /// Keeps showing the current screen until the given spinner is stopped or the window is closed.
+    pub fn wait_for_spinner(&mut self, handle: &SpinnerHandle) {
+        while self.active && !handle.is_stopped() {
+            self.wait_for_timer(POLL_TIME);
+        }
+    }
+
+    /// Returns true if the window had focus the last time the terminal drew.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Reads every event from the given source instead of the window, so tests and fuzzers can push any events they like,
+    /// like unusual text, huge pastes, or rapid resizes, through every part of the terminal. Resize events from the source resize the window,
+    /// and only render events from the source draw frames. Once the source runs out, the terminal closes as if the window had been closed.
+    /// This replaces any source given before.
+    /// 
+    /// ```no_run
+    /// # use piston_window::Key;
+    /// # use simpleterm_profile::{input::ScriptedInput, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.type_time = std::time::Duration::ZERO;
+    /// term.set_input_source(ScriptedInput::new().frames(2).text(&"\u{1F600}".repeat(10_000)).resize(120.0, 80.0).frames(2).press(Key::Return));
+    /// let answer = term.ask("Anything at all?");
+    /// assert_eq!(answer.text().map(|text| text.chars().count()), Some(10_000));
+    /// ```
+    pub fn set_input_source<S: InputSource + 'static>(&mut self, source: S) {
+        self.input_source = Some(Box::new(source));
+    }
+
+    /// Goes back to reading events from the window.
+    pub fn clear_input_source(&mut self) {
+        self.input_source = None;
+    }
+
+    /// Turns on typing sounds through the default audio output: a click for each character typed and a chime whenever a message
+    /// finishes and waits to be continued, like after [tell](#method.tell), [say](#method.say), or [chat](#method.chat). Change the sounds through [sounds](#structfield.sounds), and how loud they are with [volume](#structfield.volume).
+    /// Returns an error if there's no audio output to play them on.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{audio::Sound, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// if term.enable_sounds().is_ok() {
+    ///     term.volume = 0.5;
+    ///     if let (Some(sounds), Ok(click)) = (term.sounds.as_mut(), Sound::open("typewriter.wav")) { sounds.click = click; }
+    /// }
+    /// term.tell("Clack clack clack... ding!");
+    /// ```
+    #[cfg(feature = "audio")]
+    pub fn enable_sounds(&mut self) -> Result<(), Box<dyn Error>> {
+        self.sounds = Some(TypeSounds::new()?);
+        Ok(())
+    }
+
+    /// Calls the given function with true whenever the window gains focus, and false whenever it loses focus.
+    /// This replaces any function given before.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.pause_unfocused = true;
+    /// term.on_focus_changed(|focused| println!("{}", if focused { "Welcome back!" } else { "Paused." }));
+    /// ```
+    pub fn on_focus_changed<F: FnMut(bool) + 'static>(&mut self, callback: F) {
+        self.focus_callback = Some(Box::new(callback));
+    }
+
+    /// Calls the given function with each character of a message as it's typed out, along with its index in the message,
+    /// so sounds, lights, or anything else can keep time with the typing. Spaces are included, and line breaks aren't counted.
+    /// Characters typed in the same frame are passed one after another. This replaces any function given before.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.on_type(|c, index| if !c.is_whitespace() { println!("click {}", index); });
+    /// term.tell("Every letter of this message clicks.");
+    /// ```
+    pub fn on_type<F: FnMut(char, usize) + 'static>(&mut self, callback: F) {
+        self.type_callback = Some(Box::new(callback));
+    }
+
+    /// Draws one frame of the terminal for the given event, with everything outside the terminal's settings described by state.
+    /// Every built-in mode draws through this, so custom loops look the same as the rest of the terminal.
+    /// Returns true if a frame was drawn, which only happens for render events. Focus events are tracked here too,
+    /// so custom loops should pass every event through.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::{FrameContent, FrameState, Terminal};
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.tell("Watch the input line count up.");
+    /// let mut count: u64 = 0;
+    /// while let Some(e) = term.screen.next_event() {
+    ///     let input: String = count.to_string();
+    ///     if term.render_frame(&e, &FrameState { input: Some(&input), input_marker: true, ..FrameState::default() }) { count += 1; }
+    ///     if count == 300 { break; }
+    /// }
+    /// ```
+    pub fn render_frame(&mut self, event: &Event, state: &FrameState) -> bool {
+        self.track_focus(event);
+        let blurred: bool = !self.focused && self.dim_unfocused;
+        let blink_on: bool = state.flash || blurred;
+        let fade: f32 = if blurred { state.fade.max(UNFOCUSED_FADE) } else { state.fade };
+
+        let win_size: Size = self.win_size();
+
+        let scrolled_view: Option<(Vec<String>, Vec<Vec<Style>>)> = match state.content {
+            FrameContent::Message if self.scrolled_back > 0 => Some(self.scrollback_view()),
+            _ => None,
+        };
+        let (shown, shown_styles): (&[String], &[Vec<Style>]) = match &scrolled_view {
+            Some((lines, styles)) => (lines, styles),
+            None => (&self.message, &self.message_styles),
+        };
+        let highlighted: Vec<Vec<Style>> = highlight(shown, shown_styles, &self.highlights);
+        let mut typed: Vec<String>;
+        let selected: Vec<Vec<Style>>;
+        let (art, message, styles): (bool, &[String], &[Vec<Style>]) = match state.content {
+            FrameContent::Message => (false, shown, &highlighted),
+            FrameContent::Typing(typed_chars) => {
+                typed = typed_lines(&self.message, typed_chars);
+                if let Some(last) = typed.last_mut() { last.push_str("[]"); }
+                (false, &typed, &highlighted)
+            },
+            FrameContent::Selecting(selection) => {
+                selected = selection.apply(&self.message, &highlighted);
+                (false, &self.message, &selected)
+            },
+            FrameContent::Art => (true, &self.message, &[]),
+            FrameContent::ArtLines(lines) => (true, lines, &[]),
+            FrameContent::StyledArt(lines, art_styles) => (true, lines, art_styles),
+            FrameContent::Credits(lines, _) => (false, lines, &[]),
+        };
+        let converted: Vec<String>;
+        let message: &[String] = if art && self.cp437_art {
+            converted = message.iter().map(|line| from_cp437_text(line)).collect();
+            &converted
+        } else {
+            message
+        };
+
+        let spacing: f64 = self.spacing();
+        let placed: f64 = placement_offset(win_size, self.message.len(), self.text_size(), spacing, self.placement);
+        let first_line: usize = self.first_line.saturating_sub(self.scrolled_back).max(1);
+        if self.spinner.as_ref().is_some_and(|(_, handle)| handle.is_stopped()) { self.spinner = None; }
+        let spinning: Option<String> = self.spinner.as_ref().map(|(message, _)| {
+            let frame: usize = (self.started.elapsed().as_millis() / SPINNER_TIME.as_millis()) as usize;
+            format!("{} {}", message, SPINNER_FRAMES[frame % SPINNER_FRAMES.len()])
+        });
+        let readings: Vec<(String, String)> = read_widgets(&mut self.widgets);
+        if self.progress_bar.as_ref().is_some_and(|(_, handle)| handle.is_finished()) { self.progress_bar = None; }
+        let pins: Vec<(Anchor, String)> = self.pins.iter().map(|(_, anchor, label)| (*anchor, label.clone())).collect();
+        let progress: Option<(String, f64)> = self.progress_bar.as_ref().map(|(label, handle)| (label.clone(), handle.fraction()));
+        let bell_on: bool = self.bell_rung.is_some_and(|rung| rung.elapsed() < BELL_TIME);
+
+        let content: SceneContent = match state.content {
+            FrameContent::Credits(_, top) => SceneContent::Credits { lines: message, top },
+            _ if art => SceneContent::Art { lines: message, styles, font_size: self.art_font_size },
+            _ => SceneContent::Message {
+                lines: message,
+                styles,
+                offset: placed + state.scroll * line_height(self.text_size(), spacing),
+                gutter: self.gutter.map(|gutter| (gutter, first_line)),
+            },
+        };
+        let scene: Scene = Scene {
+            size: win_size,
+            bg: self.bg_color,
+            fg: self.fg_color,
+            message_color: self.message_color,
+            font: &self.loaded_font,
+            keyboard_font: &self.art_font,
+            font_size: self.text_size(),
+            spacing,
+            content,
+            blink_on,
+            fade,
+            border: self.border_animation,
+            clock: self.started.elapsed(),
+            scanlines: self.scanlines,
+            signal: state.signal,
+            bell: bell_on,
+            chat: if state.chat { Some((&self.chat_log, self.chat_boxes)) } else { None },
+            input_marker: if state.input_marker { Some(state.marker_color.unwrap_or(self.fg_color)) } else { None },
+            input: state.input,
+            ghost: state.ghost,
+            keyboard: state.keyboard,
+            pins: &pins,
+            widgets: &readings,
+            widget_corner: self.widget_corner,
+            progress: progress.as_ref().map(|(label, fraction)| (label.as_str(), *fraction)),
+            spinner: spinning.as_deref(),
+            help: state.help,
+            split: state.split,
+        };
+
+        let drawing: Instant = Instant::now();
+        let drawn: bool = self.screen.draw(event, &scene);
+        if drawn { self.frame_stats.record(drawing.elapsed(), take_glyph_count()); }
+        drawn
+    }
+
+    /// Starts composing a frame. Nothing changes on screen until [draw](struct.Frame.html#method.draw) presents everything at once.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{art::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// for (i, dance) in [DANCE_1, DANCE_2, DANCE_3].iter().enumerate() {
+    ///     term.frame().art(dance).status(&format!("Move {} of 3", i + 1)).draw();
+    /// }
+    /// term.frame().message("That's all the moves.").status("Thanks for watching!").draw();
+    /// ```
+    pub fn frame(&mut self) -> Frame<'_> {
+        Frame { term: self, body: FrameBody::Current, status: None }
+    }
+
+    /// Adds a command to the queue for the non-blocking mode. Nothing happens until [update](#method.update) is called.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{command::Command, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.enqueue(Command::Show(String::from("Downloading..."), Duration::from_secs(1)));
+    /// term.enqueue(Command::Ask(String::from("Where should it go?")));
+    /// ```
+    pub fn enqueue(&mut self, command: Command) {
+        self.commands.push_back(command);
+    }
+
+    /// Returns true if every queued command has finished.
+    pub fn is_idle(&self) -> bool {
+        self.running.is_none() && self.commands.is_empty()
+    }
+
+    /// Handles the window's next event and draws when it's time, moving the queued commands along a little without waiting for any of them,
+    /// so a program can drive the terminal from its own loop between other work. Returns the answer when a queued Ask finishes,
+    /// including Answer::WindowClosed if the window closes first. Fast forward, the attract animation, and paging aren't part of this mode;
+    /// a message too long for the window shows only its last page.
+    /// 
+    /// It doesn't wait for events either. If the window has nothing new and no frame is due yet, it returns None straight away,
+    /// so a loop with nothing else to do should sleep a little between calls.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{answer::Answer, command::Command, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.enqueue(Command::Ask(String::from("What's your name?")));
+    /// let mut ticks: u64 = 0;
+    /// while term.active {
+    ///     ticks += 1;
+    ///     if let Some(Answer::Text(name)) = term.update() {
+    ///         term.enqueue(Command::Tell(format!("Hello {}, that took {} ticks.", name, ticks)));
+    ///     }
+    /// }
+    /// ```
+    pub fn update(&mut self) -> Option<Answer> {
+        if !self.active { return None; }
+        if self.running.is_none() { self.start_command(); }
+
+        let asking: bool = self.running.as_ref().is_some_and(|running| matches!(running.command, Command::Ask(_)));
+        let allow_back: bool = mem::replace(&mut self.allow_back, asking);
+        let next: Option<Event> = self.read_event(false);
+        self.allow_back = allow_back;
+        let e: Event = match next {
+            Some(e) if e.close_args().is_none() => e,
+            Some(_) => {
+                self.active = false;
+                return self.end_command(Answer::WindowClosed);
+            },
+            None => return None,
+        };
+        let mut running: RunningCommand = match self.running.take() {
+            Some(running) => running,
+            None => {
+                self.render_frame(&e, &FrameState::default());
+                return None;
+            },
+        };
+
+        let now: Instant = Instant::now();
+        let total_chars: usize = self.message.iter().map(|line| line.chars().count()).sum();
+        let typed_chars: usize = match self.type_time.as_nanos() {
+            0 => total_chars,
+            nanos => (now.duration_since(running.started).as_nanos() / nanos) as usize,
+        };
+        if running.typed_at.is_none() && (!running.command.types() || typed_chars >= total_chars) { running.typed_at = Some(now); }
+        if running.command.types() {
+            let typed: usize = typed_chars.min(total_chars);
+            self.announce_typed(running.announced, typed);
+            running.announced = running.announced.max(typed);
+        }
+
+        let keymap: &KeyMap = &self.keymap;
+        let pressed = |action: Action| e.button_args().is_some_and(|args| args.state == ButtonState::Press && keymap.is_pressed(&args, action));
+        let mut answer: Option<Answer> = None;
+        let mut done: bool = false;
+        match (&running.command, running.typed_at) {
+            (Command::Tell(_), Some(_)) => done = pressed(Action::Continue),
+            (Command::Show(_, time), Some(typed_at)) | (Command::DisplayArt(_, time), Some(typed_at)) => done = now.duration_since(typed_at) > *time,
+            (Command::Ask(_), Some(_)) => {
+                e.text(|text| running.input.push_str(text));
+                if pressed(Action::Backspace) { running.input.pop(); }
+                if pressed(Action::Submit) && !running.input.is_empty() { answer = Some(Answer::Text(running.input.clone())); }
+                if pressed(Action::Back) { answer = Some(Answer::Cancelled); }
+            },
+            _ => {},
+        }
+
+        let flash: bool = check_flash(now, &mut running.flash_start);
+        let shown_input: String = match &running.command {
+            Command::Ask(_) if flash => format!("{}[]", running.input),
+            Command::Ask(_) => running.input.clone(),
+            Command::Tell(_) if flash && running.typed_at.is_some() => String::from("Press Follow to Continue"),
+            _ => String::default(),
+        };
+        let content: FrameContent = match running.command {
+            Command::DisplayArt(_, _) => FrameContent::Art,
+            _ if running.typed_at.is_none() => FrameContent::Typing(typed_chars),
+            _ => FrameContent::Message,
+        };
+        let waiting: bool = running.typed_at.is_some() && matches!(running.command, Command::Tell(_) | Command::Ask(_));
+        self.render_frame(&e, &FrameState { content, input: Some(&shown_input), input_marker: waiting, flash, ..FrameState::default() });
+
+        if let Some(Answer::Text(input)) = &answer {
+            self.input = input.clone();
+            self.remember_input();
+        }
+        if answer.is_none() && !done { self.running = Some(running); }
+        answer
+    }
+
+    /// Saves the terminal's colors, fonts, font sizes, line spacing, speeds, scanlines, chat boxes, and border animation,
+    /// so a scene can change any of them and then put everything back with [pop_style](#method.pop_style).
+    /// Saved styles stack, so scenes can be nested.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.push_style();
+    /// term.set_colors(OFF_WHITE, CRIMSON);
+    /// term.scanlines = false;
+    /// term.tell("WARNING: REACTOR CRITICAL");
+    /// term.pop_style();
+    /// term.tell("Back to normal.");
+    /// ```
+    pub fn push_style(&mut self) {
+        self.style_stack.push(SavedStyle {
+            bg_color: self.bg_color,
+            fg_color: self.fg_color,
+            message_color: self.message_color,
+            font: self.font.clone(),
+            font_size: self.font_size,
+            line_spacing: self.line_spacing,
+            art_font: self.art_font.clone(),
+            art_font_size: self.art_font_size,
+            scanlines: self.scanlines,
+            chat_boxes: self.chat_boxes,
+            border_animation: self.border_animation,
+            type_time: self.type_time,
+            fast_forward_speed: self.fast_forward_speed,
+            reading_speed: self.reading_speed,
+        });
+    }
+
+    /// Restores the style saved by the most recent [push_style](#method.push_style), reloading fonts if they changed.
+    /// Returns false if there was no saved style to restore.
+    pub fn pop_style(&mut self) -> bool {
+        let saved: SavedStyle = match self.style_stack.pop() {
+            Some(saved) => saved,
+            None => return false,
+        };
+
+        if saved.font != self.font || saved.font_size != self.font_size { self.set_font(&saved.font, saved.font_size); }
+        if saved.art_font != self.art_font || saved.art_font_size != self.art_font_size { self.set_art_font(&saved.art_font, saved.art_font_size); }
+        self.set_colors(saved.bg_color, saved.fg_color);
+        self.message_color = saved.message_color;
+        self.line_spacing = saved.line_spacing;
+        self.scanlines = saved.scanlines;
+        self.chat_boxes = saved.chat_boxes;
+        self.border_animation = saved.border_animation;
+        self.type_time = saved.type_time;
+        self.fast_forward_speed = saved.fast_forward_speed;
+        self.reading_speed = saved.reading_speed;
+        true
+    }
+
+    /// Changes the terminal's background and foreground to the given colors. The change will be apparent in the next text command.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_colors(DARK_GREY, CRIMSON);
+    /// ```
+    pub fn set_colors(&mut self, bgc: Color, fgc: Color) {
+        self.bg_color = bgc;
+        self.fg_color = fgc;
+    }
+
+    /// Sets an animation to play whenever the terminal has been waiting on the user for longer than idle_after.
+    /// The animation loops until the user presses any key, then the terminal returns to what it was waiting on.
+    /// 
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use simpleterm_profile::{art::*, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let dance: Animation = Animation::new(&DANCES, Duration::from_millis(200));
+    /// term.set_attract(dance, Duration::from_secs(30));
+    /// term.tell("Walk away for 30 seconds and see what happens!");
+    /// ```
+    pub fn set_attract(&mut self, animation: Animation, idle_after: Duration) {
+        self.attract = Some((animation, idle_after));
+    }
+
+    /// Stops playing an attract animation when the terminal is idle.
+    pub fn clear_attract(&mut self) {
+        self.attract = None;
+    }
+
+    /// Moves the window so its top left corner is at the given (x, y) screen position.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_position(100, 100);
+    /// ```
+    pub fn set_position(&mut self, x: i32, y: i32) {
+        if self.active { self.screen.set_position(x, y); }
+    }
+
+    /// Moves the window to the center of the monitor it is currently on.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.center_on_screen();
+    /// ```
+    pub fn center_on_screen(&mut self) {
+        if self.active {
+            if let Some(bounds) = self.screen.monitor_bounds(None) { self.center_on(bounds); }
+        }
+    }
+
+    /// Returns the number of monitors available to display the terminal on.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// println!("{} monitors connected", term.monitor_count());
+    /// ```
+    pub fn monitor_count(&self) -> usize {
+        self.screen.monitor_count()
+    }
+
+    /// Moves the window to the center of the monitor with the given index. Does nothing if there is no monitor with that index.
+    /// To choose which monitor the window starts on, create the terminal with [new_on_monitor](#method.new_on_monitor) instead.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.move_to_monitor(1);
+    /// ```
+    pub fn move_to_monitor(&mut self, index: usize) {
+        if self.active {
+            if let Some(bounds) = self.screen.monitor_bounds(Some(index)) { self.center_on(bounds); }
+        }
+    }
+
+    /// Makes the window fullscreen on the monitor with the given index, or returns it to a normal window if given None.
+    /// Does nothing if there is no monitor with that index.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_fullscreen(Some(1));
+    /// term.tell("This is fullscreen on the second monitor!");
+    /// term.set_fullscreen(None);
+    /// ```
+    pub fn set_fullscreen(&mut self, monitor: Option<usize>) {
+        if self.active { self.screen.set_fullscreen(monitor); }
+    }
+
+    /// Keeps the window above all other windows if given true, or lets it be covered normally if given false.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_always_on_top(true);
+    /// ```
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        if self.active {
+            self.screen.set_always_on_top(always_on_top);
+            self.always_on_top = always_on_top;
+        }
+    }
+
+    /// Minimizes the window to the taskbar, where the user can bring it back, or [restore](#method.restore) can.
+    /// Windows on Wayland can't be minimized by the program, so they're left as they are.
+    /// 
+    /// ```no_run
+    /// # use std::{thread, time::Duration};
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.minimize();
+    /// thread::sleep(Duration::from_secs(5));
+    /// term.restore();
+    /// ```
+    pub fn minimize(&mut self) {
+        if self.active { self.screen.set_minimized(true); }
+    }
+
+    /// Restores the window after it's been minimized.
+    pub fn restore(&mut self) {
+        if self.active { self.screen.set_minimized(false); }
+    }
+
+    /// Asks the window manager to draw the user's attention to the window without stealing focus,
+    /// by flashing its taskbar entry on Windows, bouncing its dock icon on macOS, or setting its urgency hint on X11.
+    /// The request is cleared once the window is focused.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{answer::Answer, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.request_attention();
+    /// let answer: Answer = term.ask("The build finished! Deploy now?");
+    /// ```
+    pub fn request_attention(&mut self) {
+        if self.active { self.screen.request_attention(); }
+    }
+
+    /// Opts in to remembering the window's size and position between runs.
+    /// If the given file holds a previously saved size and position, the window is moved and resized to match,
+    /// and the window's final size and position are saved back to the file when the terminal is dropped.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.persist_window("window.cfg");
+    /// ```
+    pub fn persist_window<P: AsRef<Path>>(&mut self, path: P) {
+        if self.active {
+            if let Ok(saved) = fs::read_to_string(&path) {
+                let values: Vec<i32> = saved.split_whitespace().filter_map(|value| value.parse().ok()).collect();
+                if let [x, y, width, height] = values[..] {
+                    self.screen.set_size([width.max(1) as u32, height.max(1) as u32].into());
+                    self.screen.set_position(x, y);
+                }
+            }
+
+            self.window_file = Some(path.as_ref().to_path_buf());
+        }
+    }
+
+    /// Saves the window's size and position to the file given to [persist_window](#method.persist_window).
+    /// Does nothing if persist_window hasn't been called, or if the screen can't say where the window is.
+    /// The window is saved again when the terminal is dropped, but an error there can't be reported.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.persist_window("window.cfg");
+    /// if let Err(e) = term.save_window() { term.tell(&format!("The window's position couldn't be saved: {}", e)); }
+    /// ```
+    pub fn save_window(&self) -> io::Result<()> {
+        match (&self.window_file, self.screen.position()) {
+            (Some(path), Some((x, y))) => {
+                let size: Size = self.screen.size();
+                fs::write(path, format!("{} {} {} {}", x, y, size.width as u32, size.height as u32))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the terminal's current end-user settings.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{settings::Settings, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// let settings: Settings = term.settings();
+    /// term.tell(&format!("Using the {} theme.", settings.theme_name()));
+    /// ```
+    pub fn settings(&self) -> Settings {
+        Settings { font_size: self.font_size, bg_color: self.bg_color, fg_color: self.fg_color, type_time: self.type_time, scanlines: self.scanlines, high_visibility: self.high_visibility }
+    }
+
+    /// Changes the terminal to use the given end-user settings.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::{settings::Settings, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.apply_settings(&Settings { scanlines: false, ..term.settings() });
+    /// ```
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        self.font_size = settings.font_size;
+        self.set_colors(settings.bg_color, settings.fg_color);
+        self.message_color = settings.fg_color;
+        self.type_time = settings.type_time;
+        self.scanlines = settings.scanlines;
+        self.set_high_visibility(settings.high_visibility);
+    }
+
+    /// Turns high visibility mode on or off. While it's on, text is never smaller than
+    /// [HIGH_VISIBILITY_FONT_SIZE](../settings/constant.HIGH_VISIBILITY_FONT_SIZE.html) whatever the font size or theme,
+    /// messages wrap at [HIGH_VISIBILITY_LINE_LENGTH](../settings/constant.HIGH_VISIBILITY_LINE_LENGTH.html) characters,
+    /// and lines are spaced further apart. The font size itself is left alone, so turning it off goes back to the size that was set.
+    /// Terminals start with it on when the [HIGH_VISIBILITY_VAR](../settings/constant.HIGH_VISIBILITY_VAR.html) environment variable is set.
+    /// 
+    /// ```
+    /// # use simpleterm_profile::{backend::Headless, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::with_screen(Box::new(Headless::new((800, 600))), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 24).unwrap();
+    /// term.set_high_visibility(true);
+    /// assert_eq!(term.settings().font_size, 24);
+    ///
+    /// term.set_high_visibility(false);
+    /// assert_eq!(term.font_size, 24);
+    /// ```
+    pub fn set_high_visibility(&mut self, on: bool) {
+        self.high_visibility = on;
+    }
+
+    /// Opts in to remembering the choices made on the settings screen between runs.
+    /// If the given file holds previously saved settings they're applied right away,
+    /// and [open_settings](#method.open_settings) saves back to the file whenever the user leaves the settings screen.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.persist_settings("settings.cfg");
+    /// ```
+    pub fn persist_settings<P: AsRef<Path>>(&mut self, path: P) {
+        if self.active {
+            if let Ok(saved) = fs::read_to_string(&path) {
+                let settings: Settings = Settings::parse(&saved, self.settings());
+                self.apply_settings(&settings);
+            }
+
+            self.settings_file = Some(path.as_ref().to_path_buf());
+        }
+    }
+
+    /// Opts in to remembering answers given to [ask](#method.ask) between runs, like a shell's history file.
+    /// If the given file holds a previously saved history it's loaded right away, so the up and down arrows can recall those answers,
+    /// and the history is saved back to the file, one answer per line, when the terminal is dropped.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.persist_history(".simpleterm_history");
+    /// while let Some(command) = term.ask("$").text() {
+    ///     if command == "exit" { break; }
+    /// }
+    /// ```
+    pub fn persist_history<P: AsRef<Path>>(&mut self, path: P) {
+        if let Ok(saved) = fs::read_to_string(&path) {
+            let mut history: Vec<String> = saved.lines().filter(|line| !line.is_empty()).map(String::from).collect();
+            history.append(&mut self.history);
+            self.history = history;
+            self.trim_history();
+        }
+
+        self.history_file = Some(path.as_ref().to_path_buf());
+    }
+
+    /// Saves the input history to the file given to [persist_history](#method.persist_history), one answer per line.
+    /// Does nothing if persist_history hasn't been called. The history is saved again when the terminal is dropped,
+    /// but an error there can't be reported, so call this first to find out whether saving works.
+    /// 
+    /// ```
+    /// # use std::env;
+    /// # use simpleterm_profile::{backend::Headless, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// let mut term: Terminal = Terminal::with_screen(Box::new(Headless::new((800, 600))), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+    /// assert!(term.save_history().is_ok());
+    /// term.persist_history(env::temp_dir().join("simpleterm_missing_folder").join("history"));
+    /// assert!(term.save_history().is_err());
+    /// ```
+    pub fn save_history(&self) -> io::Result<()> {
+        match &self.history_file {
+            Some(path) => fs::write(path, self.history.iter().map(|answer| format!("{}\n", answer)).collect::<String>()),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the answers given so far, oldest first, including any loaded by [persist_history](#method.persist_history).
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Forgets every answer in the input history.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Sets the answers [ask](#method.ask) can complete with Tab. While typing, the rest of the first one that starts with the answer
+    /// so far is shown dimmed after it, and pressing Tab fills in a match, then cycles through the others that matched the typed text.
+    /// Matching ignores case. Pass an empty slice to turn completion off.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.set_completions(&["help", "quit", "look"]);
+    /// term.ask("What do you do?");
+    /// ```
+    pub fn set_completions(&mut self, completions: &[&str]) {
+        self.completions = completions.iter().map(|completion| String::from(*completion)).collect();
+    }
+
+    /// Shows a settings screen where the user can change the font size, color theme, typing speed, scanlines, and high visibility mode.
+    /// The up and down arrows pick a setting, the left and right arrows change it, and changes show up immediately.
+    /// Leaving the screen saves the settings with [save_settings](#method.save_settings), and returns an error if they couldn't be saved.
+    /// 
+    /// ```no_run
+    /// # use simpleterm_profile::text::*;
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+    /// term.persist_settings("settings.cfg");
+    /// if term.ask("Type 'settings' to change how things look.").as_deref() == Some("settings") {
+    ///     if let Err(e) = term.open_settings() { term.tell(&format!("Your settings couldn't be saved: {}", e)); }
+    /// }
+    /// ```
+    pub fn open_settings(&mut self) -> io::Result<()> {
+        let mut cursor: usize = 0;
+
+        while self.active {
+            self.use_text_font();
+
+            let settings: Settings = self.settings();
+            let rows: [String; 5] = [
+                format!("Font size: {}", settings.font_size),
+                format!("Theme: {}", settings.theme_name()),
+                format!("Typing delay: {}ms", settings.type_time.as_millis()),
+                format!("Scanlines: {}", if settings.scanlines { "On" } else { "Off" }),
+                format!("High visibility: {}", if settings.high_visibility { "On" } else { "Off" }),
+            ];
+
+            self.message = vec![String::from("Settings"), String::default()];
+            self.message_styles = vec![Vec::new(), Vec::new()];
+            for (i, row) in rows.iter().enumerate() {
+                let span: Span = if i == cursor { Span::plain(row).inverse() } else { Span::plain(row) };
+                self.message_styles.push(vec![span.style; row.chars().count()]);
+                self.message.push(span.text);
+            }
+            self.input = String::from("Press Follow to Continue");
+
+            let change: i32 = match self.wait_for_action(&[Action::MoveUp, Action::MoveDown, Action::Decrease, Action::Increase, Action::Continue]) {
+                Some(Action::MoveUp) => { cursor = cursor.saturating_sub(1); 0 },
+                Some(Action::MoveDown) => { cursor = (cursor + 1).min(rows.len() - 1); 0 },
+                Some(Action::Decrease) => -1,
+                Some(Action::Increase) => 1,
+                _ => break,
+            };
+
+            let mut changed: Settings = settings;
+            match cursor {
+                0 => changed.font_size = (settings.font_size as i32 + change * 2).clamp(FONT_SIZE_RANGE.0 as i32, FONT_SIZE_RANGE.1 as i32) as FontSize,
+                1 => if change != 0 { changed.cycle_theme(change); },
+                2 => {
+                    let millis: i64 = settings.type_time.as_millis() as i64 + change as i64 * 5;
+                    changed.type_time = Duration::from_millis(millis.clamp(0, MAX_TYPE_MILLIS as i64) as u64);
+                },
+                3 => if change != 0 { changed.scanlines = !settings.scanlines; },
+                _ => if change != 0 { changed.high_visibility = !settings.high_visibility; },
+            }
+            self.apply_settings(&changed);
+        }
+
+        self.save_settings()
+    }
+
+    /// Saves the terminal's current settings to the file given to [persist_settings](#method.persist_settings).
+    /// Does nothing if persist_settings hasn't been called.
+    /// 
+    /// ```
+    /// # use std::env;
+    /// # use simpleterm_profile::{backend::Headless, text::*};
+    /// # use simpleterm_profile::terminal::Terminal;
+    /// let mut term: Terminal = Terminal::with_screen(Box::new(Headless::new((800, 600))), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+    /// assert!(term.save_settings().is_ok());
+    /// term.persist_settings(env::temp_dir().join("simpleterm_missing_folder").join("settings.cfg"));
+    /// assert!(term.save_settings().is_err());
+    /// ```
+    pub fn save_settings(&self) -> io::Result<()> {
+        match &self.settings_file {
+            Some(path) => fs::write(path, self.settings().to_text()),
+            None => Ok(()),
+        }
+    }
+
+    // Moves the window to the center of the given (x, y, width, height) bounds.
+    fn center_on(&mut self, (x, y, width, height): (f64, f64, f64, f64)) {
+        let win_size: Size = self.screen.size();
+        let new_x: f64 = x + (width - win_size.width) / 2.0;
+        let new_y: f64 = y + (height - win_size.height) / 2.0;
+        self.screen.set_position(new_x as i32, new_y as i32);
+    }
+
+    // Displays an art string along with the rest of the terminal. If fade is true, the terminal fades to the background color over the timer.
+    fn show_art(&mut self, timer: Duration, fade: bool) {
+        let keymap: KeyMap = self.keymap.clone();
+        let speed: u32 = self.fast_forward_speed;
+        let mut fast_forward: bool = self.fast_forwarding;
+
+        let mut last_frame: Instant = Instant::now();
+        let mut elapsed: Duration = Duration::from_secs(0);
+        let mut active: bool = self.active;
+        while let Some(e) = self.next_event() {
+            e.close(|_| { active = false; });
+            e.button(|button_args| track_fast_forward(&keymap, button_args, &mut fast_forward));
+
+            let now: Instant = Instant::now();
+            if self.clock_running() { elapsed += scaled_time(now.duration_since(last_frame), fast_forward, speed); }
+            last_frame = now;
+            if elapsed > timer { break; }
+
+            let fade_alpha: f32 = if fade { (elapsed.as_secs_f32() / timer.as_secs_f32()).min(1.0) } else { 0.0 };
+            self.render_frame(&e, &FrameState { content: FrameContent::Art, fade: fade_alpha, ..FrameState::default() });
+        }
+        self.active = active;
+        self.fast_forwarding = fast_forward;
+    }
+
+    // Starts the next queued command, setting up the message or art it shows. Color changes take effect right away.
+    fn start_command(&mut self) {
+        while let Some(command) = self.commands.pop_front() {
+            match &command {
+                Command::SetColors(bgc, fgc) => {
+                    self.set_colors(*bgc, *fgc);
+                    continue;
+                },
+                Command::DisplayArt(art, _) => {
+                    self.use_art_font();
+                    self.message = art.split('\n').map(String::from).collect();
+                    self.message_styles = Vec::new();
+                },
+                Command::Tell(message) | Command::Show(message, _) | Command::Ask(message) => {
+                    self.use_text_font();
+                    self.message_color = self.fg_color;
+                    let spans: Vec<Span> = self.stamp(&[Span::plain(message)]);
+                    let (mut lines, mut styles) = wrap_spans(&spans, self.get_max_message_characters(), self.split_strategy);
+                    let excess: usize = lines.len().saturating_sub(max_lines(self.win_size().height, self.text_size(), self.spacing()));
+                    lines.drain(..excess);
+                    styles.drain(..excess.min(styles.len()));
+                    self.message = lines;
+                    self.message_styles = styles;
+                    self.first_line = excess + 1;
+                },
+            }
+
+            let now: Instant = Instant::now();
+            self.input = String::default();
+            self.running = Some(RunningCommand { command, started: now, typed_at: None, flash_start: now, input: String::default(), announced: 0 });
+            break;
+        }
+    }
+
+    // Drops the running command, returning the given answer if it was a question.
+    fn end_command(&mut self, answer: Answer) -> Option<Answer> {
+        match self.running.take() {
+            Some(RunningCommand { command: Command::Ask(_), .. }) => Some(answer),
+            _ => None,
+        }
+    }
+
+    // Scrolls back or forward through the scrollback buffer if the event is Page Up, Page Down, or the mouse wheel.
+    fn track_scrolling(&mut self, event: &Event) {
+        let page_lines: usize = max_lines(self.win_size().height, self.text_size(), self.spacing());
+        let lines: i64 = match (event.button_args(), event.mouse_scroll_args()) {
+            (Some(args), _) if args.state == ButtonState::Press && self.keymap.is_pressed(&args, Action::ScrollBack) => page_lines as i64 - 1,
+            (Some(args), _) if args.state == ButtonState::Press && self.keymap.is_pressed(&args, Action::ScrollForward) => 1 - page_lines as i64,
+            (_, Some([_, y])) if y != 0.0 => y.signum() as i64 * WHEEL_LINES as i64,
+            _ => return,
+        };
+        self.scrolled_back = (self.scrolled_back as i64 + lines).clamp(0, self.scrollback_lines.len() as i64) as usize;
+    }
+
+    // Returns the page of lines shown while scrolled back, from the scrollback buffer and the current message, with their styles.
+    fn scrollback_view(&self) -> (Vec<String>, Vec<Vec<Style>>) {
+        let page_lines: usize = max_lines(self.win_size().height, self.text_size(), self.spacing());
+        let total: usize = self.scrollback_lines.len() + self.message.len();
+        let end: usize = total - self.scrolled_back.min(self.scrollback_lines.len());
+        let start: usize = end.saturating_sub(page_lines);
+
+        let lines: Vec<String> = self.scrollback_lines.iter().chain(self.message.iter()).skip(start).take(end - start).cloned().collect();
+        let mut message_styles: Vec<Vec<Style>> = self.message_styles.clone();
+        message_styles.resize(self.message.len(), Vec::new());
+        let styles: Vec<Vec<Style>> = self.scrollback_styles.iter().chain(message_styles.iter()).skip(start).take(end - start).cloned().collect();
+        (lines, styles)
+    }
+
+    // Moves the given number of lines off the top of the message, keeping them in the scrollback buffer if it's turned on.
+    fn scroll_off(&mut self, count: usize) {
+        let count: usize = count.min(self.message.len());
+        self.message_styles.resize(self.message.len(), Vec::new());
+        let lines: Vec<String> = self.message.drain(..count).collect();
+        let styles: Vec<Vec<Style>> = self.message_styles.drain(..count).collect();
+        self.first_line += count;
+
+        if self.scrollback {
+            self.scrollback_lines.extend(lines);
+            self.scrollback_styles.extend(styles);
+            let excess: usize = self.scrollback_lines.len().saturating_sub(SCROLLBACK_LIMIT);
+            self.scrollback_lines.drain(..excess);
+            self.scrollback_styles.drain(..excess);
+        }
+    }
+
+    // Fades whatever the terminal last drew out to the background color.
+    fn fade_out(&mut self) {
+        let art: bool = self.loaded_font == self.art_font;
+        let started: Instant = Instant::now();
+        let mut active: bool = self.active;
+        while let Some(e) = self.next_event() {
+            e.close(|_| { active = false; });
+
+            let elapsed: Duration = started.elapsed();
+            if elapsed > FADE_TIME || !active { break; }
+
+            let content: FrameContent = if art { FrameContent::Art } else { FrameContent::Message };
+            let fade: f32 = elapsed.as_secs_f32() / FADE_TIME.as_secs_f32();
+            self.render_frame(&e, &FrameState { content, fade, ..FrameState::default() });
+        }
+        self.active = active;
+    }
+
+    // Returns the window's next event, with extra presses added for a held key that repeats.
+    // Repeats sent by the operating system are dropped, so keys repeat at the same rate everywhere.
+    // Pressing Quit closes the window, unless Back is allowed right now and the same key goes back.
+    fn next_event(&mut self) -> Option<Event> {
+        self.read_event(true)
+    }
+
+    // Returns the next event like next_event if wait is true. Otherwise returns None straight away if the screen has no event ready,
+    // and a close event once the window has closed.
+    fn read_event(&mut self, wait: bool) -> Option<Event> {
+        loop {
+            if self.close_handle.requested.swap(false, Ordering::Relaxed) {
+                self.screen.close();
+                return Some(Event::Input(Input::Close(CloseArgs), None));
+            }
+            if let (Some(repeat), Some(held)) = (self.key_repeat, self.held_key.as_mut()) {
+                if repeat.repeats_after(held.pressed.elapsed()) > held.repeats {
+                    held.repeats += 1;
+                    return Some(Event::Input(Input::Button(held.button), None));
                 }
-                
-                draw_foreground(win_size, bgc, use_filter, c, g);
-            
-                glyphs.factory.encoder.flush(device);
-            });
+            }
 
-            if input_accepted { break; }
+            let event: Event = match self.input_source.as_mut() {
+                Some(source) => match source.next_event() {
+                    Some(event) => event,
+                    None => {
+                        self.screen.close();
+                        return Some(Event::Input(Input::Close(CloseArgs), None));
+                    },
+                },
+                None if wait => self.screen.next_event()?,
+                None => self.screen.poll_event()?,
+            };
+            if let (Some(resize), true) = (event.resize_args(), self.input_source.is_some()) { self.screen.set_size(resize.window_size.into()); }
+            if event.focus_args() == Some(false) { self.held_key = None; }
+            if self.scrollback { self.track_scrolling(&event); }
+            if event.resize_args().is_some() { self.reflow(); }
+            if let Some(button_args) = event.button_args() {
+                let back: bool = self.allow_back && self.keymap.is_pressed(&button_args, Action::Back);
+                if button_args.state == ButtonState::Press && !back && self.keymap.is_pressed(&button_args, Action::Quit) {
+                    self.screen.close();
+                    return Some(Event::Input(Input::Close(CloseArgs), None));
+                }
+                let held: bool = self.held_key.as_ref().is_some_and(|held| held.button.button == button_args.button);
+                match button_args.state {
+                    ButtonState::Press if held => continue,
+                    ButtonState::Press if self.keymap.repeats(&button_args) => {
+                        self.held_key = Some(HeldKey { button: button_args, pressed: Instant::now(), repeats: 0 });
+                    },
+                    ButtonState::Release if held => self.held_key = None,
+                    _ => {},
+                }
+            }
+            return Some(event);
+        }
+    }
+
+    // Returns the (column, row) of the on-screen keyboard's text under the given point, if any.
+    fn keyboard_cell_at(&mut self, keyboard: &VirtualKeyboard, point: [f64; 2]) -> Option<(usize, usize)> {
+        let (lines, _) = keyboard.lines();
+        let columns: usize = lines.iter().map(|line| display_width(line)).max().unwrap_or(0);
+        let win_size: Size = self.win_size();
+        let font_size: FontSize = self.text_size();
+        let column_width: f64 = self.screen.text_width(&self.art_font, font_size, "0").unwrap_or(font_size as f64 * 0.6);
+        keyboard_cell(win_size, (columns, lines.len()), column_width, font_size, point)
+    }
+
+    // Notes whether the window has focus if the event says it changed, and lets the focus callback know.
+    fn track_focus(&mut self, event: &Event) {
+        if let Some(focused) = event.focus_args() {
+            if focused != self.focused {
+                self.focused = focused;
+                if let Some(callback) = self.focus_callback.as_mut() { callback(focused); }
+            }
+        }
+    }
+
+    // Returns true if timed shows should keep counting down, which they don't while paused for losing focus.
+    fn clock_running(&self) -> bool {
+        self.focused || !self.pause_unfocused
+    }
+
+    // Shows art in the art font for the given amount of time, asking frame_at for the lines and styles to draw as time passes.
+    // If skippable is true, pressing Back stops it early.
+    fn play_art_frames<F: Fn(Duration) -> (Vec<String>, Vec<Vec<Style>>)>(&mut self, time: Duration, skippable: bool, frame_at: F) {
+        if self.active {
+            self.use_art_font();
+            self.input = String::default();
+
+            let keymap: KeyMap = self.keymap.clone();
+            let speed: u32 = self.fast_forward_speed;
+            let mut fast_forward: bool = self.fast_forwarding;
+            let allow_back: bool = mem::replace(&mut self.allow_back, skippable);
+
+            let mut last_frame: Instant = Instant::now();
+            let mut elapsed: Duration = Duration::from_secs(0);
+            let mut skipped: bool = false;
+            let mut active: bool = self.active;
+            while let Some(e) = self.next_event() {
+                e.close(|_| { active = false; });
+                e.button(|button_args| {
+                    track_fast_forward(&keymap, button_args, &mut fast_forward);
+                    if skippable && button_args.state == ButtonState::Press && keymap.is_pressed(&button_args, Action::Back) { skipped = true; }
+                });
+
+                let now: Instant = Instant::now();
+                if self.clock_running() { elapsed += scaled_time(now.duration_since(last_frame), fast_forward, speed); }
+                last_frame = now;
+                if skipped || elapsed > time { break; }
+
+                let (lines, styles) = frame_at(elapsed);
+                self.render_frame(&e, &FrameState { content: FrameContent::StyledArt(&lines, &styles), ..FrameState::default() });
+            }
+            self.active = active;
+            self.fast_forwarding = fast_forward;
+            self.allow_back = allow_back;
+        }
+    }
+
+    // Plays the attract animation until the user presses a key, then switches back to the normal font.
+    fn play_attract(&mut self) {
+        let animation: Animation = match &self.attract {
+            Some((animation, _)) => animation.clone(),
+            None => return,
+        };
+        self.use_art_font();
+
+        let start: Instant = Instant::now();
+        let mut woken: bool = false;
+        let mut active: bool = self.active;
+        while let Some(e) = self.next_event() {
+            e.close(|_| { active = false; });
+            e.button(|button_args| { if button_args.state == ButtonState::Press { woken = true; } });
+
+            if woken { break; }
+
+            let art: Vec<String> = animation.frame_at(Instant::now().duration_since(start)).split('\n').map(String::from).collect();
+            self.render_frame(&e, &FrameState { content: FrameContent::ArtLines(&art), ..FrameState::default() });
         }
         self.active = active;
+
+        self.use_text_font();
     }
 
-    // Displays an the current terminal until the timer runs out.
-    fn wait_for_timer(&mut self, timer: Duration) {
-        let bgc: Color = self.bg_color;
-        let fgc: Color = self.fg_color;
+    // Types a message out, one character every type_time. Characters are added based on how much time has passed,
+    // so the window keeps handling events and drawing frames while a long message is typed.
+    fn type_message(&mut self) {
+        let total_chars: usize = self.message.iter().map(|line| line.chars().count()).sum();
+        let typed_before: usize = mem::take(&mut self.typed_before);
+        let mut announced: usize = typed_before;
+        let current_input: String = self.input.clone();
+
+        let keymap: KeyMap = self.keymap.clone();
+        let speed: u32 = self.fast_forward_speed;
+        let mut fast_forward: bool = self.fast_forwarding;
+        let type_time: Duration = self.type_time;
+
+        let mut last_frame: Instant = Instant::now();
+        let mut elapsed: Duration = Duration::from_secs(0);
+        let mut active: bool = self.active;
+        while let Some(e) = self.next_event() {
+            e.close(|_| { active = false; });
+            e.button(|button_args| track_fast_forward(&keymap, button_args, &mut fast_forward));
+
+            let now: Instant = Instant::now();
+            if self.clock_running() { elapsed += scaled_time(now.duration_since(last_frame), fast_forward, speed); }
+            last_frame = now;
+
+            let typed_chars: usize = if type_time.is_zero() { total_chars } else { typed_before + (elapsed.as_nanos() / type_time.as_nanos()) as usize };
+            if active { self.announce_typed(announced, typed_chars.min(total_chars)); }
+            announced = announced.max(typed_chars);
+            if !active || typed_chars >= total_chars { break; }
+
+            self.render_frame(&e, &FrameState { content: FrameContent::Typing(typed_chars), input: Some(&current_input), ..FrameState::default() });
+        }
+        self.active = active;
+        self.fast_forwarding = fast_forward;
+    }
+
+    // Types out the current message in step with its Morse code, lighting the border while each dot and dash is sent.
+    fn send_morse(&mut self) {
+        let timing: MorseTiming = MorseTiming::new(&self.message);
+        let total_chars: usize = timing.char_starts.len();
+        let mut announced: usize = 0;
+        let current_input: String = self.input.clone();
+
+        let keymap: KeyMap = self.keymap.clone();
+        let speed: u32 = self.fast_forward_speed;
+        let mut fast_forward: bool = self.fast_forwarding;
+        let unit: Duration = self.morse_unit;
+
+        let mut last_frame: Instant = Instant::now();
+        let mut elapsed: Duration = Duration::from_secs(0);
+        let mut active: bool = self.active;
+        while let Some(e) = self.next_event() {
+            e.close(|_| { active = false; });
+            e.button(|button_args| track_fast_forward(&keymap, button_args, &mut fast_forward));
 
-        let message: &Vec<String> = &self.message;
-        let glyphs: &mut Glyphs = &mut self.glyphs;
-        let font_size: FontSize = self.font_size;
-        let use_filter: bool = self.scanlines;
+            let now: Instant = Instant::now();
+            if self.clock_running() { elapsed += scaled_time(now.duration_since(last_frame), fast_forward, speed); }
+            last_frame = now;
+
+            let units: f64 = if unit.is_zero() { f64::INFINITY } else { elapsed.as_secs_f64() / unit.as_secs_f64() };
+            let typed_chars: usize = timing.typed(units);
+            if active { self.announce_typed(announced, typed_chars); }
+            announced = typed_chars;
+            if !active || (typed_chars >= total_chars && units >= timing.length as f64) { break; }
+
+            self.render_frame(&e, &FrameState { content: FrameContent::Typing(typed_chars), input: Some(&current_input), signal: timing.lit(units), ..FrameState::default() });
+        }
+        self.active = active;
+        self.fast_forwarding = fast_forward;
+    }
+
+    // Calls the on_type function for each character of the current message from the first index up to the second.
+    // Clicks once if any of them were more than spaces and typing sounds are on.
+    fn announce_typed(&mut self, from: usize, to: usize) {
+        let mut clicked: bool = false;
+        let typed = self.message.iter().flat_map(|line| line.chars()).enumerate().skip(from).take(to.saturating_sub(from));
+        for (i, c) in typed {
+            if let Some(callback) = self.type_callback.as_mut() { callback(c, i); }
+            clicked |= !c.is_whitespace();
+        }
+        if clicked { self.typing_sound(false); }
+    }
+
+    // Plays the chime if finished is true, or the typing click otherwise, if typing sounds are turned on.
+    #[cfg(feature = "audio")]
+    fn typing_sound(&self, finished: bool) {
+        if let Some(sounds) = &self.sounds {
+            sounds.play(if finished { &sounds.chime } else { &sounds.click }, self.volume);
+        }
+    }
+
+    // Typing sounds need the audio feature, so without it there's nothing to play.
+    #[cfg(not(feature = "audio"))]
+    fn typing_sound(&self, _finished: bool) {}
+
+    // Chimes for a finished message, then waits for the user to continue, playing the attract animation whenever the terminal goes idle.
+    // If chat is true, the chat conversation is displayed as well.
+    fn finish_message(&mut self, chat: bool) {
+        self.typing_sound(true);
+        self.input = String::from("Press Follow to Continue");
+        while self.wait_for_continue(chat) { self.play_attract(); }
+    }
+
+    // Displays the current terminal until the user presses Enter. If chat is true, the chat conversation is displayed as well.
+    // Returns true if it stopped early because the terminal went idle and should play the attract animation.
+    fn wait_for_continue(&mut self, chat: bool) -> bool {
+        let mut ready: bool = false;
+        let mut show_help: bool = false;
+        let mut idle: bool = false;
+        let idle_after: Option<Duration> = self.attract.as_ref().map(|(_, idle_after)| *idle_after);
+        let advance_after: Option<Duration> = if self.auto_advance || self.demo.is_some() { Some(self.time_to_read(chat)) } else { None };
+
+        let message: Vec<String> = self.message.clone();
+        let keymap: KeyMap = self.keymap.clone();
+        let help: Vec<(String, String)> = keymap.help(&[Action::Continue, Action::FastForward, Action::Select, Action::Help]);
+        let mut fast_forward: bool = self.fast_forwarding;
+        let mut selecting: bool = false;
+        let mut selection: Option<Selection> = None;
+        let message_end: (usize, usize) = (message.len().saturating_sub(1), message.last().map_or(0, |line| line.chars().count()));
+        let current_input: String = self.input.clone();
+        let bell: Option<Color> = self.bell_color;
+        let mut rung: Option<Instant> = None;
         
-        let start: Instant = Instant::now();
+        let mut start: Instant = Instant::now();
+        let shown: Instant = start;
+        let mut last_input: Instant = start;
+        let mut active: bool = self.active;
+        while let Some(e) = self.next_event() {
+            e.close(|_| { active = false; });
+
+            e.button(|button_args| {
+                last_input = Instant::now();
+                track_fast_forward(&keymap, button_args, &mut fast_forward);
+                if let Button::Keyboard(key) = button_args.button {
+                    if keymap.is_pressed(&button_args, Action::Select) { selecting = button_args.state == ButtonState::Press; }
+                    if button_args.state == ButtonState::Press {
+                        let handled: [Action; 6] = [Action::Continue, Action::FastForward, Action::Select, Action::Help, Action::ScrollBack, Action::ScrollForward];
+                        if !selecting && !handled.iter().any(|action| keymap.is_pressed(&button_args, *action)) && !is_modifier(key) { rung = Some(Instant::now()); }
+                        if keymap.is_pressed(&button_args, Action::Continue) { ready = true; }
+                        if keymap.is_pressed(&button_args, Action::Help) { show_help = !show_help; }
+                        if selecting {
+                            let current: &mut Selection = selection.get_or_insert(Selection::new(message_end));
+                            if keymap.is_pressed(&button_args, Action::SelectLeft) { current.left(&message); }
+                            if keymap.is_pressed(&button_args, Action::SelectRight) { current.right(&message); }
+                            if keymap.is_pressed(&button_args, Action::SelectUp) { current.up(&message); }
+                            if keymap.is_pressed(&button_args, Action::SelectDown) { current.down(&message); }
+                        }
+                    }
+                }
+            });
+
+            if ready { break; }
+
+            let now: Instant = Instant::now();
+            if advance_after.is_some_and(|advance_after| now.duration_since(shown) > advance_after) { break; }
+            if idle_after.is_some_and(|idle_after| now.duration_since(last_input) > idle_after) {
+                idle = true;
+                break;
+            }
+
+            let flash: bool = check_flash(now, &mut start);
+            self.render_frame(&e, &FrameState {
+                content: selection.map_or(FrameContent::Message, FrameContent::Selecting),
+                chat,
+                input: if flash { Some(&current_input) } else { None },
+                input_marker: true,
+                marker_color: bell_marker(bell, rung, now),
+                flash,
+                help: if show_help { Some(&help) } else { None },
+                ..FrameState::default()
+            });
+        }
+        if let Some(selection) = selection { self.selected_text = selection.text(&self.message); }
+        self.active = active;
+        self.fast_forwarding = fast_forward;
+
+        idle && active
+    }
+
+    // Displays the current terminal until the user presses a key bound to one of the given actions, and returns that action.
+    // Returns None if the window is closed first.
+    fn wait_for_action(&mut self, actions: &[Action]) -> Option<Action> {
+        let mut pressed: Option<Action> = None;
+        let mut show_help: bool = false;
+        let allow_back: bool = mem::replace(&mut self.allow_back, actions.contains(&Action::Back));
+
+        let keymap: KeyMap = self.keymap.clone();
+        let help: Vec<(String, String)> = keymap.help(&[actions, &[Action::Help]].concat());
+        let current_input: String = self.input.clone();
+        let bell: Option<Color> = self.bell_color;
+        let mut rung: Option<Instant> = None;
+
+        let mut start: Instant = Instant::now();
         let mut active: bool = self.active;
-        while let Some(e) = self.window.next() {
+        while let Some(e) = self.next_event() {
             e.close(|_| { active = false; });
 
-            let win_size: Size = self.window.window.size();
+            e.button(|button_args| {
+                if let Button::Keyboard(key) = button_args.button {
+                    if button_args.state == ButtonState::Press {
+                        if keymap.is_pressed(&button_args, Action::Help) { show_help = !show_help; }
+                        let action: Option<Action> = actions.iter().copied().find(|action| keymap.is_pressed(&button_args, *action));
+                        if action.is_none() && !keymap.is_pressed(&button_args, Action::Help) && !is_modifier(key) { rung = Some(Instant::now()); }
+                        pressed = pressed.or(action);
+                    }
+                }
+            });
+
+            if pressed.is_some() { break; }
 
             let now: Instant = Instant::now();
-            if now.duration_since(start) > timer { break; }
+            let flash: bool = check_flash(now, &mut start);
+            self.render_frame(&e, &FrameState {
+                input: if flash { Some(&current_input) } else { None },
+                input_marker: true,
+                marker_color: bell_marker(bell, rung, now),
+                flash,
+                help: if show_help { Some(&help) } else { None },
+                ..FrameState::default()
+            });
+        }
+        self.active = active;
+        self.allow_back = allow_back;
+
+        if active { pressed } else { None }
+    }
+
+    // Estimates how long the message being waited on takes to read at the reading speed.
+    fn time_to_read(&self, chat: bool) -> Duration {
+        let lines: &[String] = match self.chat_log.last() {
+            Some(bubble) if chat => &bubble.lines,
+            _ => &self.message,
+        };
+        reading_time(&lines.join(" "), self.reading_speed) + FADE_TIME
+    }
+
+    // Displays the current terminal until the user submits some input.
+    // Returns true if it stopped early because the terminal went idle, saving the partial input to be picked up next time.
+    fn wait_for_input(&mut self) -> bool {
+        let mut input_string: String = self.input.clone();
+        let mut input_accepted: bool = false;
+        let mut show_help: bool = false;
+        let mut idle: bool = false;
+        let idle_after: Option<Duration> = self.attract.as_ref().map(|(_, idle_after)| *idle_after);
+
+        let keymap: KeyMap = self.keymap.clone();
+        let allow_back: bool = self.allow_back;
+        let mut went_back: bool = false;
+        let deadline: Option<Instant> = self.answer_deadline;
+        let mut timed_out: bool = false;
+        let mut keyboard: Option<VirtualKeyboard> = if self.virtual_keyboard { Some(self.keyboard.clone()) } else { None };
+        let mut help_actions: Vec<Action> = if keyboard.is_some() {
+            vec![Action::KeyboardPress, Action::KeyboardUp, Action::KeyboardDown, Action::KeyboardLeft, Action::KeyboardRight, Action::Backspace]
+        } else {
+            vec![Action::Submit, Action::Backspace, Action::PreviousInput, Action::NextInput]
+        };
+        if allow_back { help_actions.push(Action::Back); }
+        help_actions.push(Action::Help);
+        let help: Vec<(String, String)> = keymap.help(&help_actions);
+        let keyboard_on: bool = keyboard.is_some();
+        let mut mouse: [f64; 2] = [0.0, 0.0];
+        let mut clicked: Option<[f64; 2]> = None;
+        let mut fast_forward: bool = self.fast_forwarding;
+        let mut history: Vec<String> = if self.hidden { Vec::new() } else { self.history.clone() };
+        let mask: Option<char> = if self.hidden { Some(self.mask_char) } else { None };
+        let mut recalled: Option<usize> = None;
+        let mut draft: String = String::default();
+        let candidates: Vec<String> = if self.hidden { Vec::new() } else { self.completions.clone() };
+        let mut completing: Option<(String, usize)> = None;
+        let bell: Option<Color> = self.bell_color;
+        let mut rung: Option<Instant> = None;
+        let mut keypad_typed: Option<char> = None;
+        let mut function_pressed: Option<u8> = None;
+        
+        let mut start: Instant = Instant::now();
+        let mut last_input: Instant = start;
+        let mut active: bool = self.active;
+        while let Some(e) = self.next_event() {
+            e.close(|_| { active = false; });
+            e.mouse_cursor(|pos| mouse = pos);
+            
+            // Some platforms also send a text event for keypad keys, which would type the character twice.
+            e.text(|text| if keypad_typed.take().is_none_or(|typed| text != typed.to_string()) { input_string.push_str(text); });
+            e.button(|button_args| {
+                last_input = Instant::now();
+                track_fast_forward(&keymap, button_args, &mut fast_forward);
+                if let Button::Keyboard(key) = button_args.button {
+                    if button_args.state == ButtonState::Press {
+                        if let Some(typed) = keypad_char(key) {
+                            input_string.push(typed);
+                            keypad_typed = Some(typed);
+                        }
+                        function_pressed = function_key(key);
+                        // The on-screen keyboard takes over the keys for submitting and recalling answers, which usually share its keys.
+                        let submit: bool = !keyboard_on && keymap.is_pressed(&button_args, Action::Submit);
+                        let previous: bool = !keyboard_on && keymap.is_pressed(&button_args, Action::PreviousInput);
+                        let next: bool = !keyboard_on && keymap.is_pressed(&button_args, Action::NextInput);
+                        let complete: bool = !keyboard_on && keymap.is_pressed(&button_args, Action::Complete);
+                        let typed: &str = completing.as_ref().map_or(&input_string, |(typed, _)| typed);
+                        let matches: Vec<&str> = if complete { completions(typed, &candidates) } else { Vec::new() };
+                        let ignored: bool = (keymap.is_pressed(&button_args, Action::Backspace) && input_string.is_empty())
+                            || (submit && input_string.is_empty())
+                            || (keymap.is_pressed(&button_args, Action::Back) && !allow_back)
+                            || (previous && (history.is_empty() || recalled == Some(0)))
+                            || (next && recalled.is_none())
+                            || (complete && matches.is_empty());
+                        if ignored { rung = Some(Instant::now()); }
+                        if keymap.is_pressed(&button_args, Action::Backspace) { input_string.pop(); }
+                        if submit && !input_string.is_empty() { input_accepted = true; }
+                        if keymap.is_pressed(&button_args, Action::Help) { show_help = !show_help; }
+                        if allow_back && keymap.is_pressed(&button_args, Action::Back) { went_back = true; }
+                        // Edits to a recalled answer are kept while moving through the history, like in a shell, but the saved history is left alone.
+                        if previous && !history.is_empty() {
+                            match recalled {
+                                Some(i) => history[i] = input_string.clone(),
+                                None => draft = input_string.clone(),
+                            }
+                            let i: usize = recalled.map_or(history.len() - 1, |i| i.saturating_sub(1));
+                            input_string = history[i].clone();
+                            recalled = Some(i);
+                        }
+                        if next {
+                            if let Some(i) = recalled {
+                                history[i] = input_string.clone();
+                                recalled = if i + 1 < history.len() { Some(i + 1) } else { None };
+                                input_string = recalled.map_or_else(|| draft.clone(), |i| history[i].clone());
+                            }
+                        }
+                        if !matches.is_empty() {
+                            let i: usize = completing.as_ref().map_or(0, |(_, i)| (i + 1) % matches.len());
+                            let typed: String = completing.take().map_or_else(|| input_string.clone(), |(typed, _)| typed);
+                            input_string = String::from(matches[i]);
+                            completing = Some((typed, i));
+                        }
+                    }
+                }
+            });
+
+            // Any change besides cycling through completions starts completing from the new input.
+            if completing.as_ref().is_some_and(|(typed, i)| completions(typed, &candidates).get(*i) != Some(&input_string.as_str())) { completing = None; }
 
-            self.window.draw_2d(&e, |c, g, device| {
-                clear(bgc, g);
+            if let Some(keyboard) = keyboard.as_mut() {
+                let mut pressed: bool = false;
+                e.button(|button_args| if button_args.state == ButtonState::Press {
+                    let (across, down): (i32, i32) = match button_args.button {
+                        Button::Hat(hat) => match hat.state {
+                            HatState::Up => (0, -1),
+                            HatState::Down => (0, 1),
+                            HatState::Left => (-1, 0),
+                            HatState::Right => (1, 0),
+                            _ => (0, 0),
+                        },
+                        Button::Controller(controller) => {
+                            pressed = controller.button == 0;
+                            (0, 0)
+                        },
+                        Button::Mouse(MouseButton::Left) => {
+                            clicked = Some(mouse);
+                            (0, 0)
+                        },
+                        _ => {
+                            pressed = keymap.is_pressed(&button_args, Action::KeyboardPress);
+                            let vertical: i32 = keymap.is_pressed(&button_args, Action::KeyboardDown) as i32 - keymap.is_pressed(&button_args, Action::KeyboardUp) as i32;
+                            let horizontal: i32 = keymap.is_pressed(&button_args, Action::KeyboardRight) as i32 - keymap.is_pressed(&button_args, Action::KeyboardLeft) as i32;
+                            (horizontal, vertical)
+                        },
+                    };
+                    if (across, down) != (0, 0) { keyboard.move_cursor(across, down); }
+                });
+                if let Some((column, row)) = clicked.take().and_then(|point| self.keyboard_cell_at(keyboard, point)) {
+                    pressed = keyboard.select_at(column, row);
+                }
+                if pressed && keyboard.press(&mut input_string) {
+                    if input_string.is_empty() { rung = Some(Instant::now()); } else { input_accepted = true; }
+                }
+            }
+
+            if let Some(number) = function_pressed.take() {
+                if let Some(callback) = self.function_key_callback.as_mut() { callback(number); }
+            }
+            if went_back { break; }
 
-                draw_background(win_size, bgc, fgc, use_filter, c, g);
-                draw_message(message, glyphs, font_size, fgc, c, g);
-                draw_foreground(win_size, bgc, use_filter, c, g);
+            if input_accepted {
+                self.input = input_string.clone();
+                input_string = String::default();
+            }
             
-                glyphs.factory.encoder.flush(device);
+            let now: Instant = Instant::now();
+            if idle_after.is_some_and(|idle_after| now.duration_since(last_input) > idle_after) {
+                self.input = input_string;
+                idle = true;
+                break;
+            }
+            if !input_accepted && deadline.is_some_and(|deadline| now >= deadline) {
+                timed_out = true;
+                break;
+            }
+
+            let flash: bool = check_flash(now, &mut start);
+            let masked: String = match mask {
+                Some(mask) => input_string.chars().map(|_| mask).collect(),
+                None => input_string.clone(),
+            };
+            let ghost: Option<String> = if completing.is_none() { ghost_completion(&input_string, &candidates) } else { None };
+            let ghost_after: String = format!("{}[]", masked);
+            let shown_input: String = if flash { format!("{}[]", masked) } else { masked };
+            self.render_frame(&e, &FrameState {
+                input: Some(&shown_input),
+                ghost: ghost.as_deref().map(|ghost| (ghost_after.as_str(), ghost)),
+                input_marker: true,
+                marker_color: bell_marker(bell, rung, now),
+                flash,
+                help: if show_help { Some(&help) } else { None },
+                keyboard: keyboard.as_ref(),
+                ..FrameState::default()
             });
+
+            if input_accepted { break; }
+        }
+        if let Some(keyboard) = keyboard { self.keyboard = keyboard; }
+        self.active = active;
+        self.fast_forwarding = fast_forward;
+        self.went_back = went_back;
+        self.timed_out = timed_out;
+
+        idle && active
+    }
+
+    // Displays an the current terminal until the timer runs out, always drawing at least one frame.
+    fn wait_for_timer(&mut self, timer: Duration) {
+        let keymap: KeyMap = self.keymap.clone();
+        let speed: u32 = self.fast_forward_speed;
+        let mut fast_forward: bool = self.fast_forwarding;
+
+        let mut last_frame: Instant = Instant::now();
+        let mut flash_start: Instant = last_frame;
+        let mut elapsed: Duration = Duration::from_secs(0);
+        let mut drawn: bool = false;
+        let mut active: bool = self.active;
+        while let Some(e) = self.next_event() {
+            e.close(|_| { active = false; });
+            e.button(|button_args| track_fast_forward(&keymap, button_args, &mut fast_forward));
+
+            let now: Instant = Instant::now();
+            if self.clock_running() { elapsed += scaled_time(now.duration_since(last_frame), fast_forward, speed); }
+            last_frame = now;
+            if elapsed > timer && drawn { break; }
+
+            let flash: bool = check_flash(now, &mut flash_start);
+            if self.render_frame(&e, &FrameState { flash, ..FrameState::default() }) { drawn = true; }
         }
         self.active = active;
+        self.fast_forwarding = fast_forward;
     }
 
     // Processes a new message and types it out.
     fn new_message(&mut self, message: &str) {
-        self.message = message.split('\n').map(String::from).collect();
-        self.process_message();
+        self.new_styled_message(&[Span::plain(message)]);
+    }
+
+    // Splits a styled message into lines that can fit in the current window's bounds and types it out.
+    // If there are more lines than fit in the window, each full page is typed out and waits for the user to continue before the next.
+    fn new_styled_message(&mut self, spans: &[Span]) {
+        let (spans, _) = self.control_codes(spans);
+        let spans: Vec<Span> = self.stamp(&spans);
+        let max_chars: usize = self.get_max_message_characters();
+        let (message, styles) = wrap_spans(&spans, max_chars, self.split_strategy);
+        self.reflow_source = Some((spans, message.clone()));
+        self.new_wrapped_message(message, styles);
+    }
+
+    // Rewraps the current message to fit the window after a resize, if it's still the message last wrapped by new_styled_message
+    // and the whole thing fits on one page. Messages spread over several pages, added under earlier text, or shown some other way are left alone.
+    fn reflow(&mut self) {
+        let (spans, lines) = match self.reflow_source.take() {
+            Some(source) => source,
+            None => return,
+        };
+        if self.message != lines {
+            self.reflow_source = Some((spans, lines));
+            return;
+        }
+
+        let max_chars: usize = self.get_max_message_characters();
+        let page_lines: usize = max_lines(self.win_size().height, self.text_size(), self.spacing());
+        let (message, styles) = wrap_spans(&spans, max_chars, self.split_strategy);
+        if message.len() <= page_lines {
+            if self.last_text == self.message { self.last_text = message.clone(); }
+            self.message = message.clone();
+            self.message_styles = styles;
+            self.reflow_source = Some((spans, message));
+        } else {
+            self.reflow_source = Some((spans, lines));
+        }
+    }
+
+    // Types out lines that have already been wrapped to fit the window, a page at a time like new_styled_message.
+    // With scrollback on, the new lines go below whatever the last message left on screen, which scrolls up to make room.
+    fn new_wrapped_message(&mut self, mut message: Vec<String>, mut styles: Vec<Vec<Style>>) {
+        self.overprint = None;
+        self.scrolled_back = 0;
+
+        let page_lines: usize = max_lines(self.win_size().height, self.text_size(), self.spacing());
+        if self.scrollback && self.message == self.last_text {
+            let kept: usize = page_lines.saturating_sub(message.len()).min(self.message.len());
+            self.scroll_off(self.message.len() - kept);
+            self.typed_before = self.message.iter().map(|line| line.chars().count()).sum();
+
+            self.message_styles.resize(self.message.len(), Vec::new());
+            styles = [mem::take(&mut self.message_styles), styles].concat();
+            message = [mem::take(&mut self.message), message].concat();
+        } else {
+            self.first_line = 1;
+        }
+
+        while message.len() > page_lines && self.active {
+            self.message = message.drain(..page_lines).collect();
+            self.message_styles = styles.drain(..page_lines.min(styles.len())).collect();
+            self.input = String::default();
+            self.type_message();
+
+            self.finish_message(false);
+            self.scroll_off(page_lines);
+        }
+
+        self.message = message;
+        self.message_styles = styles;
+        self.last_text = self.message.clone();
         self.input = String::default();
         self.type_message();
     }
 
-    // Splits a message into a vector of strings that can fit in the current window's bounds.
-    fn process_message(&mut self) {
-        let max_chars: usize = self.get_max_characters();
+    // Shows lines of art in the art font starting from the top left, a page at a time, waiting for the user to continue after each page.
+    fn show_art_pages(&mut self, lines: Vec<String>) {
+        // Art is drawn at the art font size even in high visibility mode, like any other art.
+        let text_size: FontSize = mem::replace(&mut self.font_size, self.art_font_size);
+        let high_visibility: bool = mem::replace(&mut self.high_visibility, false);
+        let page_lines: usize = max_lines(self.win_size().height, self.font_size, DEFAULT_LINE_SPACING);
+
+        for page in lines.chunks(page_lines) {
+            if !self.active { break; }
+            self.use_art_font();
+            self.message = page.to_vec();
+            self.message_styles = Vec::new();
+            self.input = String::from("Press Follow to Continue");
+            while self.wait_for_continue(false) {
+                self.play_attract();
+                self.use_art_font();
+            }
+        }
+
+        self.font_size = text_size;
+        self.high_visibility = high_visibility;
+    }
+
+    // Prefixes a message with a dimmed timestamp if timestamps are turned on.
+    fn stamp(&self, spans: &[Span]) -> Vec<Span> {
+        let mut stamped: Vec<Span> = spans.to_vec();
+        if let Some(format) = &self.timestamp_format {
+            let seconds: u64 = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs());
+            let c: Color = self.message_color;
+            stamped.insert(0, Span::colored(&format!("{} ", format_time(format, seconds)), [c[0], c[1], c[2], c[3] * 0.5]));
+        }
+        stamped
+    }
+
+    // Acts on the control codes in a message the way a real terminal would: a bell character flashes the screen,
+    // a form feed clears everything before it, tabs expand to the next tab stop, and ANSI escape codes color the text.
+    // Returns what's left of the message and whether the screen should be cleared before showing it.
+    fn control_codes(&mut self, spans: &[Span]) -> (Vec<Span>, bool) {
+        let mut spans: Vec<Span> = spans.iter()
+            .flat_map(|span| if span.text.contains('\x1b') { ansi_spans(&span.text, span.style) } else { vec![span.clone()] })
+            .collect();
+        if spans.is_empty() { spans.push(Span::default()); }
+        if spans.iter().any(|span| span.text.contains('\x07')) {
+            self.bell_rung = Some(Instant::now());
+            for span in spans.iter_mut() { span.text = span.text.replace('\x07', ""); }
+        }
 
-        let mut new_message_vec: Vec<String> = Vec::new();
+        let cleared: Option<usize> = spans.iter().rposition(|span| span.text.contains('\x0c'));
+        if let Some(i) = cleared {
+            spans.drain(..i);
+            let after: String = spans[0].text.rsplit('\x0c').next().unwrap_or_default().to_string();
+            spans[0].text = after;
+        }
 
-        for old_message in self.message.iter() {
-            let mut new_message: String = String::new();
+        (expand_tabs(&spans, self.tab_width), cleared.is_some())
+    }
 
-            for word in old_message.split_whitespace() {
-                let word_len: usize = word.len();
-                let message_len: usize = new_message.len();
+    // Adds a styled message below the lines already on screen without typing it out, then draws a frame so it appears right away.
+    fn append_message(&mut self, spans: &[Span]) {
+        self.push_message(spans);
+        self.input = String::default();
+        self.wait_for_timer(Duration::ZERO);
+    }
 
-                if word_len > max_chars {
-                    if message_len > 0 {
-                        let word_vec = split_word(word, max_chars - (message_len + 1), max_chars);
-                        let mut word_iter = word_vec.iter();
-                        new_message_vec.push(format!("{} {}", new_message, word_iter.next().unwrap()));
-                        for continued_word in word_iter {
-                            new_message_vec.push(continued_word.to_string());
-                        }
-                        new_message = new_message_vec.pop().unwrap();
-                    } else {
-                        new_message_vec.append(&mut split_word(word, max_chars, max_chars));
-                    }
-                } else if message_len + word_len > max_chars {
-                    new_message_vec.push(new_message);
-                    new_message = String::from(word);
-                } else if message_len > 0 {
-                    new_message = format!("{} {}", new_message, word);
-                } else {
-                    new_message = String::from(word);
+    // Adds a styled message below the lines already on screen, scrolling off the oldest lines once the window is full.
+    // If the last message ended in a carriage return, its last line is written over instead.
+    fn push_message(&mut self, spans: &[Span]) {
+        let (spans, cleared) = self.control_codes(spans);
+        self.scrolled_back = 0;
+        if cleared {
+            self.scroll_off(self.message.len());
+            self.overprint = None;
+            self.first_line = 1;
+        }
+        let mut spans: Vec<Span> = self.stamp(&spans);
+        if let Some((start, pending)) = self.overprint.take() {
+            self.message.truncate(start);
+            self.message_styles.truncate(start);
+            spans = [pending, vec![Span::plain("\r")], spans].concat();
+        }
+        let max_chars: usize = self.get_max_message_characters();
+        let (message, styles) = wrap_spans(&spans, max_chars, self.split_strategy);
+
+        self.message_styles.resize(self.message.len(), Vec::new());
+        self.message.extend(message);
+        self.message_styles.extend(styles);
+
+        if spans.last().is_some_and(|span| span.text.ends_with('\r')) {
+            let pending: Vec<Span> = last_line_spans(&spans);
+            let pending_lines: usize = wrap_spans(&pending, max_chars, self.split_strategy).0.len();
+            self.overprint = Some((self.message.len().saturating_sub(pending_lines), pending));
+        }
+
+        let page_lines: usize = max_lines(self.win_size().height, self.text_size(), self.spacing());
+        if self.message.len() > page_lines {
+            let scrolled: usize = self.message.len() - page_lines;
+            self.scroll_off(scrolled);
+            if let Some((start, _)) = self.overprint.as_mut() { *start = start.saturating_sub(scrolled); }
+        }
+        self.last_text = self.message.clone();
+    }
+
+    // Appends lines from the receiver as they arrive, drawing each frame in between, until the sender hangs up.
+    fn follow_lines(&mut self, rx: Receiver<String>) {
+        self.input = String::default();
+        while self.active {
+            let mut received: bool = false;
+            loop {
+                match rx.try_recv() {
+                    Ok(line) => {
+                        self.push_message(&[Span::plain(&line)]);
+                        received = true;
+                    },
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => return,
                 }
             }
-            if !new_message.is_empty() { new_message_vec.push(new_message); }
+
+            self.wait_for_timer(if received { Duration::ZERO } else { POLL_TIME });
+        }
+    }
+
+    // Switches to the art font if it isn't already loaded.
+    fn use_art_font(&mut self) {
+        let font: String = self.art_font.clone();
+        self.switch_font(&font);
+    }
+
+    // Switches back to the normal font if it isn't already loaded.
+    fn use_text_font(&mut self) {
+        let font: String = self.font.clone();
+        self.switch_font(&font);
+    }
+
+    // Draws with the given font from now on. The screen keeps every font it has loaded,
+    // so switching back and forth between fonts only loads each one once.
+    fn switch_font(&mut self, font: &str) {
+        if self.loaded_font != font {
+            self.screen.load_font(font).unwrap();
+            self.loaded_font = String::from(font);
         }
-        self.message = new_message_vec;
+    }
+
+    // Determines the max number of art characters that fit across the window, assuming a monospace art font.
+    fn get_max_art_characters(&self) -> usize {
+        (((self.win_size().width - TEXT_OFFSET.0 * 2.0) / (self.art_font_size as f64 * 0.6)) as usize).max(1)
+    }
+
+    // Determines the max number of message characters per line, leaving room for the gutter if there is one.
+    fn get_max_message_characters(&self) -> usize {
+        let gutter_width: usize = self.gutter.map_or(0, |gutter| gutter.width + 1);
+        self.get_max_characters().saturating_sub(gutter_width).max(1)
+    }
+
+    // Returns the size the terminal is laid out at, which is never smaller than the minimum window size.
+    fn win_size(&self) -> Size {
+        layout_size(self.screen.size())
     }
 
     // Determines the max number of characters based on window and font size.
     fn get_max_characters(&self) -> usize {
-        ((self.window.window.size().width / self.font_size as f64) * 2.15) as usize
+        let fitting: usize = (((self.win_size().width / self.text_size() as f64) * 2.15) as usize).max(1);
+        if self.high_visibility { fitting.min(HIGH_VISIBILITY_LINE_LENGTH) } else { fitting }
+    }
+
+    // Returns the font size text is drawn at, which is never smaller than the high visibility font size in high visibility mode.
+    fn text_size(&self) -> FontSize {
+        if self.high_visibility { self.font_size.max(HIGH_VISIBILITY_FONT_SIZE) } else { self.font_size }
+    }
+
+    // Returns how far apart lines of text are drawn, spreading them out further in high visibility mode.
+    fn spacing(&self) -> f64 {
+        if self.high_visibility { self.line_spacing.max(HIGH_VISIBILITY_LINE_SPACING) } else { self.line_spacing }
+    }
+}
+
+impl Drop for Terminal {
+    // Saves the window's size and position if persist_window was called, and the input history if persist_history was called.
+    // Saving here is best effort. Drop can't return an error, and panicking while the program is already unwinding would abort it,
+    // so errors are ignored. Scripts that need to know whether saving works call save_history and save_window themselves.
+    fn drop(&mut self) {
+        let _ = self.save_history();
+        let _ = self.save_window();
+    }
+}
+
+// Updates whether the fast forward key is held when one of its keys is pressed or released.
+fn track_fast_forward(keymap: &KeyMap, button_args: ButtonArgs, fast_forward: &mut bool) {
+    if keymap.is_pressed(&button_args, Action::FastForward) { *fast_forward = button_args.state == ButtonState::Press; }
+}
+
+// Returns the spans of the last line of a message, after its last newline.
+fn last_line_spans(spans: &[Span]) -> Vec<Span> {
+    let mut line: Vec<Span> = Vec::new();
+    for span in spans.iter().rev() {
+        match span.text.rfind('\n') {
+            Some(end) => {
+                line.push(Span { text: String::from(&span.text[end + 1..]), style: span.style });
+                break;
+            },
+            None => line.push(span.clone()),
+        }
+    }
+    line.reverse();
+    line
+}
+
+// Returns the bell color if a key press was ignored recently enough that the input marker should still be lit.
+fn bell_marker(bell: Option<Color>, rung: Option<Instant>, now: Instant) -> Option<Color> {
+    bell.filter(|_| rung.is_some_and(|rung| now.duration_since(rung) < BELL_TIME))
+}
+
+// Opens the screen a new terminal draws on: an OpenGL window, or a window drawn with the CPU if OpenGL isn't available,
+// centered on the monitor with the given index if there is one.
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "tty")))]
+fn open_screen(title: &str, size: (u32, u32), monitor: Option<usize>) -> Result<Box<dyn Screen>, Box<dyn Error>> {
+    match PistonScreen::open(title, size, monitor) {
+        Ok(screen) => Ok(Box::new(screen)),
+        Err(gl) => {
+            let screen: SoftwareScreen = SoftwareScreen::open(title, size, monitor)
+                .map_err(|e| format!("Couldn't open an OpenGL window ({}) or a software window ({})", gl, e))?;
+            Ok(Box::new(screen))
+        }
     }
 }
+
+// Opens the screen a new terminal draws on, which is the real terminal the program was started from with the tty feature.
+#[cfg(feature = "tty")]
+fn open_screen(title: &str, _size: (u32, u32), _monitor: Option<usize>) -> Result<Box<dyn Screen>, Box<dyn Error>> {
+    Ok(Box::new(TtyScreen::new(title)?))
+}
+
+// Returns true for keys that are only ever held along with other keys, which shouldn't ring the bell on their own.
+fn is_modifier(key: Key) -> bool {
+    matches!(key, Key::LShift | Key::RShift | Key::LCtrl | Key::RCtrl | Key::LAlt | Key::RAlt | Key::LGui | Key::RGui | Key::CapsLock)
+}
+
+// Scales a frame's duration by the fast forward speed if fast forwarding.
+fn scaled_time(frame: Duration, fast_forward: bool, speed: u32) -> Duration {
+    if fast_forward { frame * speed } else { frame }
+}
@@ -1,7 +1,18 @@
-use piston_window::{*, types::{Color, FontSize}};
-use std::{path::Path, {time::Duration, time::Instant}};
+use graphics::types::{Color, FontSize};
+use piston::Size;
+#[cfg(not(target_arch = "wasm32"))]
+use piston_window::{Glyphs, PistonWindow};
+use regex::Regex;
+use unicode_width::UnicodeWidthStr;
+use std::{mem, time::Duration};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{io, path::Path};
+use web_time::Instant;
 
-use crate::FLASH_TIME;
+use crate::{ansi::VGA_COLORS, FLASH_TIME, TEXT_OFFSET};
+
+/// How far apart lines of text are drawn by default, as a multiple of the font size. Art is always drawn at this spacing.
+pub const DEFAULT_LINE_SPACING: f64 = 0.8;
 
 /// <span style="color:#DB143D; text-shadow: 1px 0.5px #555">█</span>
 pub const CRIMSON: Color =      [0.86, 0.08, 0.24, 1.0];
@@ -35,18 +46,310 @@ pub const COLORS: [Color; 5] = [
     LIGHT_BLUE,
 ];
 
+// The colors markup can name, by their lowercase names.
+const NAMED_COLORS: [(&str, Color); 8] = [
+    ("crimson", CRIMSON),
+    ("dark_grey", DARK_GREY),
+    ("dark_purple", DARK_PURPLE),
+    ("emerald", EMERALD),
+    ("gold", GOLD),
+    ("light_blue", LIGHT_BLUE),
+    ("light_purple", LIGHT_PURPLE),
+    ("off_white", OFF_WHITE),
+];
+
+// The style names markup tags can turn on.
+const MARKUP_STYLES: [&str; 5] = ["color", "blink", "inverse", "shadow", "outline"];
+
+/// Display attributes for a single character of a message.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Style {
+    /// The color of the character, or None to use the message's color.
+    pub color: Option<Color>,
+    /// Whether the character flashes on and off along with the input cursor.
+    pub blink: bool,
+    /// Whether the character is drawn in the background color on a block of its own color.
+    pub inverse: bool,
+    /// A darker copy of the character drawn behind it.
+    pub backdrop: Backdrop,
+}
+
+/// A darker copy of text drawn behind it, so it stays readable over art, images, or particles.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Backdrop {
+    /// Nothing is drawn behind the text.
+    #[default]
+    None,
+    /// The copy is drawn offset down and to the right, like a drop shadow.
+    Shadow,
+    /// Copies are drawn a pixel out in every direction, outlining each character.
+    Outline,
+}
+
+/// The colors used to draw styled text, and whether blinking text is currently visible.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextColors {
+    /// The background color, used for inverse text.
+    pub bg: Color,
+    /// The color of characters that don't have their own color.
+    pub fg: Color,
+    /// Whether blinking characters should be drawn this frame.
+    pub blink_on: bool,
+}
+
+/// A piece of a message that shares a single style.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Span {
+    /// The text of this span. This may contain newlines.
+    pub text: String,
+    /// The style used for every character of the text.
+    pub style: Style,
+}
+
+impl Span {
+    /// Creates a span that uses the message's color.
+    pub fn plain(text: &str) -> Span {
+        Span { text: String::from(text), style: Style::default() }
+    }
+
+    /// Creates a span drawn in the given color.
+    pub fn colored(text: &str, color: Color) -> Span {
+        Span { text: String::from(text), style: Style { color: Some(color), ..Style::default() } }
+    }
+
+    /// Makes this span flash on and off.
+    /// ```
+    /// # use simpleterm_profile::text::*;
+    /// assert!(Span::colored("WARNING", CRIMSON).blink().style.blink);
+    /// ```
+    pub fn blink(mut self) -> Span {
+        self.style.blink = true;
+        self
+    }
+
+    /// Makes this span draw with its foreground and background colors swapped.
+    /// ```
+    /// # use simpleterm_profile::text::*;
+    /// assert!(Span::plain("selected").inverse().style.inverse);
+    /// ```
+    pub fn inverse(mut self) -> Span {
+        self.style.inverse = true;
+        self
+    }
+
+    /// Makes this span draw with a darker drop shadow behind it.
+    /// ```
+    /// # use simpleterm_profile::text::*;
+    /// assert_eq!(Span::plain("TITLE").shadow().style.backdrop, Backdrop::Shadow);
+    /// ```
+    pub fn shadow(mut self) -> Span {
+        self.style.backdrop = Backdrop::Shadow;
+        self
+    }
+
+    /// Makes this span draw with a darker outline around each character.
+    /// ```
+    /// # use simpleterm_profile::text::*;
+    /// assert_eq!(Span::plain("TITLE").outline().style.backdrop, Backdrop::Outline);
+    /// ```
+    pub fn outline(mut self) -> Span {
+        self.style.backdrop = Backdrop::Outline;
+        self
+    }
+}
+
+/// Which side of a chat conversation a message belongs to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    /// Messages from the other person, aligned to the left.
+    Them,
+    /// Messages from the user, aligned to the right.
+    Me,
+}
+
+/// How serious a logged message is, which decides its tag and color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    Info,
+    Warn,
+    Error,
+    Success,
+}
+
+impl Level {
+    /// Returns the tag shown before messages at this level.
+    /// ```
+    /// # use simpleterm_profile::text::*;
+    /// assert_eq!(Level::Warn.tag(), "[!]");
+    /// ```
+    pub fn tag(self) -> &'static str {
+        match self {
+            Level::Info => "[i]",
+            Level::Warn => "[!]",
+            Level::Error => "[x]",
+            Level::Success => "[+]",
+        }
+    }
+
+    /// Returns the color messages at this level are drawn in.
+    pub fn color(self) -> Color {
+        match self {
+            Level::Info => LIGHT_BLUE,
+            Level::Warn => GOLD,
+            Level::Error => CRIMSON,
+            Level::Success => EMERALD,
+        }
+    }
+}
+
+/// A column beside the message showing line numbers, useful when displaying code or logs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gutter {
+    /// How many digits wide the column is. Longer line numbers still fit, but push the message over.
+    pub width: usize,
+    /// The color the line numbers are drawn in.
+    pub color: Color,
+}
+
+/// A single wrapped message in a chat conversation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bubble {
+    /// Which side of the conversation this message is from.
+    pub side: Side,
+    /// The wrapped lines of the message.
+    pub lines: Vec<String>,
+    /// The color the message is drawn in.
+    pub color: Color,
+}
+
+/// A range of displayed text picked out by the user, from where the selection started to where its cursor is now.
+/// Positions are (line, column) pairs, where the column is the gap before that character.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let message: Vec<String> = vec![String::from("hello"), String::from("world")];
+/// let mut selection: Selection = Selection::new((1, 2));
+/// selection.left(&message);
+/// selection.up(&message);
+/// assert_eq!(selection.text(&message), "ello\nwo");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Selection {
+    /// Where the selection started.
+    pub anchor: (usize, usize),
+    /// Where the selection currently ends.
+    pub cursor: (usize, usize),
+}
+
+impl Selection {
+    /// Starts an empty selection at the given position.
+    pub fn new(at: (usize, usize)) -> Selection {
+        Selection { anchor: at, cursor: at }
+    }
+
+    /// Moves the cursor one character left, wrapping to the end of the previous line.
+    pub fn left(&mut self, message: &[String]) {
+        let (line, col) = self.cursor;
+        if col > 0 {
+            self.cursor = (line, col - 1);
+        } else if line > 0 {
+            self.cursor = (line - 1, line_length(message, line - 1));
+        }
+    }
+
+    /// Moves the cursor one character right, wrapping to the start of the next line.
+    pub fn right(&mut self, message: &[String]) {
+        let (line, col) = self.cursor;
+        if col < line_length(message, line) {
+            self.cursor = (line, col + 1);
+        } else if line + 1 < message.len() {
+            self.cursor = (line + 1, 0);
+        }
+    }
+
+    /// Moves the cursor up a line, keeping its column if that line is long enough.
+    pub fn up(&mut self, message: &[String]) {
+        let (line, col) = self.cursor;
+        if line > 0 { self.cursor = (line - 1, col.min(line_length(message, line - 1))); }
+    }
+
+    /// Moves the cursor down a line, keeping its column if that line is long enough.
+    pub fn down(&mut self, message: &[String]) {
+        let (line, col) = self.cursor;
+        if line + 1 < message.len() { self.cursor = (line + 1, col.min(line_length(message, line + 1))); }
+    }
+
+    /// Returns whether the character at the given line and column is selected.
+    pub fn contains(&self, line: usize, col: usize) -> bool {
+        let (start, end) = self.ordered();
+        (line, col) >= start && (line, col) < end
+    }
+
+    /// Returns the selected text, with lines joined by newlines.
+    pub fn text(&self, message: &[String]) -> String {
+        let (start, end) = self.ordered();
+        if start == end || start.0 >= message.len() { return String::new(); }
+
+        (start.0..=end.0.min(message.len() - 1)).map(|line| {
+            let from: usize = if line == start.0 { start.1 } else { 0 };
+            let to: usize = if line == end.0 { end.1 } else { line_length(message, line) };
+            message[line].chars().skip(from).take(to.saturating_sub(from)).collect::<String>()
+        }).collect::<Vec<String>>().join("\n")
+    }
+
+    /// Returns a copy of the given styles with the selected characters inverted, filling in default styles where needed.
+    /// ```
+    /// # use simpleterm_profile::text::*;
+    /// let message: Vec<String> = vec![String::from("abc")];
+    /// let selection: Selection = Selection { anchor: (0, 1), cursor: (0, 2) };
+    /// let styles: Vec<Vec<Style>> = selection.apply(&message, &[]);
+    /// assert_eq!(styles[0].iter().map(|style| style.inverse).collect::<Vec<bool>>(), vec![false, true, false]);
+    /// ```
+    pub fn apply(&self, message: &[String], styles: &[Vec<Style>]) -> Vec<Vec<Style>> {
+        message.iter().enumerate().map(|(line, text)| {
+            (0..text.chars().count()).map(|col| {
+                let mut style: Style = styles.get(line).and_then(|line_styles| line_styles.get(col)).copied().unwrap_or_default();
+                if self.contains(line, col) { style.inverse = !style.inverse; }
+                style
+            }).collect()
+        }).collect()
+    }
+
+    // Returns the start and end of the selection, in reading order.
+    fn ordered(&self) -> ((usize, usize), (usize, usize)) {
+        if self.anchor <= self.cursor { (self.anchor, self.cursor) } else { (self.cursor, self.anchor) }
+    }
+}
+
+// Returns the number of characters on the given line of a message.
+fn line_length(message: &[String], line: usize) -> usize {
+    message.get(line).map_or(0, |text| text.chars().count())
+}
+
+/// How words that are too long to fit on a line get split across lines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SplitStrategy {
+    /// Chop the word wherever the line runs out of room.
+    Chop,
+    /// Break the word one character early and end the line with a hyphen.
+    Hyphenate,
+    /// Break the word at the last syllable boundary that fits, using an English hyphenation dictionary.
+    /// Falls back to Hyphenate for words the dictionary can't split.
+    #[cfg(feature = "hyphenation")]
+    Dictionary,
+}
+
 /// Adds brightness functions to PistonWindow's Color type
 pub trait TermColor {
     /// Uses a [weighted](https://www.nbdtech.com/Blog/archive/2008/04/27/Calculating-the-Perceived-Brightness-of-a-Color.aspx) color axis to determine percieved brightness of a color.
     /// ```
-    /// # use simpleterm::text::*;
+    /// # use simpleterm_profile::text::*;
     /// assert_eq!(EMERALD.brightness(), 0.6626567);
     /// ```
     fn brightness(&self) -> f32;
     
     /// Returns true if this color is brighter than the given other color.
     /// ```
-    /// # use simpleterm::text::*;
+    /// # use simpleterm_profile::text::*;
     /// assert!(LIGHT_PURPLE.brighter_than(DARK_PURPLE));
     /// ```
     fn brighter_than(&self, other: Color) -> bool;
@@ -68,15 +371,22 @@ impl TermColor for Color {
 }
 
 /// Returns the Glyph cache generated from the given font file opened in the given PistonWindow.
+#[cfg(not(target_arch = "wasm32"))]
 pub fn load_font(window: &mut PistonWindow, name: &str) -> Glyphs {
+    try_load_font(window, name).unwrap()
+}
+
+/// Returns the Glyph cache generated from the given font file opened in the given PistonWindow, or an error if the font couldn't be loaded.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn try_load_font(window: &mut PistonWindow, name: &str) -> io::Result<Glyphs> {
     let resources: &Path = Path::new("resources");
-    window.load_font(resources.join(name)).unwrap()
+    window.load_font(resources.join(name))
 }
 
 /// Returns a vector of strings corresponding to a word split up at the given number of characters.
 /// first_split may be smaller than rest_split to allow the first part of a word to fit on a line with previous words.
 /// ```
-/// # use simpleterm::text::*;
+/// # use simpleterm_profile::text::*;
 /// let long_word: &str = "supercalifragilisticexpialidocious";
 /// assert_eq!(
 ///     split_word(long_word, 5, 10),
@@ -119,10 +429,739 @@ pub fn split_word(x: &str, first_split: usize, rest_split: usize) -> Vec<String>
     result
 }
 
+/// Returns a vector of strings corresponding to a word split up at the given number of characters, using the given strategy.
+/// Like [split_word](fn.split_word.html), first_split may be smaller than rest_split to fit the first part of a word after previous words.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// assert_eq!(
+///     split_word_with("supercalifragilistic", 6, 8, SplitStrategy::Hyphenate),
+///     vec!(
+///         String::from("super-"),
+///         String::from("califra-"),
+///         String::from("gilistic"),
+///     )
+/// );
+/// ```
+pub fn split_word_with(x: &str, first_split: usize, rest_split: usize, strategy: SplitStrategy) -> Vec<String> {
+    match strategy {
+        SplitStrategy::Chop => split_word(x, first_split, rest_split),
+        SplitStrategy::Hyphenate => split_at_breaks(x, first_split, rest_split, &[]),
+        #[cfg(feature = "hyphenation")]
+        SplitStrategy::Dictionary => split_at_breaks(x, first_split, rest_split, &dictionary_breaks(x)),
+    }
+}
+
+// Splits a word into hyphenated pieces that fit in first_split and then rest_split characters, hyphen included.
+// Each piece ends at the last of the given character break indices that fits, or is chopped one character early if none fit.
+fn split_at_breaks(x: &str, first_split: usize, rest_split: usize, breaks: &[usize]) -> Vec<String> {
+    let chars: Vec<char> = x.chars().collect();
+    let mut result: Vec<String> = Vec::new();
+
+    let mut start: usize = 0;
+    let mut limit: usize = first_split;
+    while chars.len() - start > limit {
+        let furthest: usize = start + limit.saturating_sub(1);
+        let end: usize = breaks.iter().copied().filter(|b| *b > start && *b <= furthest).max().unwrap_or(furthest);
+
+        if end > start {
+            let piece: String = chars[start..end].iter().collect();
+            result.push(format!("{}-", piece));
+        } else {
+            result.push(String::default());
+        }
+
+        start = end;
+        limit = rest_split.max(2);
+    }
+    result.push(chars[start..].iter().collect());
+
+    result
+}
+
+// Returns the character indices where the dictionary says the given word can be hyphenated.
+#[cfg(feature = "hyphenation")]
+fn dictionary_breaks(x: &str) -> Vec<usize> {
+    use hyphenation::{Hyphenator, Language, Load, Standard};
+    use std::sync::OnceLock;
+
+    static DICTIONARY: OnceLock<Option<Standard>> = OnceLock::new();
+    let dictionary = DICTIONARY.get_or_init(|| Standard::from_embedded(Language::EnglishUS).ok());
+
+    match dictionary {
+        Some(dictionary) => dictionary.hyphenate(x).breaks.iter().map(|b| x[..*b].chars().count()).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Splits each of the given lines into a vector of strings that are at most max_chars long, breaking on whitespace where possible.
+/// Words longer than max_chars are split across multiple lines.
+/// Any indentation at the start of a line is repeated on each of the lines it wraps onto.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let lines: Vec<String> = vec!(String::from("the quick brown fox jumps over the lazy dog"));
+/// assert_eq!(
+///     wrap_lines(&lines, 16),
+///     vec!(
+///         String::from("the quick brown"),
+///         String::from("fox jumps over"),
+///         String::from("the lazy dog"),
+///     )
+/// );
+/// 
+/// let indented: Vec<String> = vec!(String::from("  the quick brown fox"));
+/// assert_eq!(wrap_lines(&indented, 12), vec!(String::from("  the quick"), String::from("  brown fox")));
+/// ```
+pub fn wrap_lines(lines: &[String], max_chars: usize) -> Vec<String> {
+    wrap_lines_with(lines, max_chars, SplitStrategy::Chop)
+}
+
+/// Wraps lines like [wrap_lines](fn.wrap_lines.html), splitting words that are too long with the given strategy.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let lines: Vec<String> = vec!(String::from("a supercalifragilistic word"));
+/// assert_eq!(
+///     wrap_lines_with(&lines, 10, SplitStrategy::Hyphenate),
+///     vec!(
+///         String::from("a superca-"),
+///         String::from("lifragili-"),
+///         String::from("stic word"),
+///     )
+/// );
+/// ```
+pub fn wrap_lines_with(lines: &[String], max_chars: usize, strategy: SplitStrategy) -> Vec<String> {
+    let mut new_message_vec: Vec<String> = Vec::new();
+
+    for old_message in lines.iter() {
+        let indent_len: usize = old_message.len() - old_message.trim_start().len();
+        let indent: &str = if indent_len < max_chars / 2 { &old_message[..indent_len] } else { "" };
+
+        for wrapped in wrap_line(old_message, max_chars - indent.len(), strategy) {
+            new_message_vec.push(format!("{}{}", indent, wrapped));
+        }
+    }
+
+    new_message_vec
+}
+
+/// Wraps lines like [wrap_lines_with](fn.wrap_lines_with.html) so they fit in max_chars once indented, and starts every wrapped line with the indent.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let lines: Vec<String> = vec!(String::from("the quick brown fox"));
+/// assert_eq!(
+///     wrap_indented(&lines, "    ", 14, SplitStrategy::Chop),
+///     vec!(
+///         String::from("    the quick"),
+///         String::from("    brown fox"),
+///     )
+/// );
+/// ```
+pub fn wrap_indented(lines: &[String], indent: &str, max_chars: usize, strategy: SplitStrategy) -> Vec<String> {
+    let width: usize = max_chars.saturating_sub(indent.len()).max(1);
+    lines.iter().flat_map(|line| wrap_line(line, width, strategy)).map(|line| format!("{}{}", indent, line)).collect()
+}
+
+// Splits a single line into a vector of strings that are at most max_chars long, dropping any extra whitespace.
+fn wrap_line(old_message: &str, max_chars: usize, strategy: SplitStrategy) -> Vec<String> {
+    let mut new_message_vec: Vec<String> = Vec::new();
+    let mut new_message: String = String::new();
+
+    for word in old_message.split_whitespace() {
+        let word_len: usize = word.len();
+        let message_len: usize = new_message.len();
+
+        if word_len > max_chars {
+            if message_len > 0 {
+                let word_vec = split_word_with(word, max_chars - (message_len + 1), max_chars, strategy);
+                let mut word_iter = word_vec.iter();
+                new_message_vec.push(format!("{} {}", new_message, word_iter.next().unwrap()));
+                for continued_word in word_iter {
+                    new_message_vec.push(continued_word.to_string());
+                }
+                new_message = new_message_vec.pop().unwrap();
+            } else {
+                new_message_vec.append(&mut split_word_with(word, max_chars, max_chars, strategy));
+            }
+        } else if message_len + word_len > max_chars {
+            new_message_vec.push(new_message);
+            new_message = String::from(word);
+        } else if message_len > 0 {
+            new_message = format!("{} {}", new_message, word);
+        } else {
+            new_message = String::from(word);
+        }
+    }
+    if !new_message.is_empty() { new_message_vec.push(new_message); }
+
+    new_message_vec
+}
+
+/// Wraps styled text the same way as [wrap_lines_with](fn.wrap_lines_with.html), splitting the spans into lines at each newline first.
+/// A carriage return goes back to the start of its line, so the characters after it type over the ones before, like on a teletype.
+/// Returns the wrapped lines along with the style of each character in them.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let spans: Vec<Span> = vec!(Span::colored("Bob:", GOLD), Span::plain(" hi there"));
+/// let (lines, styles) = wrap_spans(&spans, 8, SplitStrategy::Chop);
+/// assert_eq!(lines, vec!(String::from("Bob: hi"), String::from("there")));
+/// assert_eq!(styles[0][0].color, Some(GOLD));
+/// assert_eq!(styles[0][5].color, None);
+///
+/// let (lines, _) = wrap_spans(&[Span::plain("Loading 10%\rLoading 100%\nDone\rG")], 20, SplitStrategy::Chop);
+/// assert_eq!(lines, vec!(String::from("Loading 100%"), String::from("Gone")));
+/// ```
+pub fn wrap_spans(spans: &[Span], max_chars: usize, strategy: SplitStrategy) -> (Vec<String>, Vec<Vec<Style>>) {
+    let mut lines: Vec<String> = Vec::new();
+    let mut styles: Vec<Vec<Style>> = Vec::new();
+
+    let mut source: Vec<(char, Style)> = Vec::new();
+    let mut column: usize = 0;
+    let chars = spans.iter().flat_map(|span| span.text.chars().map(move |c| (c, span.style)));
+    for (c, style) in chars.chain(std::iter::once(('\n', Style::default()))) {
+        if c == '\r' {
+            column = 0;
+            continue;
+        }
+        if c != '\n' {
+            if column < source.len() { source[column] = (c, style); } else { source.push((c, style)); }
+            column += 1;
+            continue;
+        }
+        column = 0;
+
+        let text: String = source.iter().map(|(c, _)| c).collect();
+        let mut source_iter = source.iter().filter(|(c, _)| !c.is_whitespace()).peekable();
+        for line in wrap_lines_with(&[text], max_chars, strategy) {
+            let mut last: Option<Style> = None;
+            let line_styles: Vec<Style> = line.chars().map(|c| {
+                if c.is_whitespace() || source_iter.peek().is_none_or(|(source_c, _)| *source_c != c) {
+                    last.unwrap_or_default()
+                } else {
+                    let style: Style = source_iter.next().map(|(_, style)| *style).unwrap_or_default();
+                    last = Some(style);
+                    style
+                }
+            }).collect();
+
+            lines.push(line);
+            styles.push(line_styles);
+        }
+
+        source.clear();
+    }
+
+    (lines, styles)
+}
+
+/// Returns the color with the given name, like "gold" or "light blue", or written as hex like "#FFA61A".
+/// ```
+/// # use simpleterm_profile::text::*;
+/// assert_eq!(named_color("Light Blue"), Some(LIGHT_BLUE));
+/// assert_eq!(named_color("#FF0000"), Some([1.0, 0.0, 0.0, 1.0]));
+/// assert_eq!(named_color("plaid"), None);
+/// ```
+pub fn named_color(name: &str) -> Option<Color> {
+    if let Some(hex) = name.strip_prefix('#') {
+        let channel = |i: usize| Some(u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()? as f32 / 255.0);
+        return if hex.len() == 6 { Some([channel(0)?, channel(2)?, channel(4)?, 1.0]) } else { None };
+    }
+
+    let name: String = name.trim().to_lowercase().replace(' ', "_");
+    NAMED_COLORS.iter().find(|(color_name, _)| *color_name == name).map(|(_, color)| *color)
+}
+
+/// Splits text with simple markup tags into styled spans, for coloring words without raw escape codes.
+/// [color=gold] switches to a [named color](fn.named_color.html), and [blink], [inverse], [shadow], and [outline] turn on that style.
+/// Each tag lasts until its closing tag, like [/color], or a bare [/], which closes the most recent tag. Tags can be nested.
+/// Write [[ for a literal bracket; anything else in brackets that isn't a tag is left as it is.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let spans: Vec<Span> = parse_markup("[color=crimson]WARNING[/color]: [blink]core [inverse]unstable[/][/] [[1/3]");
+/// assert_eq!(spans, vec![
+///     Span::colored("WARNING", CRIMSON),
+///     Span::plain(": "),
+///     Span::plain("core ").blink(),
+///     Span::plain("unstable").blink().inverse(),
+///     Span::plain(" [1/3]"),
+/// ]);
+/// ```
+pub fn parse_markup(text: &str) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut stack: Vec<Style> = Vec::new();
+    let mut style: Style = Style::default();
+    let mut current: String = String::new();
+
+    let mut rest: &str = text;
+    while let Some(open) = rest.find('[') {
+        current.push_str(&rest[..open]);
+        rest = &rest[open..];
+        if let Some(after) = rest.strip_prefix("[[") {
+            current.push('[');
+            rest = after;
+            continue;
+        }
+
+        let tag: Option<(usize, Option<Style>)> = rest.find(']').and_then(|close| Some((close, markup_tag(&rest[1..close], style)?)));
+        match tag {
+            Some((close, next)) => {
+                if !current.is_empty() { spans.push(Span { text: mem::take(&mut current), style }); }
+                match next {
+                    Some(next) => stack.push(mem::replace(&mut style, next)),
+                    None => style = stack.pop().unwrap_or_default(),
+                }
+                rest = &rest[close + 1..];
+            },
+            None => {
+                current.push('[');
+                rest = &rest[1..];
+            },
+        }
+    }
+
+    current.push_str(rest);
+    if !current.is_empty() { spans.push(Span { text: current, style }); }
+    spans
+}
+
+// Returns the style a markup tag switches to from the given one, Some(None) for a closing tag, or None if it isn't a tag at all.
+fn markup_tag(tag: &str, style: Style) -> Option<Option<Style>> {
+    if let Some(name) = tag.strip_prefix('/') {
+        return if name.is_empty() || MARKUP_STYLES.contains(&name) { Some(None) } else { None };
+    }
+
+    let mut next: Style = style;
+    match tag.split_once('=') {
+        Some(("color", color)) => next.color = Some(named_color(color)?),
+        None if tag == "blink" => next.blink = true,
+        None if tag == "inverse" => next.inverse = true,
+        None if tag == "shadow" => next.backdrop = Backdrop::Shadow,
+        None if tag == "outline" => next.backdrop = Backdrop::Outline,
+        _ => return None,
+    }
+    Some(Some(next))
+}
+
+/// Splits text containing ANSI color escape codes into styled spans, starting from the given style, so
+/// output meant for a real terminal keeps its colors. Reset (0), bold (1), blink (5), and inverse (7) are supported along with
+/// their resets (22, 25, and 27), the normal and bright text colors (30 to 37 and 90 to 97), and the default color (39).
+/// Bold brightens the normal colors. Background colors and every other escape code are left out.
+/// ```
+/// # use simpleterm_profile::{ansi::VGA_COLORS, text::*};
+/// let spans: Vec<Span> = ansi_spans("\x1b[31mERROR\x1b[0m: failed", Style::default());
+/// assert_eq!(spans, vec![Span::colored("ERROR", VGA_COLORS[1]), Span::plain(": failed")]);
+///
+/// let spans: Vec<Span> = ansi_spans("\x1b[1;32mOK\x1b[22m done\x1b[2K", Style::default());
+/// assert_eq!(spans, vec![Span::colored("OK", VGA_COLORS[10]), Span::colored(" done", VGA_COLORS[2])]);
+/// ```
+pub fn ansi_spans(text: &str, base: Style) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut style: Style = base;
+    let (mut color, mut bold): (Option<usize>, bool) = (None, false);
+    let mut current: String = String::new();
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            current.push(c);
+            continue;
+        }
+        if chars.peek() != Some(&'[') { continue; }
+        chars.next();
+
+        let mut params: String = String::new();
+        let mut command: Option<char> = None;
+        for c in chars.by_ref() {
+            if ('@'..='~').contains(&c) {
+                command = Some(c);
+                break;
+            }
+            params.push(c);
+        }
+        if command != Some('m') { continue; }
+
+        if !current.is_empty() { spans.push(Span { text: mem::take(&mut current), style }); }
+        for param in params.split(';').map(|param| param.parse::<usize>().unwrap_or(0)) {
+            match param {
+                0 => {
+                    style = base;
+                    color = None;
+                    bold = false;
+                },
+                1 => bold = true,
+                5 => style.blink = true,
+                7 => style.inverse = true,
+                22 => bold = false,
+                25 => style.blink = base.blink,
+                27 => style.inverse = base.inverse,
+                30..=37 => color = Some(param - 30),
+                39 => color = None,
+                90..=97 => color = Some(param - 90 + 8),
+                _ => {},
+            }
+        }
+        style.color = color.map_or(base.color, |color| Some(VGA_COLORS[if bold { color | 8 } else { color }]));
+    }
+
+    if !current.is_empty() { spans.push(Span { text: current, style }); }
+    spans
+}
+
+/// Replaces each tab with enough spaces to reach the next tab stop, with a stop every tab_width columns.
+/// Columns are counted from the start of each line, across spans.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let spans: Vec<Span> = expand_tabs(&[Span::plain("a\tb"), Span::colored("cd\te\n\tf", GOLD)], 4);
+/// assert_eq!(spans[0].text, "a   b");
+/// assert_eq!(spans[1].text, "cd e\n    f");
+/// ```
+pub fn expand_tabs(spans: &[Span], tab_width: usize) -> Vec<Span> {
+    let tab_width: usize = tab_width.max(1);
+    let mut column: usize = 0;
+    spans.iter().map(|span| {
+        let mut text: String = String::new();
+        for c in span.text.chars() {
+            match c {
+                '\t' => {
+                    let spaces: usize = tab_width - column % tab_width;
+                    text.push_str(&" ".repeat(spaces));
+                    column += spaces;
+                },
+                '\n' | '\r' => {
+                    text.push(c);
+                    column = 0;
+                },
+                _ => {
+                    text.push(c);
+                    column += 1;
+                },
+            }
+        }
+        Span { text, style: span.style }
+    }).collect()
+}
+
+/// Groups the characters of a line into runs that share the same style.
+/// Characters past the end of styles use the default style.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let gold: Style = Style { color: Some(GOLD), ..Style::default() };
+/// let styles: Vec<Style> = vec!(gold, gold, Style::default());
+/// assert_eq!(
+///     style_runs("hey you", &styles),
+///     vec!((String::from("he"), gold), (String::from("y you"), Style::default()))
+/// );
+/// ```
+pub fn style_runs(line: &str, styles: &[Style]) -> Vec<(String, Style)> {
+    let mut runs: Vec<(String, Style)> = Vec::new();
+
+    for (i, c) in line.chars().enumerate() {
+        let style: Style = styles.get(i).copied().unwrap_or_default();
+        match runs.last_mut() {
+            Some((run, run_style)) if *run_style == style => run.push(c),
+            _ => runs.push((c.to_string(), style)),
+        }
+    }
+
+    runs
+}
+
+/// Returns the candidates that start with the given input, ignoring case, in the order they were given.
+/// Every candidate matches empty input.
+/// ```
+/// # use simpleterm_profile::text::completions;
+/// let commands: Vec<String> = vec![String::from("help"), String::from("look"), String::from("Lookup")];
+/// assert_eq!(completions("lo", &commands), vec!["look", "Lookup"]);
+/// assert_eq!(completions("", &commands).len(), 3);
+/// assert!(completions("quit", &commands).is_empty());
+/// ```
+pub fn completions<'a>(input: &str, candidates: &'a [String]) -> Vec<&'a str> {
+    let input: String = input.to_lowercase();
+    candidates.iter().filter(|candidate| candidate.to_lowercase().starts_with(&input)).map(String::as_str).collect()
+}
+
+/// Returns the rest of the first candidate that would complete the given input, to suggest it as the user types,
+/// or None if the input is empty or no candidate is longer than it.
+/// ```
+/// # use simpleterm_profile::text::ghost_completion;
+/// let commands: Vec<String> = vec![String::from("look"), String::from("lookup")];
+/// assert_eq!(ghost_completion("lo", &commands), Some(String::from("ok")));
+/// assert_eq!(ghost_completion("look", &commands), Some(String::from("up")));
+/// assert_eq!(ghost_completion("", &commands), None);
+/// ```
+pub fn ghost_completion(input: &str, candidates: &[String]) -> Option<String> {
+    if input.is_empty() { return None; }
+    let typed: usize = input.chars().count();
+    completions(input, candidates).into_iter().find(|candidate| candidate.chars().count() > typed).map(|candidate| candidate.chars().skip(typed).collect())
+}
+
+/// Returns the color of the first rule whose pattern matches anywhere in the line, if any do.
+/// ```
+/// # use piston_window::types::Color;
+/// # use regex::Regex;
+/// # use simpleterm_profile::text::*;
+/// let rules: Vec<(Regex, Color)> = vec![(Regex::new("ERROR").unwrap(), CRIMSON), (Regex::new("WARN|ERROR").unwrap(), GOLD)];
+/// assert_eq!(line_color("12:00 ERROR disk full", &rules), Some(CRIMSON));
+/// assert_eq!(line_color("12:00 WARN disk almost full", &rules), Some(GOLD));
+/// assert_eq!(line_color("12:00 INFO disk fine", &rules), None);
+/// ```
+pub fn line_color(line: &str, rules: &[(Regex, Color)]) -> Option<Color> {
+    rules.iter().find(|(pattern, _)| pattern.is_match(line)).map(|(_, color)| *color)
+}
+
+/// Returns a copy of the given styles with every match of each rule's pattern drawn in the rule's color,
+/// filling in default styles where needed. Earlier rules win where matches overlap. Matches are found line by line,
+/// so they can't span a wrapped line break.
+/// ```
+/// # use piston_window::types::Color;
+/// # use regex::Regex;
+/// # use simpleterm_profile::text::*;
+/// let message: Vec<String> = vec![String::from("ping 10.0.0.1 ok")];
+/// let rules: Vec<(Regex, Color)> = vec![(Regex::new(r"\d+(\.\d+){3}").unwrap(), GOLD)];
+/// let styles: Vec<Vec<Style>> = highlight(&message, &[], &rules);
+/// assert_eq!(styles[0][4].color, None);
+/// assert_eq!(styles[0][5].color, Some(GOLD));
+/// assert_eq!(styles[0][12].color, Some(GOLD));
+/// assert_eq!(styles[0][13].color, None);
+/// ```
+pub fn highlight(message: &[String], styles: &[Vec<Style>], rules: &[(Regex, Color)]) -> Vec<Vec<Style>> {
+    message.iter().enumerate().map(|(i, line)| {
+        let mut line_styles: Vec<Style> = styles.get(i).cloned().unwrap_or_default();
+        line_styles.resize(line.chars().count(), Style::default());
+
+        for (pattern, color) in rules.iter().rev() {
+            for found in pattern.find_iter(line) {
+                let start: usize = line[..found.start()].chars().count();
+                let length: usize = found.as_str().chars().count();
+                for style in line_styles.iter_mut().skip(start).take(length) { style.color = Some(*color); }
+            }
+        }
+
+        line_styles
+    }).collect()
+}
+
+/// One line of a unified diff.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiffLine {
+    /// A hunk header like "@@ -1,4 +1,5 @@".
+    Hunk(String),
+    /// A line both texts share, shown for context.
+    Context(String),
+    /// A line only in the new text.
+    Added(String),
+    /// A line only in the old text.
+    Removed(String),
+}
+
+impl DiffLine {
+    /// Returns the line as it appears in a unified diff, with its leading marker.
+    pub fn to_line(&self) -> String {
+        match self {
+            DiffLine::Hunk(header) => header.clone(),
+            DiffLine::Context(line) => format!(" {}", line),
+            DiffLine::Added(line) => format!("+{}", line),
+            DiffLine::Removed(line) => format!("-{}", line),
+        }
+    }
+}
+
+/// Compares two texts line by line and returns a unified diff, keeping up to context unchanged lines around each change.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let diff: Vec<String> = unified_diff("a\nb\nc\nd", "a\nB\nc\nd", 1).iter().map(DiffLine::to_line).collect();
+/// assert_eq!(diff, vec!["@@ -1,3 +1,3 @@", " a", "-b", "+B", " c"]);
+/// ```
+pub fn unified_diff(old: &str, new: &str, context: usize) -> Vec<DiffLine> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let (n, m) = (old_lines.len(), new_lines.len());
+
+    let mut common: Vec<Vec<usize>> = vec![vec![0; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            common[i][j] = if old_lines[i] == new_lines[j] { common[i + 1][j + 1] + 1 } else { common[i + 1][j].max(common[i][j + 1]) };
+        }
+    }
+
+    // Each change is an (old index, new index) pair, where a side is None if the line isn't in that text.
+    let mut changes: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old_lines[i] == new_lines[j] {
+            changes.push((Some(i), Some(j)));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || common[i + 1][j] >= common[i][j + 1]) {
+            changes.push((Some(i), None));
+            i += 1;
+        } else {
+            changes.push((None, Some(j)));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..changes.len()).filter(|k| !matches!(changes[*k], (Some(_), Some(_)))).collect();
+    let mut diff: Vec<DiffLine> = Vec::new();
+    let mut k: usize = 0;
+    while k < changed.len() {
+        let start: usize = changed[k].saturating_sub(context);
+        let mut end: usize = changed[k] + context + 1;
+        while k + 1 < changed.len() && changed[k + 1] <= end + context { k += 1; end = changed[k] + context + 1; }
+        let end: usize = end.min(changes.len());
+        k += 1;
+
+        let hunk: &[(Option<usize>, Option<usize>)] = &changes[start..end];
+        let old_start: usize = changes[..start].iter().filter(|(o, _)| o.is_some()).count();
+        let new_start: usize = changes[..start].iter().filter(|(_, n)| n.is_some()).count();
+        let old_count: usize = hunk.iter().filter(|(o, _)| o.is_some()).count();
+        let new_count: usize = hunk.iter().filter(|(_, n)| n.is_some()).count();
+        diff.push(DiffLine::Hunk(format!("@@ -{},{} +{},{} @@", old_start + 1, old_count, new_start + 1, new_count)));
+
+        for change in hunk {
+            diff.push(match *change {
+                (Some(o), Some(_)) => DiffLine::Context(old_lines[o].to_string()),
+                (None, Some(n)) => DiffLine::Added(new_lines[n].to_string()),
+                (Some(o), None) => DiffLine::Removed(old_lines[o].to_string()),
+                (None, None) => continue,
+            });
+        }
+    }
+
+    diff
+}
+
+/// Hard wraps a line at exactly max_chars characters without touching its whitespace, starting each continuation line
+/// with the given prefix. Useful for code and diffs, where spacing matters.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// assert_eq!(wrap_exact("+    let x = 1;", 8, "+"), vec!["+    let", "+ x = 1;"]);
+/// ```
+pub fn wrap_exact(line: &str, max_chars: usize, prefix: &str) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    let prefix_len: usize = prefix.chars().count();
+    if chars.len() <= max_chars || max_chars <= prefix_len { return vec![line.to_string()]; }
+
+    let mut lines: Vec<String> = vec![chars[..max_chars].iter().collect()];
+    for chunk in chars[max_chars..].chunks(max_chars - prefix_len) {
+        lines.push(format!("{}{}", prefix, chunk.iter().collect::<String>()));
+    }
+    lines
+}
+
+/// Estimates how long it takes to read a message at the given reading speed in words per minute.
+/// Useful for picking a sensible timer to pass to `show`.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// # use std::time::Duration;
+/// assert_eq!(reading_time("four words to read", 240), Duration::from_secs(1));
+/// assert_eq!(reading_time("", 240), Duration::from_secs(0));
+/// ```
+pub fn reading_time(message: &str, wpm: u32) -> Duration {
+    let words: u64 = message.split_whitespace().count() as u64;
+    Duration::from_millis(words * 60_000 / wpm.max(1) as u64)
+}
+
+/// Returns the lines of a message as they look once the first count characters have been typed, with later lines left out.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// let message: Vec<String> = vec![String::from("Hi!"), String::from("Bye.")];
+/// assert_eq!(typed_lines(&message, 0), vec![""]);
+/// assert_eq!(typed_lines(&message, 2), vec!["Hi"]);
+/// assert_eq!(typed_lines(&message, 5), vec!["Hi!", "By"]);
+/// assert_eq!(typed_lines(&message, 100), vec!["Hi!", "Bye."]);
+/// ```
+pub fn typed_lines(message: &[String], count: usize) -> Vec<String> {
+    let mut typed: Vec<String> = Vec::new();
+    let mut remaining: usize = count;
+    for line in message {
+        let line_len: usize = line.chars().count();
+        typed.push(line.chars().take(remaining).collect());
+        if remaining <= line_len { break; }
+        remaining -= line_len;
+    }
+
+    if typed.is_empty() { typed.push(String::default()); }
+    typed
+}
+
+/// Formats a time, given in seconds since the Unix epoch, with a strftime-like format string in UTC.
+/// Supports %Y, %m, %d, %H, %M, %S, and %% for a literal percent sign. Anything else is copied as-is.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// assert_eq!(format_time("[%Y-%m-%d %H:%M:%S]", 1_700_000_000), "[2023-11-14 22:13:20]");
+/// assert_eq!(format_time("%H:%M 100%%", 0), "00:00 100%");
+/// ```
+pub fn format_time(format: &str, seconds: u64) -> String {
+    let days: i64 = (seconds / 86_400) as i64;
+    let (year, month, day) = civil_from_days(days);
+    let time_of_day: u64 = seconds % 86_400;
+
+    let mut formatted: String = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            formatted.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => formatted.push_str(&year.to_string()),
+            Some('m') => formatted.push_str(&format!("{:02}", month)),
+            Some('d') => formatted.push_str(&format!("{:02}", day)),
+            Some('H') => formatted.push_str(&format!("{:02}", time_of_day / 3600)),
+            Some('M') => formatted.push_str(&format!("{:02}", time_of_day / 60 % 60)),
+            Some('S') => formatted.push_str(&format!("{:02}", time_of_day % 60)),
+            Some('%') => formatted.push('%'),
+            Some(other) => { formatted.push('%'); formatted.push(other); },
+            None => formatted.push('%'),
+        }
+    }
+
+    formatted
+}
+
+// Converts days since the Unix epoch into a (year, month, day) date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z: i64 = days + 719_468;
+    let era: i64 = z.div_euclid(146_097);
+    let day_of_era: i64 = z - era * 146_097;
+    let year_of_era: i64 = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month: i64 = (5 * day_of_year + 2) / 153;
+    let day: u32 = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month: u32 = (if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 }) as u32;
+    let year: i64 = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Returns how far apart lines of text at the given font size and line spacing are drawn, from one baseline to the next.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// assert_eq!(line_height(20, DEFAULT_LINE_SPACING), 16.0);
+/// assert_eq!(line_height(20, 1.2), 24.0);
+/// ```
+pub fn line_height(font_size: FontSize, spacing: f64) -> f64 {
+    font_size as f64 * spacing
+}
+
+/// Returns how many lines of text at the given font size and line spacing fit between the top of the terminal and the input line,
+/// for a window of the given height. At least one line is always allowed.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// assert_eq!(max_lines(600.0, 32, DEFAULT_LINE_SPACING), 20);
+/// assert_eq!(max_lines(600.0, 32, 1.2), 13);
+/// assert_eq!(max_lines(120.0, 32, DEFAULT_LINE_SPACING), 1);
+/// ```
+pub fn max_lines(win_height: f64, font_size: FontSize, spacing: f64) -> usize {
+    let line_height: f64 = line_height(font_size, spacing);
+    let available: f64 = win_height - TEXT_OFFSET.1 - (TEXT_OFFSET.1 - 20.0) - (font_size as f64);
+
+    if available > 0.0 { (available / line_height) as usize + 1 } else { 1 }
+}
+
 /// Determines if enough time has passed since the last flash toggle. If so, save the current time and toggle the current flash state.
 /// ```
 /// # use std::{thread, time::{Duration, Instant}};
-/// # use simpleterm::{text::*, FLASH_TIME};
+/// # use simpleterm_profile::{text::*, FLASH_TIME};
 /// let mut start: Instant = Instant::now();
 /// thread::sleep(FLASH_TIME);
 /// assert!(check_flash(Instant::now(), &mut start));
@@ -137,17 +1176,65 @@ pub fn check_flash(now: Instant, then: &mut Instant) -> bool {
     }
 }
 
+/// Returns how many columns a line takes up on screen. Wide characters like CJK and most emoji take two columns,
+/// and combining marks and other zero-width characters take none.
+/// ```
+/// # use simpleterm_profile::text::*;
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("日本"), 4);
+/// assert_eq!(display_width("e\u{301}"), 1);
+/// assert_eq!(display_width("░▒▓"), 3);
+/// ```
+pub fn display_width(line: &str) -> usize {
+    UnicodeWidthStr::width(line)
+}
+
+/// Returns the color partway through a cycle that blends smoothly from each color to the next, and from the last back to the first,
+/// taking the given period to go all the way around. Returns None if there are no colors.
+/// ```
+/// # use std::time::Duration;
+/// # use piston_window::types::Color;
+/// # use simpleterm_profile::text::*;
+/// let colors: [Color; 2] = [[0.0, 0.0, 0.0, 1.0], [1.0, 0.5, 0.0, 1.0]];
+/// let period: Duration = Duration::from_secs(2);
+/// assert_eq!(cycle_color(&colors, period, Duration::ZERO), Some(colors[0]));
+/// assert_eq!(cycle_color(&colors, period, Duration::from_millis(500)), Some([0.5, 0.25, 0.0, 1.0]));
+/// assert_eq!(cycle_color(&colors, period, Duration::from_secs(1)), Some(colors[1]));
+/// assert_eq!(cycle_color(&colors, period, Duration::from_secs(2)), Some(colors[0]));
+/// assert_eq!(cycle_color(&[], period, Duration::ZERO), None);
+/// ```
+pub fn cycle_color(colors: &[Color], period: Duration, elapsed: Duration) -> Option<Color> {
+    if colors.len() < 2 || period.is_zero() { return colors.first().copied(); }
+
+    let progress: f64 = (elapsed.as_secs_f64() / period.as_secs_f64()).fract() * colors.len() as f64;
+    let from: Color = colors[progress as usize % colors.len()];
+    let to: Color = colors[(progress as usize + 1) % colors.len()];
+    let blend: f32 = progress.fract() as f32;
+
+    let mut color: Color = from;
+    for (channel, target) in color.iter_mut().zip(to.iter()) {
+        *channel += (target - *channel) * blend;
+    }
+    Some(color)
+}
+
 /// Determines the top left corner of the given art in the given window, in order for the art to be centered.
+/// The art is measured by its widest line, in display columns.
 /// ```
-/// # use simpleterm::{art::*, text::*};
-/// let art: Vec<String> = GEO.split('\n').map(String::from).collect();
-/// assert_eq!(place_art((800, 600).into(), &art, 10), (26.25, 40.0));
+/// # use simpleterm_profile::{art::*, text::*};
+/// let art: Vec<String> = DANCE_10.split('\n').map(String::from).collect();
+/// assert_eq!(place_art((800, 600).into(), &art, 10), (319.6, 296.55));
+///
+/// let wide: Vec<String> = vec![String::from("日本"), String::from("<>")];
+/// let narrow: Vec<String> = vec![String::from("-"), String::from("abcd")];
+/// assert_eq!(place_art((800, 600).into(), &wide, 10), place_art((800, 600).into(), &narrow, 10));
 /// ```
 pub fn place_art(win_size: Size, art: &[String], font_size: FontSize) -> (f64, f64) {
     let mid_x: f64 = win_size.width / 2.0;
     let mid_y: f64 = win_size.height / 2.0;
 
-    let art_mid_x: f64 = (art[0].len() as f64 / 2.0) * (font_size as f64 * 0.67);
+    let columns: usize = art.iter().map(|line| display_width(line)).max().unwrap_or(0);
+    let art_mid_x: f64 = (columns as f64 / 2.0) * (font_size as f64 * 0.67);
     let art_mid_y: f64 = (art.len() as f64 / 2.0) * (font_size as f64 * 0.23);
     
     (mid_x - art_mid_x, mid_y - art_mid_y)
@@ -0,0 +1,470 @@
+use crossterm::{cursor, event::{self, Event as TtyEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers}, execute, queue, style::{self, Print, ResetColor, SetBackgroundColor, SetForegroundColor}, terminal::{self, EnterAlternateScreen, LeaveAlternateScreen, SetTitle}};
+use graphics::{character::{Character, CharacterCache}, draw_state::DrawState, math::Matrix2d, types::{Color, FontSize}, Context, Graphics, Image, ImageSize, Viewport};
+use piston::*;
+use std::{any::Any, collections::{HashMap, VecDeque}, convert::Infallible, error::Error, io::{self, Stdout, Write}, time::{Duration, Instant}};
+
+use crate::{backend::{Scene, SceneContent, Screen}, draw::draw_scene, text::DEFAULT_LINE_SPACING};
+
+// How long the screen waits between frames. Terminals redraw far slower than windows, so about 30 frames a second is plenty.
+const FRAME_TIME: Duration = Duration::from_micros(33_333);
+
+// The first key code of the function keys, F1, which piston numbers on from there.
+const F1_CODE: u32 = 0x4000003A;
+
+/// A [Screen](../backend/trait.Screen.html) that draws in the real terminal the program was started from, using crossterm.
+/// [Terminal::new](../terminal/struct.Terminal.html#method.new) opens one instead of a window when the tty feature is enabled,
+/// and any terminal can draw on one with [with_screen](../terminal/struct.Terminal.html#method.with_screen).
+///
+/// The terminal's frames are drawn the same way as in a window, on a grid of character cells instead of pixels:
+/// each cell takes the color of whatever covers its center, and shows the character drawn over it, if any.
+/// Every character takes up one cell, so the grid is laid out as if the terminal's font were as wide as a cell.
+/// Pixel effects like scanlines and split transitions aren't drawn.
+///
+/// The screen switches the terminal to raw mode and its alternate screen while it's open, and puts both back when it closes.
+/// Ctrl+C closes it like closing a window.
+///
+/// ```no_run
+/// # use simpleterm_profile::{tty::TtyScreen, text::*};
+/// # use simpleterm_profile::terminal::Terminal;
+/// let screen: TtyScreen = TtyScreen::new("simpleterm test").unwrap();
+/// let mut term: Terminal = Terminal::with_screen(Box::new(screen), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+/// term.tell("No window needed.");
+/// ```
+pub struct TtyScreen {
+    stdout: Stdout,
+    title: String,
+    grid: CellGrid,
+    shown: Vec<Cell>,
+    cell_size: [f64; 2],
+    events: VecDeque<Event>,
+    next_frame: Instant,
+    closed: bool,
+}
+
+impl TtyScreen {
+    /// Takes over the terminal the program was started from, or returns an error if it isn't a terminal.
+    pub fn new(title: &str) -> Result<TtyScreen, Box<dyn Error>> {
+        let mut screen: TtyScreen = TtyScreen {
+            stdout: io::stdout(),
+            title: String::from(title),
+            grid: CellGrid::new(0, 0),
+            shown: Vec::new(),
+            cell_size: cell_size(32, DEFAULT_LINE_SPACING),
+            events: VecDeque::new(),
+            next_frame: Instant::now(),
+            closed: true,
+        };
+        screen.open()?;
+        Ok(screen)
+    }
+
+    // Switches the terminal to raw mode and its alternate screen, and sizes the grid to fit it.
+    fn open(&mut self) -> io::Result<()> {
+        terminal::enable_raw_mode()?;
+        self.closed = false;
+        execute!(self.stdout, EnterAlternateScreen, cursor::Hide, SetTitle(&self.title))?;
+        let (columns, rows): (u16, u16) = terminal::size()?;
+        self.grid = CellGrid::new(columns, rows);
+        self.shown.clear();
+        Ok(())
+    }
+
+    // Puts the terminal back the way it was before the screen opened.
+    fn restore(&mut self) {
+        if !self.closed {
+            let _ = execute!(self.stdout, ResetColor, cursor::Show, LeaveAlternateScreen);
+            let _ = terminal::disable_raw_mode();
+            self.closed = true;
+        }
+    }
+
+    // Returns the resize input for the grid's current size.
+    fn resize_input(&self) -> Input {
+        let size: Size = self.size();
+        Input::Resize(ResizeArgs { window_size: [size.width, size.height], draw_size: [self.grid.columns as u32, self.grid.rows as u32] })
+    }
+
+    // Queues the input for every event the terminal has received since the last check, waiting up to the given time for the first.
+    fn read_events(&mut self, mut wait: Duration) {
+        while !self.closed && event::poll(wait).unwrap_or(false) {
+            wait = Duration::ZERO;
+            match event::read() {
+                Ok(TtyEvent::Key(key)) => self.key_input(key),
+                Ok(TtyEvent::Resize(columns, rows)) => {
+                    self.grid = CellGrid::new(columns, rows);
+                    self.shown.clear();
+                    self.events.push_back(Event::Input(self.resize_input(), None));
+                }
+                Ok(TtyEvent::FocusGained) => self.events.push_back(Event::Input(Input::Focus(true), None)),
+                Ok(TtyEvent::FocusLost) => self.events.push_back(Event::Input(Input::Focus(false), None)),
+                Ok(_) => {}
+                Err(_) => {
+                    self.restore();
+                    self.events.push_back(Event::Input(Input::Close(CloseArgs), None));
+                }
+            }
+        }
+    }
+
+    // Queues the input for a key event. Terminals only say when keys are pressed, so each press is followed by its release,
+    // with the modifiers held around it pressed before and released after.
+    fn key_input(&mut self, key: KeyEvent) {
+        if key.kind == KeyEventKind::Release { return; }
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.restore();
+            self.events.push_back(Event::Input(Input::Close(CloseArgs), None));
+            return;
+        }
+
+        let modifiers: Vec<Key> = [(KeyModifiers::CONTROL, Key::LCtrl), (KeyModifiers::SHIFT, Key::LShift), (KeyModifiers::ALT, Key::LAlt)]
+            .iter()
+            .filter(|(modifier, _)| key.modifiers.contains(*modifier) || (key.code == KeyCode::BackTab && *modifier == KeyModifiers::SHIFT))
+            .map(|(_, key)| *key)
+            .collect();
+        let button = |key: Key, state: ButtonState| Event::Input(Input::Button(ButtonArgs { state, button: Button::Keyboard(key), scancode: None }), None);
+
+        self.events.extend(modifiers.iter().map(|modifier| button(*modifier, ButtonState::Press)));
+        if let Some(code) = map_key(key.code) {
+            self.events.push_back(button(code, ButtonState::Press));
+            if let KeyCode::Char(c) = key.code {
+                if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+                    self.events.push_back(Event::Input(Input::Text(c.to_string()), None));
+                }
+            }
+            self.events.push_back(button(code, ButtonState::Release));
+        }
+        self.events.extend(modifiers.iter().rev().map(|modifier| button(*modifier, ButtonState::Release)));
+    }
+
+    // Returns a render event if it's time for the next frame.
+    fn frame_due(&mut self) -> Option<Event> {
+        let now: Instant = Instant::now();
+        if now < self.next_frame { return None; }
+        self.next_frame = (self.next_frame + FRAME_TIME).max(now);
+
+        let size: Size = self.size();
+        let args: RenderArgs = RenderArgs {
+            ext_dt: 0.0,
+            window_size: [size.width, size.height],
+            draw_size: [self.grid.columns as u32, self.grid.rows as u32],
+        };
+        Some(Event::Loop(Loop::Render(args)))
+    }
+
+    // Writes every cell that changed since the last frame to the terminal.
+    fn present(&mut self) -> io::Result<()> {
+        let full: bool = self.shown.len() != self.grid.cells.len();
+        let mut colors: Option<(Color, Color)> = None;
+        for (i, cell) in self.grid.cells.iter().enumerate() {
+            if !full && self.shown[i] == *cell { continue; }
+
+            let (x, y): (u16, u16) = ((i % self.grid.columns as usize) as u16, (i / self.grid.columns as usize) as u16);
+            queue!(self.stdout, cursor::MoveTo(x, y))?;
+            if colors != Some((cell.bg, cell.fg)) {
+                queue!(self.stdout, SetBackgroundColor(tty_color(cell.bg)), SetForegroundColor(tty_color(cell.fg)))?;
+                colors = Some((cell.bg, cell.fg));
+            }
+            queue!(self.stdout, Print(cell.ch))?;
+        }
+        self.shown.clone_from(&self.grid.cells);
+        self.stdout.flush()
+    }
+}
+
+impl Drop for TtyScreen {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
+
+impl Screen for TtyScreen {
+    fn next_event(&mut self) -> Option<Event> {
+        loop {
+            if let Some(event) = self.events.pop_front() { return Some(event); }
+            if self.closed { return None; }
+
+            if let Some(event) = self.frame_due() { return Some(event); }
+            self.read_events(self.next_frame.saturating_duration_since(Instant::now()));
+        }
+    }
+
+    fn poll_event(&mut self) -> Option<Event> {
+        if self.events.is_empty() && !self.closed { self.read_events(Duration::ZERO); }
+        if let Some(event) = self.events.pop_front() { return Some(event); }
+        if self.closed { return Some(Event::Input(Input::Close(CloseArgs), None)); }
+        self.frame_due()
+    }
+
+    fn draw(&mut self, event: &Event, scene: &Scene) -> bool {
+        if event.render_args().is_none() || self.closed { return false; }
+
+        // Cells are sized to the text on screen, so changing between art and messages changes the size the terminal is laid out at.
+        let cell_size: [f64; 2] = match scene.content {
+            SceneContent::Art { font_size, .. } => art_cell_size(font_size),
+            _ => cell_size(scene.font_size, scene.spacing),
+        };
+        if cell_size != self.cell_size {
+            self.cell_size = cell_size;
+            self.events.push_back(Event::Input(self.resize_input(), None));
+        }
+
+        let mut glyphs: CellGlyphs = CellGlyphs::new(self.cell_size[0]);
+        let mut keyboard_glyphs: CellGlyphs = CellGlyphs::new(self.cell_size[0]);
+        let window_size: [f64; 2] = [self.size().width, self.size().height];
+        self.grid.draw(window_size, |c, g| draw_scene(scene, &mut glyphs, Some(&mut keyboard_glyphs), None, c, g));
+        if self.present().is_err() {
+            self.restore();
+            self.events.push_back(Event::Input(Input::Close(CloseArgs), None));
+        }
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size { width: self.grid.columns as f64 * self.cell_size[0], height: self.grid.rows as f64 * self.cell_size[1] }
+    }
+
+    fn set_size(&mut self, _size: Size) {}
+
+    fn close(&mut self) {
+        self.restore();
+    }
+
+    fn reopen(&mut self, _size: Size) {
+        self.events.clear();
+        if self.open().is_err() {
+            self.restore();
+            self.events.push_back(Event::Input(Input::Close(CloseArgs), None));
+        }
+    }
+
+    fn load_font(&mut self, _font: &str) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    fn text_width(&mut self, _font: &str, _size: FontSize, text: &str) -> Option<f64> {
+        Some(text.chars().count() as f64 * self.cell_size[0])
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+// Returns the size of a cell in points for messages in the given font size and line spacing,
+// so lines are a cell apart and lines wrapped to fit the window fit the grid.
+fn cell_size(font_size: FontSize, spacing: f64) -> [f64; 2] {
+    [font_size as f64 * 0.45, font_size as f64 * spacing]
+}
+
+// Returns the size of a cell in points for art in the given font size, matching how wide art is laid out.
+fn art_cell_size(font_size: FontSize) -> [f64; 2] {
+    [font_size as f64 * 0.6, font_size as f64 * DEFAULT_LINE_SPACING]
+}
+
+// Converts a crossterm key code into the piston key it stands for, if there is one.
+fn map_key(code: KeyCode) -> Option<Key> {
+    Some(match code {
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Enter => Key::Return,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::Tab | KeyCode::BackTab => Key::Tab,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::Insert => Key::Insert,
+        KeyCode::Esc => Key::Escape,
+        KeyCode::F(n @ 1..=12) => Key::from(F1_CODE + n as u32 - 1),
+        KeyCode::Char(' ') => Key::Space,
+        // Piston numbers the keys for printable characters by the lowercase character itself.
+        KeyCode::Char(c) if c.is_ascii() => Key::from(c.to_ascii_lowercase() as u32),
+        _ => return None,
+    })
+}
+
+// Converts a piston color into a 24-bit crossterm color.
+fn tty_color(color: Color) -> style::Color {
+    style::Color::Rgb {
+        r: (color[0].clamp(0.0, 1.0) * 255.0) as u8,
+        g: (color[1].clamp(0.0, 1.0) * 255.0) as u8,
+        b: (color[2].clamp(0.0, 1.0) * 255.0) as u8,
+    }
+}
+
+// Blends a color over another by the given amount, from 0.0 to 1.0.
+fn blend(under: Color, over: Color, amount: f32) -> Color {
+    let amount: f32 = amount.clamp(0.0, 1.0);
+    [0, 1, 2, 3].map(|i| over[i] * amount + under[i] * (1.0 - amount))
+}
+
+// One character cell of the terminal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Cell {
+    ch: char,
+    fg: Color,
+    bg: Color,
+}
+
+// A frame of character cells, drawn on with piston's graphics the way a raster is drawn on in pixels.
+struct CellGrid {
+    columns: u16,
+    rows: u16,
+    cells: Vec<Cell>,
+}
+
+impl CellGrid {
+    fn new(columns: u16, rows: u16) -> CellGrid {
+        CellGrid { columns, rows, cells: vec![Cell { ch: ' ', fg: [1.0; 4], bg: [0.0, 0.0, 0.0, 1.0] }; columns as usize * rows as usize] }
+    }
+
+    // Draws on the grid, calling draw with a context covering a window of the given size in points.
+    fn draw<F>(&mut self, window_size: [f64; 2], draw: F)
+    where
+        F: FnOnce(Context, &mut CellGraphics),
+    {
+        let draw_size: [u32; 2] = [self.columns as u32, self.rows as u32];
+        let viewport: Viewport = Viewport { rect: [0, 0, draw_size[0] as i32, draw_size[1] as i32], draw_size, window_size };
+        draw(Context::new_viewport(viewport), &mut CellGraphics { grid: self });
+    }
+
+    // Returns the cell at the given column and row, if it's inside the grid and the scissor rectangle.
+    fn cell_mut(&mut self, draw_state: &DrawState, column: u32, row: u32) -> Option<&mut Cell> {
+        if column >= self.columns as u32 || row >= self.rows as u32 { return None; }
+        if let Some([left, top, width, height]) = draw_state.scissor {
+            if column < left || row < top || column >= left.saturating_add(width) || row >= top.saturating_add(height) { return None; }
+        }
+        self.cells.get_mut(row as usize * self.columns as usize + column as usize)
+    }
+
+    // Fills the cells whose centers lie inside a triangle given in normalized device coordinates.
+    // A fill that covers a cell completely hides the character in it, and one that doesn't tints it.
+    fn fill_triangle(&mut self, draw_state: &DrawState, corners: [[f32; 2]; 3], color: Color) {
+        let points: [[f32; 2]; 3] = corners.map(|[x, y]| [(x + 1.0) / 2.0 * self.columns as f32, (1.0 - y) / 2.0 * self.rows as f32]);
+        let [[x0, y0], [x1, y1], [x2, y2]] = points;
+        let area: f32 = (x1 - x0) * (y2 - y0) - (x2 - x0) * (y1 - y0);
+        if area == 0.0 || !area.is_finite() { return; }
+
+        let first_column: u32 = (x0.min(x1).min(x2) - 0.5).ceil().max(0.0) as u32;
+        let last_column: u32 = ((x0.max(x1).max(x2) - 0.5).ceil().max(0.0) as u32).min(self.columns as u32);
+        let first_row: u32 = (y0.min(y1).min(y2) - 0.5).ceil().max(0.0) as u32;
+        let last_row: u32 = ((y0.max(y1).max(y2) - 0.5).ceil().max(0.0) as u32).min(self.rows as u32);
+        for row in first_row..last_row {
+            for column in first_column..last_column {
+                let (px, py): (f32, f32) = (column as f32 + 0.5, row as f32 + 0.5);
+                let w1: f32 = ((px - x0) * (y2 - y0) - (x2 - x0) * (py - y0)) / area;
+                let w2: f32 = ((x1 - x0) * (py - y0) - (px - x0) * (y1 - y0)) / area;
+                if w1 < 0.0 || w2 < 0.0 || w1 + w2 > 1.0 { continue; }
+
+                if let Some(cell) = self.cell_mut(draw_state, column, row) {
+                    cell.bg = blend(cell.bg, color, color[3]);
+                    cell.fg = blend(cell.fg, color, color[3]);
+                    if color[3] >= 1.0 { cell.ch = ' '; }
+                }
+            }
+        }
+    }
+
+    // Puts a character in the cell under the given point in normalized device coordinates, blended over the cell's background by the color's alpha.
+    fn put_char(&mut self, draw_state: &DrawState, [x, y]: [f64; 2], ch: char, color: Color) {
+        let (column, row): (f64, f64) = ((x + 1.0) / 2.0 * self.columns as f64, (1.0 - y) / 2.0 * self.rows as f64);
+        if ch.is_whitespace() || color[3] <= 0.0 || column < 0.0 || row < 0.0 { return; }
+        if let Some(cell) = self.cell_mut(draw_state, column as u32, row as u32) {
+            cell.ch = ch;
+            cell.fg = blend(cell.bg, color, color[3]);
+        }
+    }
+}
+
+// Draws on a CellGrid with piston's graphics, so every draw function works on it.
+struct CellGraphics<'a> {
+    grid: &'a mut CellGrid,
+}
+
+impl Graphics for CellGraphics<'_> {
+    type Texture = CellTexture;
+
+    fn clear_color(&mut self, color: Color) {
+        self.grid.cells.fill(Cell { ch: ' ', fg: color, bg: color });
+    }
+
+    fn clear_stencil(&mut self, _value: u8) {}
+
+    fn tri_list<F>(&mut self, draw_state: &DrawState, color: &[f32; 4], mut f: F)
+    where
+        F: FnMut(&mut dyn FnMut(&[[f32; 2]])),
+    {
+        let grid: &mut CellGrid = self.grid;
+        f(&mut |vertices: &[[f32; 2]]| {
+            for corners in vertices.chunks_exact(3) {
+                grid.fill_triangle(draw_state, [corners[0], corners[1], corners[2]], *color);
+            }
+        });
+    }
+
+    fn tri_list_uv<F>(&mut self, draw_state: &DrawState, color: &[f32; 4], texture: &CellTexture, mut f: F)
+    where
+        F: FnMut(&mut dyn FnMut(&[[f32; 2]], &[[f32; 2]])),
+    {
+        // Textured quads come in as two triangles, and the character goes in the cell under the middle of each quad.
+        let grid: &mut CellGrid = self.grid;
+        f(&mut |vertices: &[[f32; 2]], _: &[[f32; 2]]| {
+            for quad in vertices.chunks_exact(6) {
+                let center: [f64; 2] = [0, 1].map(|i| quad.iter().map(|vertex| vertex[i] as f64).sum::<f64>() / 6.0);
+                grid.put_char(draw_state, center, texture.ch, *color);
+            }
+        });
+    }
+
+    fn image(&mut self, image: &Image, texture: &CellTexture, draw_state: &DrawState, transform: Matrix2d) {
+        let (width, height): (u32, u32) = texture.get_size();
+        let source: [f64; 4] = image.source_rectangle.unwrap_or([0.0, 0.0, width as f64, height as f64]);
+        let [x, y, w, h] = image.rectangle.unwrap_or([0.0, 0.0, source[2], source[3]]);
+        let (cx, cy): (f64, f64) = (x + w / 2.0, y + h / 2.0);
+        let center: [f64; 2] = [0, 1].map(|i| transform[i][0] * cx + transform[i][1] * cy + transform[i][2]);
+        self.grid.put_char(draw_state, center, texture.ch, image.color.unwrap_or([1.0; 4]));
+    }
+}
+
+// The "texture" of a character drawn on a CellGrid, which is just the character.
+struct CellTexture {
+    ch: char,
+}
+
+impl ImageSize for CellTexture {
+    fn get_size(&self) -> (u32, u32) {
+        (1, 1)
+    }
+}
+
+// A glyph cache for drawing on a CellGrid, where every character is a cell wide whatever its size.
+struct CellGlyphs {
+    advance: f64,
+    textures: HashMap<char, CellTexture>,
+}
+
+impl CellGlyphs {
+    fn new(advance: f64) -> CellGlyphs {
+        CellGlyphs { advance, textures: HashMap::new() }
+    }
+}
+
+impl CharacterCache for CellGlyphs {
+    type Texture = CellTexture;
+    type Error = Infallible;
+
+    fn character(&mut self, font_size: FontSize, ch: char) -> Result<Character<'_, CellTexture>, Infallible> {
+        // The glyph's box reaches from most of the font size above the baseline to a little below it, so its middle is in the middle of lowercase letters.
+        let size: f64 = font_size as f64;
+        Ok(Character {
+            offset: [0.0, size * 0.85],
+            advance_size: [self.advance, 0.0],
+            atlas_offset: [0.0, 0.0],
+            atlas_size: [self.advance, size],
+            texture: self.textures.entry(ch).or_insert(CellTexture { ch }),
+        })
+    }
+}
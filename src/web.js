@@ -0,0 +1,34 @@
+// The worker's half of the canvas screen in web.rs. The page's half is web/simpleterm.js.
+// Input arrives in a SharedArrayBuffer of 32-bit integers: a count of the records written so far,
+// then a ring of RECORDS records of [kind, a, b, c], which the page overwrites once the worker falls that far behind.
+// RECORDS matches INPUT_RECORDS in web.rs.
+const HEADER = 4;
+const RECORD = 4;
+const RECORDS = 256;
+
+export function waitForInput(input, read, timeout) {
+    const view = new Int32Array(input);
+    if (Atomics.load(view, 0) === read && timeout > 0) {
+        Atomics.wait(view, 0, read, timeout);
+    }
+    return Atomics.load(view, 0);
+}
+
+export function readInput(input, index, record) {
+    const view = new Int32Array(input);
+    const at = HEADER + (((index % RECORDS) + RECORDS) % RECORDS) * RECORD;
+    record.set(view.subarray(at, at + RECORD));
+}
+
+export function presentFrame(pixels, width, height) {
+    const copy = pixels.slice();
+    self.postMessage({ simpleterm: "frame", width, height, pixels: copy }, [copy.buffer]);
+}
+
+export function resizeCanvas(width, height) {
+    self.postMessage({ simpleterm: "resize", width, height });
+}
+
+export function closeCanvas() {
+    self.postMessage({ simpleterm: "close" });
+}
@@ -0,0 +1,248 @@
+use graphics::types::FontSize;
+use piston::*;
+use std::{any::Any, collections::VecDeque, error::Error, time::Duration};
+use wasm_bindgen::prelude::*;
+use web_time::Instant;
+
+use crate::{backend::{Scene, Screen}, raster::SoftwareRenderer};
+
+// How long the canvas waits between frames, for about 60 frames a second.
+const FRAME_TIME: Duration = Duration::from_micros(16_667);
+
+// How many input records the page keeps before overwriting the oldest, matching RECORDS in web.js.
+const INPUT_RECORDS: i32 = 256;
+
+// The kinds of input record the page sends, matching the ones written by web/simpleterm.js.
+const KEY_DOWN: i32 = 1;
+const KEY_UP: i32 = 2;
+const TEXT: i32 = 3;
+const MOUSE_MOVE: i32 = 4;
+const MOUSE_DOWN: i32 = 5;
+const MOUSE_UP: i32 = 6;
+const SCROLL: i32 = 7;
+const RESIZE: i32 = 8;
+const FOCUS: i32 = 9;
+
+#[wasm_bindgen(module = "/src/web.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = waitForInput)]
+    fn wait_for_input(input: &JsValue, read: i32, timeout: f64) -> i32;
+
+    #[wasm_bindgen(js_name = readInput)]
+    fn read_input(input: &JsValue, index: i32, record: &mut [i32]);
+
+    #[wasm_bindgen(js_name = presentFrame)]
+    fn present_frame(pixels: &[u8], width: u32, height: u32);
+
+    #[wasm_bindgen(js_name = resizeCanvas)]
+    fn resize_canvas(width: f64, height: f64);
+
+    #[wasm_bindgen(js_name = closeCanvas)]
+    fn close_canvas();
+}
+
+/// A [Screen](../backend/trait.Screen.html) that draws on a canvas in a web page, for running scripts in the browser.
+///
+/// Scripts wait for input the same way they do in a window, so the terminal runs in a web worker where waiting doesn't freeze the page.
+/// Frames are drawn with the CPU and posted to the page, and the page passes input back through a SharedArrayBuffer.
+/// `web/simpleterm.js` does the page's half: its `attach` function connects a canvas to the worker and posts it the buffer to open the screen with.
+/// SharedArrayBuffers are only available on cross-origin isolated pages, so the page has to be served with the
+/// `Cross-Origin-Opener-Policy: same-origin` and `Cross-Origin-Embedder-Policy: require-corp` headers.
+///
+/// The bundled fonts are built in, and others can be added with [add_font](#method.add_font), since the browser has no resources folder to load them from.
+/// Parts of the terminal that need threads or files, like running commands or saving settings, don't work in the browser.
+///
+/// The worker builds the script with wasm-bindgen and starts it once the buffer arrives:
+///
+/// ```js
+/// import init, { run } from "./pkg/my_script.js";
+/// self.onmessage = async (event) => { await init(); run(event.data); };
+/// ```
+///
+/// ```no_run
+/// # use wasm_bindgen::prelude::*;
+/// # use simpleterm_profile::{web::CanvasScreen, text::*};
+/// # use simpleterm_profile::terminal::Terminal;
+/// // Called from the worker with the buffer attach posted to it.
+/// #[wasm_bindgen]
+/// pub fn run(input: JsValue) {
+///     let screen: CanvasScreen = CanvasScreen::new(input, (800, 600));
+///     let mut term: Terminal = Terminal::with_screen(Box::new(screen), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+///     term.tell("Hello from the browser!");
+/// }
+/// ```
+pub struct CanvasScreen {
+    input: JsValue,
+    read: i32,
+    renderer: SoftwareRenderer,
+    size: Size,
+    scale: f64,
+    events: VecDeque<Event>,
+    next_frame: Instant,
+    closed: bool,
+}
+
+impl CanvasScreen {
+    /// Opens a screen on the canvas that posted the given input buffer, resizing the canvas to the given size in CSS pixels.
+    pub fn new(input: JsValue, size: (u32, u32)) -> CanvasScreen {
+        let mut renderer: SoftwareRenderer = SoftwareRenderer::new();
+        renderer.add_font("LeagueSpartan-Regular.ttf", include_bytes!("../resources/LeagueSpartan-Regular.ttf"));
+        renderer.add_font("LeagueMono-Regular.ttf", include_bytes!("../resources/LeagueMono-Regular.ttf"));
+
+        let mut screen: CanvasScreen = CanvasScreen {
+            input,
+            read: 0,
+            renderer,
+            size: size.into(),
+            scale: 1.0,
+            events: VecDeque::new(),
+            next_frame: Instant::now(),
+            closed: false,
+        };
+        screen.set_size(size.into());
+        screen
+    }
+
+    /// Adds a font under the given name, so it can be used like a font file in the resources folder.
+    pub fn add_font(&mut self, name: &str, data: &'static [u8]) {
+        self.renderer.add_font(name, data);
+    }
+
+    // Queues every input record the page has written since the last one read, waiting up to the given time for the first.
+    fn read_events(&mut self, timeout: Duration) {
+        let written: i32 = wait_for_input(&self.input, self.read, timeout.as_secs_f64() * 1000.0);
+        if written.wrapping_sub(self.read) > INPUT_RECORDS {
+            // The page has overwritten the records this screen fell too far behind to read.
+            self.read = written.wrapping_sub(INPUT_RECORDS);
+        }
+        let mut record: [i32; 4] = [0; 4];
+        while self.read != written {
+            read_input(&self.input, self.read, &mut record);
+            self.read = self.read.wrapping_add(1);
+            if let Some(input) = input_event(record) {
+                if let Input::Resize(args) = input {
+                    self.size = args.window_size.into();
+                    self.scale = args.draw_size[0] as f64 / args.window_size[0].max(1.0);
+                }
+                self.events.push_back(Event::Input(input, None));
+            }
+        }
+    }
+
+    // Returns the size of the canvas in pixels.
+    fn draw_size(&self) -> [u32; 2] {
+        [(self.size.width * self.scale).round() as u32, (self.size.height * self.scale).round() as u32]
+    }
+}
+
+impl Screen for CanvasScreen {
+    fn next_event(&mut self) -> Option<Event> {
+        loop {
+            if self.closed { return None; }
+            if let Some(event) = self.events.pop_front() { return Some(event); }
+
+            let now: Instant = Instant::now();
+            if now >= self.next_frame {
+                self.next_frame = (self.next_frame + FRAME_TIME).max(now);
+                let args: RenderArgs = RenderArgs { ext_dt: 0.0, window_size: self.size.into(), draw_size: self.draw_size() };
+                return Some(Event::Loop(Loop::Render(args)));
+            }
+            self.read_events(self.next_frame - now);
+        }
+    }
+
+    fn poll_event(&mut self) -> Option<Event> {
+        if self.closed { return Some(Event::Input(Input::Close(CloseArgs), None)); }
+        if self.events.is_empty() { self.read_events(Duration::ZERO); }
+        if let Some(event) = self.events.pop_front() { return Some(event); }
+
+        let now: Instant = Instant::now();
+        if now < self.next_frame { return None; }
+        self.next_frame = (self.next_frame + FRAME_TIME).max(now);
+        let args: RenderArgs = RenderArgs { ext_dt: 0.0, window_size: self.size.into(), draw_size: self.draw_size() };
+        Some(Event::Loop(Loop::Render(args)))
+    }
+
+    fn draw(&mut self, event: &Event, scene: &Scene) -> bool {
+        let args: RenderArgs = match event.render_args() {
+            Some(args) => args,
+            None => return false,
+        };
+
+        let raster = self.renderer.render(scene, args.draw_size);
+        let [width, height] = raster.size();
+        present_frame(raster.pixels().as_flattened(), width, height);
+        true
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+        resize_canvas(size.width, size.height);
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+        close_canvas();
+    }
+
+    fn reopen(&mut self, size: Size) {
+        self.closed = false;
+        self.set_size(size);
+    }
+
+    fn load_font(&mut self, font: &str) -> Result<(), Box<dyn Error>> {
+        self.renderer.load_font(font)
+    }
+
+    fn text_width(&mut self, font: &str, size: FontSize, text: &str) -> Option<f64> {
+        self.renderer.text_width(font, size, text)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+/// Returns the input a record from the page stands for, as [kind, a, b, c], or None if it stands for nothing the terminal handles.
+/// Keys are piston key codes, mouse buttons are numbered the way the browser numbers them, and sizes are in CSS pixels,
+/// with resizes giving the pixels per CSS pixel in thousandths.
+/// ```
+/// # use piston::*;
+/// # use simpleterm_profile::web::input_event;
+/// assert_eq!(input_event([1, 0x0D, 0, 0]), Some(Input::Button(ButtonArgs { state: ButtonState::Press, button: Button::Keyboard(Key::Return), scancode: None })));
+/// assert_eq!(input_event([3, 'é' as i32, 0, 0]), Some(Input::Text(String::from("é"))));
+/// assert_eq!(input_event([8, 800, 600, 2000]), Some(Input::Resize(ResizeArgs { window_size: [800.0, 600.0], draw_size: [1600, 1200] })));
+/// assert_eq!(input_event([0, 0, 0, 0]), None);
+/// ```
+pub fn input_event([kind, a, b, c]: [i32; 4]) -> Option<Input> {
+    let button = |state: ButtonState, button: Button| Input::Button(ButtonArgs { state, button, scancode: None });
+    let mouse_button = |number: i32| match number {
+        0 => MouseButton::Left,
+        1 => MouseButton::Middle,
+        2 => MouseButton::Right,
+        3 => MouseButton::X1,
+        4 => MouseButton::X2,
+        _ => MouseButton::Unknown,
+    };
+
+    match kind {
+        KEY_DOWN => Some(button(ButtonState::Press, Button::Keyboard(Key::from(a as u32)))),
+        KEY_UP => Some(button(ButtonState::Release, Button::Keyboard(Key::from(a as u32)))),
+        TEXT => char::from_u32(a as u32).map(|c| Input::Text(c.to_string())),
+        MOUSE_MOVE => Some(Input::Move(Motion::MouseCursor([a as f64, b as f64]))),
+        MOUSE_DOWN => Some(button(ButtonState::Press, Button::Mouse(mouse_button(a)))),
+        MOUSE_UP => Some(button(ButtonState::Release, Button::Mouse(mouse_button(a)))),
+        SCROLL => Some(Input::Move(Motion::MouseScroll([a as f64, b as f64]))),
+        RESIZE => {
+            let scale: f64 = if c > 0 { c as f64 / 1000.0 } else { 1.0 };
+            let draw_size: [u32; 2] = [(a as f64 * scale).round() as u32, (b as f64 * scale).round() as u32];
+            Some(Input::Resize(ResizeArgs { window_size: [a as f64, b as f64], draw_size }))
+        },
+        FOCUS => Some(Input::Focus(a != 0)),
+        _ => None,
+    }
+}
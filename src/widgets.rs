@@ -0,0 +1,132 @@
+use std::{fs, time::Duration};
+use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// A source of live data shown in the terminal's widget panel.
+/// Implement this to feed the panel custom data, like the weather from an API.
+/// ```
+/// # use simpleterm_profile::widgets::*;
+/// struct Weather;
+/// impl Widget for Weather {
+///     fn label(&self) -> String { String::from("WX") }
+///     fn value(&mut self) -> String { String::from("rain") }
+/// }
+/// 
+/// let mut widgets: Vec<Box<dyn Widget>> = vec![Box::new(Weather)];
+/// assert_eq!(read_widgets(&mut widgets), vec![(String::from("WX"), String::from("rain"))]);
+/// ```
+pub trait Widget {
+    /// The short label shown next to the widget's value.
+    fn label(&self) -> String;
+
+    /// The widget's current value. This is called every frame, so slow sources should cache their last reading.
+    fn value(&mut self) -> String;
+}
+
+/// Which corner of the terminal the widget panel is drawn in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Reads the current label and value of every widget.
+pub fn read_widgets(widgets: &mut [Box<dyn Widget>]) -> Vec<(String, String)> {
+    widgets.iter_mut().map(|widget| (widget.label(), widget.value())).collect()
+}
+
+const SAMPLE_TIME: Duration = Duration::from_millis(1000);
+
+/// Shows the current UTC time of day.
+pub struct Clock;
+
+impl Widget for Clock {
+    fn label(&self) -> String { String::from("TIME") }
+
+    fn value(&mut self) -> String {
+        let seconds: u64 = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs());
+        format!("{:02}:{:02}:{:02}", seconds / 3600 % 24, seconds / 60 % 60, seconds % 60)
+    }
+}
+
+/// Shows how busy the CPU has been over the last second. Reads /proc/stat, so it only has a value on Linux.
+#[derive(Default)]
+pub struct CpuUsage {
+    last_sample: Option<(Instant, u64, u64)>,
+    reading: String,
+}
+
+impl Widget for CpuUsage {
+    fn label(&self) -> String { String::from("CPU") }
+
+    fn value(&mut self) -> String {
+        let due: bool = self.last_sample.is_none_or(|(sampled, _, _)| sampled.elapsed() >= SAMPLE_TIME);
+        if due {
+            if let Some((busy, total)) = cpu_times() {
+                if let Some((_, last_busy, last_total)) = self.last_sample {
+                    let busy_delta: u64 = busy.saturating_sub(last_busy);
+                    let total_delta: u64 = total.saturating_sub(last_total).max(1);
+                    self.reading = format!("{}%", busy_delta * 100 / total_delta);
+                }
+                self.last_sample = Some((Instant::now(), busy, total));
+            }
+        }
+
+        if self.reading.is_empty() { String::from("--") } else { self.reading.clone() }
+    }
+}
+
+/// Shows how much memory is in use. Reads /proc/meminfo, so it only has a value on Linux.
+#[derive(Default)]
+pub struct MemoryUsage;
+
+impl Widget for MemoryUsage {
+    fn label(&self) -> String { String::from("RAM") }
+
+    fn value(&mut self) -> String {
+        let meminfo: String = fs::read_to_string("/proc/meminfo").unwrap_or_default();
+        let field = |name: &str| -> Option<u64> {
+            meminfo.lines()
+                .find(|line| line.starts_with(name))
+                .and_then(|line| line.split_whitespace().nth(1))
+                .and_then(|kb| kb.parse().ok())
+        };
+
+        match (field("MemTotal:"), field("MemAvailable:")) {
+            (Some(total), Some(available)) if total > 0 => format!("{}%", (total - available.min(total)) * 100 / total),
+            _ => String::from("--"),
+        }
+    }
+}
+
+/// Shows the battery's charge. Reads /sys/class/power_supply, so it only has a value on Linux machines with a battery.
+#[derive(Default)]
+pub struct Battery;
+
+impl Widget for Battery {
+    fn label(&self) -> String { String::from("BAT") }
+
+    fn value(&mut self) -> String {
+        let capacity: Option<String> = fs::read_dir("/sys/class/power_supply").ok().and_then(|supplies| {
+            supplies.flatten()
+                .filter(|supply| supply.file_name().to_string_lossy().starts_with("BAT"))
+                .find_map(|supply| fs::read_to_string(supply.path().join("capacity")).ok())
+        });
+
+        match capacity {
+            Some(capacity) => format!("{}%", capacity.trim()),
+            None => String::from("--"),
+        }
+    }
+}
+
+// Reads the busy and total CPU time from the first line of /proc/stat.
+fn cpu_times() -> Option<(u64, u64)> {
+    let stat: String = fs::read_to_string("/proc/stat").ok()?;
+    let times: Vec<u64> = stat.lines().next()?.split_whitespace().skip(1).filter_map(|time| time.parse().ok()).collect();
+    let total: u64 = times.iter().sum();
+    let idle: u64 = times.iter().skip(3).take(2).sum();
+
+    Some((total - idle, total))
+}
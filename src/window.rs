@@ -0,0 +1,344 @@
+use gfx_device_gl::Device;
+use piston_window::{*, character::CharacterCache, types::{Color, FontSize}};
+use std::{any::Any, collections::HashMap, error::Error, mem, time::{Duration, Instant}};
+
+use crate::{backend::{Scene, Screen}, draw::*, text::*, MIN_WINDOW_SIZE};
+
+// How long polling waits between frames, for about 60 frames a second like the window's own event loop.
+const FRAME_TIME: Duration = Duration::from_micros(16_667);
+
+/// The default [Screen](../backend/trait.Screen.html): a piston_window window drawn with OpenGL.
+/// Fonts, scanlines, and the target split transitions are rendered to are kept between frames,
+/// and created again whenever the window is reopened.
+///
+/// ```no_run
+/// # use simpleterm_profile::{window::PistonScreen, text::*};
+/// # use simpleterm_profile::terminal::Terminal;
+/// let screen: PistonScreen = PistonScreen::new("simpleterm test", (800, 600)).unwrap();
+/// let mut term: Terminal = Terminal::with_screen(Box::new(screen), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32).unwrap();
+/// term.tell("Same terminal, any screen.");
+/// ```
+pub struct PistonScreen {
+    /// The window frames are drawn on.
+    pub window: PistonWindow,
+    title: String,
+    fonts: HashMap<String, Glyphs>,
+    keyboard_glyphs: Option<(String, Glyphs)>,
+    scanlines: Option<Scanlines<G2dTexture>>,
+    split_target: Option<SceneTarget>,
+    monitor: Option<usize>,
+    next_frame: Instant,
+    swap_after_draw: bool,
+    wants_attention: bool,
+}
+
+impl PistonScreen {
+    /// Opens a window with the given title and size, falling back to OpenGL 2.1 if the default OpenGL version is unavailable.
+    pub fn new(title: &str, size: (u32, u32)) -> Result<PistonScreen, Box<dyn Error>> {
+        PistonScreen::open(title, size, None)
+    }
+
+    /// Opens a window like [new](#method.new), centered on the monitor with the given index.
+    /// If there's no monitor with that index, the window opens wherever the system puts it.
+    pub fn on_monitor(title: &str, size: (u32, u32), monitor: usize) -> Result<PistonScreen, Box<dyn Error>> {
+        PistonScreen::open(title, size, Some(monitor))
+    }
+
+    // Opens a window with the given title and size, centered on the monitor with the given index if there is one.
+    pub(crate) fn open(title: &str, size: (u32, u32), monitor: Option<usize>) -> Result<PistonScreen, Box<dyn Error>> {
+        Ok(PistonScreen {
+            window: build_window(title, size.into(), monitor)?,
+            title: String::from(title),
+            fonts: HashMap::new(),
+            keyboard_glyphs: None,
+            scanlines: None,
+            split_target: None,
+            monitor,
+            next_frame: Instant::now(),
+            swap_after_draw: false,
+            wants_attention: false,
+        })
+    }
+
+    // Returns the glyphs for the given font, loading them the first time they're needed.
+    fn glyphs(&mut self, font: &str) -> &mut Glyphs {
+        let window: &mut PistonWindow = &mut self.window;
+        self.fonts.entry(String::from(font)).or_insert_with(|| load_font(window, font))
+    }
+
+    // Stops asking for attention once the window is focused, since X11 leaves the urgency hint set until it's taken off.
+    fn track_focus(&mut self, event: &Event) {
+        if self.wants_attention && event.focus_args() == Some(true) {
+            self.wants_attention = false;
+            set_attention(self.window.window.ctx.window(), false);
+        }
+    }
+}
+
+impl Screen for PistonScreen {
+    fn next_event(&mut self) -> Option<Event> {
+        self.swap_after_draw = false;
+        let event: Option<Event> = self.window.next();
+        if let Some(event) = &event { self.track_focus(event); }
+        event
+    }
+
+    // piston's event loop sleeps until the next frame when nothing has happened, so polling reads the window directly,
+    // and the frame drawn for a polled render event is shown as soon as it's drawn instead of waiting for the loop to swap it in.
+    fn poll_event(&mut self) -> Option<Event> {
+        let event: Event = if let Some(event) = Window::poll_event(&mut self.window) {
+            event
+        } else if self.window.should_close() {
+            Event::Input(Input::Close(CloseArgs), None)
+        } else {
+            let now: Instant = Instant::now();
+            if now < self.next_frame { return None; }
+            self.next_frame = now + FRAME_TIME;
+            self.swap_after_draw = true;
+            RenderArgs { ext_dt: 0.0, window_size: self.window.size().into(), draw_size: self.window.draw_size().into() }.into()
+        };
+        self.window.event(&event);
+        self.track_focus(&event);
+        Some(event)
+    }
+
+    fn draw(&mut self, event: &Event, scene: &Scene) -> bool {
+        let args: RenderArgs = match event.render_args() {
+            Some(args) => args,
+            None => return false,
+        };
+        if scene.keyboard.is_some() && self.keyboard_glyphs.as_ref().is_none_or(|(font, _)| font != scene.keyboard_font) {
+            self.keyboard_glyphs = Some((String::from(scene.keyboard_font), load_font(&mut self.window, scene.keyboard_font)));
+        }
+
+        let scanlines: Option<&Scanlines<G2dTexture>> = cached_scanlines(&mut self.scanlines, &mut self.window, scene.size, (scene.bg, scene.fg), scene.scanlines);
+        let window: &mut PistonWindow = &mut self.window;
+        let glyphs: &mut Glyphs = self.fonts.entry(String::from(scene.font)).or_insert_with(|| load_font(window, scene.font));
+        let mut keyboard_glyphs: Option<&mut Glyphs> = self.keyboard_glyphs.as_mut().map(|(_, glyphs)| glyphs).filter(|_| scene.keyboard.is_some());
+        let frame = |c: Context, g: &mut G2d, device: &mut Device| {
+            draw_scene(scene, glyphs, keyboard_glyphs.as_deref_mut(), scanlines, c, g);
+
+            glyphs.factory.encoder.flush(device);
+            if let Some(keyboard_glyphs) = keyboard_glyphs { keyboard_glyphs.factory.encoder.flush(device); }
+        };
+
+        let target: Option<&SceneTarget> = match scene.split {
+            Some(_) => cached_scene_target(&mut self.split_target, &mut self.window, args.draw_size),
+            None => None,
+        };
+        let drawn: Option<()> = match (scene.split, target) {
+            (Some(split), Some(target)) => {
+                target.render(&mut self.window, args.viewport(), frame);
+                self.window.draw_2d(event, |c, g, _| draw_split_scene(scene, (target.texture(), true), split, scanlines, c, g))
+            },
+            _ => self.window.draw_2d(event, frame),
+        };
+        if mem::take(&mut self.swap_after_draw) {
+            self.window.window.swap_buffers();
+            self.window.event(&Event::from(AfterRenderArgs));
+        }
+        drawn.is_some()
+    }
+
+    fn size(&self) -> Size {
+        self.window.size()
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.window.set_size(size);
+    }
+
+    fn close(&mut self) {
+        self.window.set_should_close(true);
+    }
+
+    fn reopen(&mut self, size: Size) {
+        self.window = build_window(&self.title, size, self.monitor).unwrap();
+
+        // Glyphs and textures belong to the window that made them, so everything made for the old one is dropped.
+        self.fonts.clear();
+        self.keyboard_glyphs = None;
+        self.scanlines = None;
+        self.split_target = None;
+    }
+
+    fn load_font(&mut self, font: &str) -> Result<(), Box<dyn Error>> {
+        if !self.fonts.contains_key(font) {
+            let glyphs: Glyphs = try_load_font(&mut self.window, font)?;
+            self.fonts.insert(String::from(font), glyphs);
+        }
+        Ok(())
+    }
+
+    fn text_width(&mut self, font: &str, size: FontSize, text: &str) -> Option<f64> {
+        self.glyphs(font).width(size, text).ok()
+    }
+
+    fn position(&self) -> Option<(i32, i32)> {
+        self.window.get_position().map(|pos| (pos.x, pos.y))
+    }
+
+    fn set_position(&mut self, x: i32, y: i32) {
+        self.window.set_position([x, y]);
+    }
+
+    fn monitor_count(&self) -> usize {
+        self.window.window.ctx.window().get_available_monitors().count()
+    }
+
+    fn monitor_bounds(&self, index: Option<usize>) -> Option<(f64, f64, f64, f64)> {
+        monitor_bounds(self.window.window.ctx.window(), index)
+    }
+
+    fn set_fullscreen(&mut self, monitor: Option<usize>) {
+        set_fullscreen(self.window.window.ctx.window(), monitor);
+    }
+
+    fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.window.window.ctx.window().set_always_on_top(always_on_top);
+    }
+
+    fn set_minimized(&mut self, minimized: bool) {
+        set_minimized(self.window.window.ctx.window(), minimized);
+    }
+
+    fn request_attention(&mut self) {
+        self.wants_attention = true;
+        set_attention(self.window.window.ctx.window(), true);
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+// Returns the scanlines to draw this frame if they're enabled, rendering them again first if the window size or colors have changed.
+fn cached_scanlines<'a>(cache: &'a mut Option<Scanlines<G2dTexture>>, window: &mut PistonWindow, win_size: Size, (bgc, fgc): (Color, Color), enabled: bool) -> Option<&'a Scanlines<G2dTexture>> {
+    if !enabled { return None; }
+
+    if !cache.as_ref().is_some_and(|lines| lines.matches(win_size, bgc, fgc)) {
+        *cache = Scanlines::new(&mut window.create_texture_context(), win_size, bgc, fgc);
+    }
+    cache.as_ref()
+}
+
+// Returns a target to render split frames to, creating it again first if the window's size in pixels has changed.
+fn cached_scene_target<'a>(cache: &'a mut Option<SceneTarget>, window: &mut PistonWindow, draw_size: [u32; 2]) -> Option<&'a SceneTarget> {
+    if !cache.as_ref().is_some_and(|target| target.matches(draw_size)) {
+        *cache = SceneTarget::new(&mut window.factory, draw_size);
+    }
+    cache.as_ref()
+}
+
+// Builds a window with the given title and size, falling back to OpenGL 2.1 if the default OpenGL version is unavailable.
+// The window can't be resized below the minimum window size. Escape is left to the key map, so it never closes the window.
+// Given a monitor, the window is centered on it straight away, before anything is drawn in it.
+fn build_window(title: &str, size: Size, monitor: Option<usize>) -> Result<PistonWindow, Box<dyn Error>> {
+    let window: PistonWindow = WindowSettings::new(title, size).build().or_else(|_| {
+        WindowSettings::new(title, size).graphics_api(OpenGL::V2_1).build()
+    })?;
+    window.window.ctx.window().set_min_dimensions(Some(MIN_WINDOW_SIZE.into()));
+    if let Some(index) = monitor { center_on_monitor(window.window.ctx.window(), index); }
+    Ok(window)
+}
+
+// Moves the window to the center of the monitor with the given index. Does nothing if there is no monitor with that index.
+pub(crate) fn center_on_monitor(window: &winit::Window, index: usize) {
+    if let (Some((x, y, width, height)), Some(size)) = (monitor_bounds(window, Some(index)), window.get_inner_size()) {
+        window.set_position(winit::dpi::LogicalPosition::new(x + (width - size.width) / 2.0, y + (height - size.height) / 2.0));
+    }
+}
+
+// Returns the (x, y, width, height) of the monitor with the given index, or the window's current monitor if given None.
+pub(crate) fn monitor_bounds(window: &winit::Window, index: Option<usize>) -> Option<(f64, f64, f64, f64)> {
+    let monitor = match index {
+        Some(i) => window.get_available_monitors().nth(i)?,
+        None => window.get_current_monitor(),
+    };
+
+    let hidpi: f64 = monitor.get_hidpi_factor();
+    let pos = monitor.get_position().to_logical(hidpi);
+    let size = monitor.get_dimensions().to_logical(hidpi);
+    Some((pos.x, pos.y, size.width, size.height))
+}
+
+// Makes the window fullscreen on the monitor with the given index, or returns it to a normal window if given None.
+pub(crate) fn set_fullscreen(window: &winit::Window, monitor: Option<usize>) {
+    match monitor {
+        Some(index) => {
+            if let Some(id) = window.get_available_monitors().nth(index) { window.set_fullscreen(Some(id)); }
+        }
+        None => window.set_fullscreen(None),
+    }
+}
+
+// Minimizes the window to the taskbar if given true, or restores it if given false.
+// winit can't do this itself, so it's done through the window's X11 handle. Wayland windows don't have one and are left alone.
+#[cfg(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+pub(crate) fn set_minimized(window: &winit::Window, minimized: bool) {
+    use winit::os::unix::WindowExt;
+
+    if let (Some(xconn), Some(xwindow), Some(screen)) = (window.get_xlib_xconnection(), window.get_xlib_window(), window.get_xlib_screen_id()) {
+        unsafe {
+            if minimized { (xconn.xlib.XIconifyWindow)(xconn.display, xwindow, screen); } else { (xconn.xlib.XMapRaised)(xconn.display, xwindow); }
+            (xconn.xlib.XFlush)(xconn.display);
+        }
+    }
+}
+
+// Minimizes the window to the taskbar if given true, or restores it if given false.
+#[cfg(target_os = "windows")]
+pub(crate) fn set_minimized(window: &winit::Window, minimized: bool) {
+    use winapi::um::winuser::{ShowWindow, SW_MINIMIZE, SW_RESTORE};
+    use winit::os::windows::WindowExt;
+
+    unsafe { ShowWindow(window.get_hwnd() as _, if minimized { SW_MINIMIZE } else { SW_RESTORE }); }
+}
+
+// Minimizes the window to the dock if given true, or restores it if given false.
+#[cfg(target_os = "macos")]
+pub(crate) fn set_minimized(window: &winit::Window, minimized: bool) {
+    use cocoa::{appkit::NSWindow, base::{id, nil}};
+    use winit::os::macos::WindowExt;
+
+    let ns_window: id = window.get_nswindow() as id;
+    unsafe { if minimized { ns_window.miniaturize_(nil); } else { ns_window.deminiaturize_(nil); } }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd", target_os = "windows", target_os = "macos")))]
+pub(crate) fn set_minimized(_window: &winit::Window, _minimized: bool) {}
+
+// Sets or clears the window's urgency hint, which most window managers show by highlighting its taskbar entry.
+#[cfg(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+pub(crate) fn set_attention(window: &winit::Window, wanted: bool) {
+    use winit::os::unix::WindowExt;
+
+    window.set_urgent(wanted);
+}
+
+// Flashes the window's taskbar entry until it's focused, or stops flashing it.
+#[cfg(target_os = "windows")]
+pub(crate) fn set_attention(window: &winit::Window, wanted: bool) {
+    use winapi::um::winuser::{FlashWindowEx, FLASHWINFO, FLASHW_ALL, FLASHW_STOP, FLASHW_TIMERNOFG};
+    use winit::os::windows::WindowExt;
+
+    let mut info: FLASHWINFO = FLASHWINFO {
+        cbSize: mem::size_of::<FLASHWINFO>() as u32,
+        hwnd: window.get_hwnd() as _,
+        dwFlags: if wanted { FLASHW_ALL | FLASHW_TIMERNOFG } else { FLASHW_STOP },
+        uCount: 0,
+        dwTimeout: 0,
+    };
+    unsafe { FlashWindowEx(&mut info); }
+}
+
+// Bounces the window's dock icon once. The dock stops on its own when the window is focused, so there's nothing to clear.
+#[cfg(target_os = "macos")]
+pub(crate) fn set_attention(window: &winit::Window, wanted: bool) {
+    use winit::os::macos::WindowExt;
+
+    if wanted { window.request_user_attention(false); }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd", target_os = "windows", target_os = "macos")))]
+pub(crate) fn set_attention(_window: &winit::Window, _wanted: bool) {}
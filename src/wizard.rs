@@ -0,0 +1,127 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{keymap::Action, terminal::Terminal};
+
+/// What the user did when asked one step of a wizard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StepInput {
+    /// The user typed this answer.
+    Answer(String),
+    /// The user went back to the previous step.
+    Back,
+}
+
+// Checks whether an answer parses, returning why it doesn't if not.
+type Check = Box<dyn Fn(&str) -> Result<(), String>>;
+
+// A single question in a wizard, along with how to check its answer.
+struct Step {
+    name: String,
+    prompt: String,
+    check: Check,
+}
+
+/// A series of questions asked one after another, with a "Step 2/5" indicator. The user can go back to change an earlier answer
+/// by pressing a key bound to [Action::Back](../keymap/enum.Action.html), Escape by default, which the indicator names.
+/// The earlier answer is shown alongside its question. Each step's answer must parse as that step's type before the wizard moves on.
+///
+/// ```no_run
+/// # use simpleterm_profile::text::*;
+/// # use simpleterm_profile::terminal::Terminal;
+/// # use simpleterm_profile::wizard::*;
+/// # let mut term: Terminal = Terminal::new("simpleterm test", (800, 600), DARK_GREY, GOLD, "LeagueSpartan-Regular.ttf", 32);
+/// let answers: Answers = Wizard::new()
+///     .step::<String>("name", "What is your name?")
+///     .step::<u32>("age", "How old are you?")
+///     .run(&mut term)
+///     .unwrap();
+/// let age: u32 = answers.get("age").unwrap();
+/// ```
+#[derive(Default)]
+pub struct Wizard {
+    steps: Vec<Step>,
+}
+
+impl Wizard {
+    /// Creates a wizard with no steps.
+    pub fn new() -> Wizard {
+        Wizard::default()
+    }
+
+    /// Adds a step that asks the given question and only accepts answers that parse as T. Answers are retrieved by name.
+    pub fn step<T>(mut self, name: &str, prompt: &str) -> Wizard
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.steps.push(Step {
+            name: String::from(name),
+            prompt: String::from(prompt),
+            check: Box::new(|answer: &str| answer.parse::<T>().map(|_| ()).map_err(|error| error.to_string())),
+        });
+        self
+    }
+
+    /// Asks every step in order on the given terminal and returns all the answers, or None if the window was closed.
+    pub fn run(&self, term: &mut Terminal) -> Option<Answers> {
+        let mut answers: Vec<Option<String>> = vec![None; self.steps.len()];
+        let mut error: Option<String> = None;
+        let mut i: usize = 0;
+        let back_keys: Option<String> = term.keymap.help(&[Action::Back]).pop().map(|(keys, _)| keys);
+
+        while i < self.steps.len() {
+            let step: &Step = &self.steps[i];
+            let mut message: String = format!("Step {}/{}", i + 1, self.steps.len());
+            if let (true, Some(keys)) = (i > 0, &back_keys) { message.push_str(&format!(" ({} to go back)", keys)); }
+            message.push_str(&format!("\n{}", step.prompt));
+            if let Some(previous) = &answers[i] { message.push_str(&format!("\n(was: {})", previous)); }
+            if let Some(reason) = error.take() { message = format!("{}\n{}", reason, message); }
+
+            match term.ask_step(&message, i > 0)? {
+                StepInput::Back => i -= 1,
+                StepInput::Answer(answer) => match (step.check)(&answer) {
+                    Ok(()) => {
+                        answers[i] = Some(answer);
+                        i += 1;
+                    },
+                    Err(reason) => error = Some(format!("\"{}\" won't work: {}", answer, reason)),
+                },
+            }
+        }
+
+        Some(Answers {
+            answers: self.steps.iter().zip(answers).map(|(step, answer)| (step.name.clone(), answer.unwrap_or_default())).collect(),
+        })
+    }
+}
+
+/// The answers given to a wizard, looked up by step name.
+/// ```
+/// # use simpleterm_profile::wizard::Answers;
+/// let answers: Answers = Answers::from(vec![(String::from("age"), String::from("42"))]);
+/// assert_eq!(answers.get::<u32>("age"), Some(42));
+/// assert_eq!(answers.raw("age"), Some("42"));
+/// assert_eq!(answers.raw("name"), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: Vec<(String, String)>,
+}
+
+impl Answers {
+    /// Returns the answer to the named step parsed as T, or None if there's no such step or it doesn't parse.
+    pub fn get<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.raw(name).and_then(|answer| answer.parse().ok())
+    }
+
+    /// Returns the answer to the named step exactly as it was typed.
+    pub fn raw(&self, name: &str) -> Option<&str> {
+        self.answers.iter().find(|(step, _)| step == name).map(|(_, answer)| answer.as_str())
+    }
+}
+
+impl From<Vec<(String, String)>> for Answers {
+    fn from(answers: Vec<(String, String)>) -> Answers {
+        Answers { answers }
+    }
+}
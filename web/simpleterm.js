@@ -0,0 +1,125 @@
+// The page's half of simpleterm's canvas screen. The worker's half is src/web.js, and web.rs documents how the two fit together.
+//
+//     import { attach } from "./simpleterm.js";
+//     attach(document.querySelector("canvas"), new Worker("worker.js", { type: "module" }));
+//
+// Input is written to a SharedArrayBuffer of 32-bit integers: a count of the records written so far,
+// then a ring of RECORDS records of [kind, a, b, c].
+const HEADER = 4;
+const RECORD = 4;
+const RECORDS = 256;
+
+// The kinds of input record, matching the ones read by web.rs.
+const KEY_DOWN = 1;
+const KEY_UP = 2;
+const TEXT = 3;
+const MOUSE_MOVE = 4;
+const MOUSE_DOWN = 5;
+const MOUSE_UP = 6;
+const SCROLL = 7;
+const RESIZE = 8;
+const FOCUS = 9;
+
+// Piston's codes for the keys whose names are more than one character long.
+const NAMED_KEYS = {
+    Backspace: 0x08, Tab: 0x09, Enter: 0x0D, Escape: 0x1B, Delete: 0x7F, CapsLock: 0x40000039,
+    F1: 0x4000003A, F2: 0x4000003B, F3: 0x4000003C, F4: 0x4000003D, F5: 0x4000003E, F6: 0x4000003F,
+    F7: 0x40000040, F8: 0x40000041, F9: 0x40000042, F10: 0x40000043, F11: 0x40000044, F12: 0x40000045,
+    PrintScreen: 0x40000046, ScrollLock: 0x40000047, Pause: 0x40000048, Insert: 0x40000049, Home: 0x4000004A,
+    PageUp: 0x4000004B, End: 0x4000004D, PageDown: 0x4000004E,
+    ArrowRight: 0x4000004F, ArrowLeft: 0x40000050, ArrowDown: 0x40000051, ArrowUp: 0x40000052,
+};
+
+// Piston's codes for the modifier keys, by which side of the keyboard they're on.
+const MODIFIER_KEYS = {
+    Control: [0x400000E0, 0x400000E4], Shift: [0x400000E1, 0x400000E5],
+    Alt: [0x400000E2, 0x400000E6], Meta: [0x400000E3, 0x400000E7],
+};
+
+// Returns piston's code for the key of a keyboard event, or 0 if it has none.
+function keyCode(event) {
+    if (event.key in MODIFIER_KEYS) {
+        return MODIFIER_KEYS[event.key][event.location === KeyboardEvent.DOM_KEY_LOCATION_RIGHT ? 1 : 0];
+    }
+    if (event.key in NAMED_KEYS) {
+        return NAMED_KEYS[event.key];
+    }
+    if ([...event.key].length === 1) {
+        const code = event.key.toLowerCase().codePointAt(0);
+        return code < 0x80 ? code : 0;
+    }
+    return 0;
+}
+
+// Connects a canvas to a worker running a script on a CanvasScreen. The worker is posted the input buffer
+// to open the screen with, and the canvas shows the frames the worker draws and passes on its input.
+export function attach(canvas, worker) {
+    const input = new Int32Array(new SharedArrayBuffer((HEADER + RECORDS * RECORD) * 4));
+    const context = canvas.getContext("2d");
+
+    function send(kind, a = 0, b = 0, c = 0) {
+        const written = Atomics.load(input, 0);
+        const at = HEADER + (((written % RECORDS) + RECORDS) % RECORDS) * RECORD;
+        input.set([kind, a, b, c], at);
+        Atomics.store(input, 0, (written + 1) | 0);
+        Atomics.notify(input, 0);
+    }
+
+    function sendSize() {
+        const bounds = canvas.getBoundingClientRect();
+        send(RESIZE, Math.round(bounds.width), Math.round(bounds.height), Math.round(window.devicePixelRatio * 1000));
+    }
+
+    function mousePosition(event) {
+        const bounds = canvas.getBoundingClientRect();
+        return [Math.round(event.clientX - bounds.left), Math.round(event.clientY - bounds.top)];
+    }
+
+    canvas.tabIndex = 0;
+    canvas.addEventListener("keydown", (event) => {
+        const code = keyCode(event);
+        if (code) send(KEY_DOWN, code);
+        if ([...event.key].length === 1 && !event.ctrlKey && !event.metaKey && !event.altKey) send(TEXT, event.key.codePointAt(0));
+        if (code) event.preventDefault();
+    });
+    canvas.addEventListener("keyup", (event) => {
+        const code = keyCode(event);
+        if (code) send(KEY_UP, code);
+    });
+    canvas.addEventListener("mousemove", (event) => send(MOUSE_MOVE, ...mousePosition(event)));
+    canvas.addEventListener("mousedown", (event) => {
+        canvas.focus();
+        send(MOUSE_MOVE, ...mousePosition(event));
+        send(MOUSE_DOWN, event.button);
+    });
+    canvas.addEventListener("mouseup", (event) => send(MOUSE_UP, event.button));
+    canvas.addEventListener("contextmenu", (event) => event.preventDefault());
+    canvas.addEventListener("wheel", (event) => {
+        send(SCROLL, -Math.sign(event.deltaX), -Math.sign(event.deltaY));
+        event.preventDefault();
+    }, { passive: false });
+    canvas.addEventListener("focus", () => send(FOCUS, 1));
+    canvas.addEventListener("blur", () => send(FOCUS, 0));
+    new ResizeObserver(sendSize).observe(canvas);
+
+    worker.addEventListener("message", (event) => {
+        const message = event.data;
+        if (message.simpleterm === "frame") {
+            if (canvas.width !== message.width || canvas.height !== message.height) {
+                canvas.width = message.width;
+                canvas.height = message.height;
+            }
+            const pixels = new Uint8ClampedArray(message.pixels.buffer);
+            context.putImageData(new ImageData(pixels, message.width, message.height), 0, 0);
+        } else if (message.simpleterm === "resize") {
+            canvas.style.width = `${message.width}px`;
+            canvas.style.height = `${message.height}px`;
+        } else if (message.simpleterm === "close") {
+            context.clearRect(0, 0, canvas.width, canvas.height);
+        }
+    });
+
+    worker.postMessage(input.buffer);
+    sendSize();
+    canvas.focus();
+}